@@ -20,6 +20,7 @@ fn build_config(template: &str, style: BoxStyle) -> BoxyConfig {
         h_padding: 2,
         v_padding: 1,
         enable_wrapping: false,
+        ..WidthConfig::default()
     };
 
     config.fixed_height = Some(20);
@@ -38,12 +39,14 @@ fn build_config(template: &str, style: BoxStyle) -> BoxyConfig {
         divider_before_status: true,
         pad_after_title_divider: true,
         pad_before_status_divider: true,
+        ..DividerConfig::default()
     };
 
     config.alignment = AlignmentConfig {
         header_align: "center".to_string(),
         footer_align: "center".to_string(),
         status_align_override: None,
+        vertical_align: "top".to_string(),
     };
 
     config.colors = BoxColors {
@@ -53,6 +56,7 @@ fn build_config(template: &str, style: BoxStyle) -> BoxyConfig {
         status_color: Some("lime".to_string()),
         header_color: None,
         footer_color: None,
+        ..BoxColors::default()
     };
 
     config.style = style;
@@ -78,6 +78,7 @@ fn main() {
         header_color: Some("#00ffff".to_string()),
         footer_color: Some("#ffff00".to_string()),
         status_color: Some("#ff00ff".to_string()),
+        ..Default::default()
     };
 
     // Plain renderer (no colors applied)
@@ -46,7 +46,15 @@
 // Re-export BoxyConfig as public API for library users
 pub use crate::core::BoxyConfig;
 
-use crate::api::layout::{BoxBuilder, BoxLayout, FooterBuilder, HeaderBuilder, StatusBuilder};
+use crate::api::diff::{DiffStyle, split_documents};
+use crate::api::tree::{TreeFormat, TreeStyle};
+use crate::core::BodyMode;
+use crate::visual::DividerStyle;
+
+use crate::api::layout::{
+    BoxBuilder, BoxLayout, CodeStyle, FooterBuilder, HeaderBuilder, LabelDecoration, SectionStyle,
+    Shadow, StatusBuilder, VerticalAlign,
+};
 
 /// Convert BoxyConfig to BoxLayout (CLI → API adapter)
 ///
@@ -74,11 +82,73 @@ impl From<&BoxyConfig> for BoxLayout {
         // CRITICAL: Preserve CLI title behavior - titles render INSIDE body, not as headers
         // Legacy CLI: Body::compose_content_lines() adds title as first body line
         // Do NOT use with_header() for titles - that would break CLI parity
+        // (the only exception is the opt-in embedded title mode, layout token `te`)
+
+        // Nested mode keeps an already rendered box as one atomic block;
+        // markdown mode turns the text into styled rows and code sub-boxes;
+        // code mode (and command output) soft-wraps long lines only when wrapping is enabled
+        let mut builder = match &config.body_mode {
+            BodyMode::Text => BoxBuilder::new(&config.text),
+            BodyMode::Nested => BoxBuilder::new("").with_block(&config.text),
+            BodyMode::Markdown => BoxBuilder::new("").with_markdown(&config.text),
+            BodyMode::Code(code) => BoxBuilder::new("").with_code(
+                &config.text,
+                CodeStyle::new()
+                    .with_tab_width(code.tab_width)
+                    .with_line_numbers(code.line_numbers)
+                    .with_soft_wrap(config.width.enable_wrapping),
+            ),
+            BodyMode::Tree(tree) => {
+                let mut style = TreeStyle::new()
+                    .with_format(TreeFormat::from_name(&tree.format).unwrap_or_default());
+                if let Some(depth) = tree.max_depth {
+                    style = style.with_max_depth(depth);
+                }
+                BoxBuilder::new("").with_tree(&config.text, style)
+            }
+            BodyMode::Diff(diff) => {
+                let (old, new) = match &diff.documents {
+                    Some(documents) => documents.clone(),
+                    // Without a `#DIFF#` line everything counts as removed
+                    None => split_documents(&config.text)
+                        .unwrap_or_else(|| (config.text.clone(), String::new())),
+                };
+                let mut style = DiffStyle::new()
+                    .with_line_numbers(diff.line_numbers)
+                    .with_colors(config.colors.diff.clone());
+                if let Some((old_title, new_title)) = &diff.titles {
+                    style = style.with_titles(old_title, new_title);
+                }
+                BoxBuilder::new("").with_diff(&old, &new, style)
+            }
+            BodyMode::Run(run) => {
+                let style = CodeStyle::new().with_soft_wrap(config.width.enable_wrapping);
+                let builder = BoxBuilder::new("");
+                match (run.stdout.is_empty(), run.stderr.is_empty()) {
+                    (true, false) => builder.with_code(&run.stderr, style),
+                    (_, true) => builder.with_code(&run.stdout, style),
+                    (false, false) => builder
+                        .with_code(&run.stdout, style.clone())
+                        .with_section("stderr", "")
+                        .with_code(&run.stderr, style),
+                }
+            }
+        };
+        // The code language labels the header unless one was given
+        let code_label = match &config.body_mode {
+            BodyMode::Code(code) => code.lang.as_ref(),
+            _ => None,
+        };
 
-        let mut builder = BoxBuilder::new(&config.text);
+        // Embedded title mode: title moves into the top border when no header claims it
+        let border_title = match (&config.title, &config.header) {
+            (Some(title), None) if config.border_labels.embed_title => Some(title),
+            _ => None,
+        };
+        let label_decoration = LabelDecoration::from(config.border_labels.decoration.as_str());
 
         // Apply title if provided (renders inside body, not as header)
-        if let Some(title) = &config.title {
+        if let (Some(title), None) = (&config.title, border_title) {
             builder = builder.with_title(title);
         }
 
@@ -87,9 +157,11 @@ impl From<&BoxyConfig> for BoxLayout {
             builder = builder.with_icon(icon);
         }
 
-        // Only use header builder for explicit header field (not title)
-        if let Some(header) = &config.header {
-            let mut header_builder = HeaderBuilder::new(header);
+        // Only use header builder for explicit header field (or an embedded title)
+        if let Some(header) = config.header.as_ref().or(border_title).or(code_label) {
+            let mut header_builder = HeaderBuilder::new(header)
+                .with_decoration(label_decoration)
+                .with_inset(config.border_labels.inset);
             match config.alignment.header_align.as_str() {
                 "left" => header_builder = header_builder.align_left(),
                 "center" => header_builder = header_builder.align_center(),
@@ -101,7 +173,9 @@ impl From<&BoxyConfig> for BoxLayout {
 
         // Apply footer if provided
         if let Some(footer_text) = &config.footer {
-            let mut footer_builder = FooterBuilder::new(footer_text);
+            let mut footer_builder = FooterBuilder::new(footer_text)
+                .with_decoration(label_decoration)
+                .with_inset(config.border_labels.inset);
 
             // Apply footer alignment from config
             match config.alignment.footer_align.as_str() {
@@ -114,9 +188,29 @@ impl From<&BoxyConfig> for BoxLayout {
             builder = builder.with_footer(footer_builder);
         }
 
+        // Dividers inside the box may differ in weight from the frame (--divider-style)
+        let rule_style = DividerStyle::from_name(&config.dividers.rule_style).unwrap_or_default();
+
         // Apply status line if provided
         if let Some(status_text) = &config.status_bar {
-            builder = builder.with_status(StatusBuilder::new(status_text));
+            let mut status_builder =
+                StatusBuilder::parse(status_text).with_divider_style(rule_style);
+
+            // An explicit status alignment overrides the prefix of a single-text status
+            if status_builder.segments().len() == 1 {
+                match config.alignment.status_align_override.as_deref() {
+                    Some("left") => status_builder = status_builder.align_left(),
+                    Some("center") => status_builder = status_builder.align_center(),
+                    Some("right") => status_builder = status_builder.align_right(),
+                    _ => {}
+                }
+            }
+
+            if let Some(color) = &config.colors.status_color {
+                status_builder = status_builder.with_color(color);
+            }
+
+            builder = builder.with_status(status_builder);
         }
 
         // Apply box style from config
@@ -126,6 +220,15 @@ impl From<&BoxyConfig> for BoxLayout {
         if let Some(fixed) = config.width.fixed_width {
             builder = builder.with_fixed_width(fixed);
         }
+        if let Some(min) = config.width.min_width {
+            builder = builder.with_min_width(min);
+        }
+        if let Some(max) = config.width.max_width {
+            builder = builder.with_max_width(max);
+        }
+        if let Some(policy) = config.width.overflow {
+            builder = builder.with_overflow(policy, crate::width_plugin::get_terminal_width());
+        }
 
         // Apply horizontal padding
         builder = builder.with_h_padding(config.width.h_padding);
@@ -136,14 +239,54 @@ impl From<&BoxyConfig> for BoxLayout {
         let legacy_v_padding = 0; // Legacy behavior: no vertical padding
         builder = builder.with_v_padding(legacy_v_padding);
 
+        // Explicit per-side padding (--padding, theme padding) replaces both
+        if let Some(padding) = config.width.body_padding {
+            builder = builder.with_padding_sides(padding);
+        }
+        builder = builder
+            .with_margin(config.margin)
+            .with_borders(config.borders);
+
         // Apply height configuration (if multiplexing mode enabled)
         if let Some(height) = config.fixed_height {
             builder = builder.with_fixed_height(height);
         }
 
+        // Apply vertical placement of body content (vt/vm/vb layout tokens)
+        builder = builder.with_vertical_align(VerticalAlign::from(
+            config.alignment.vertical_align.as_str(),
+        ));
+
         // Apply wrapping mode
         builder = builder.with_wrapping(config.width.enable_wrapping);
 
+        // Section dividers (`#DIV:Label#` lines, dl/dc/dr layout tokens)
+        let mut section_style = SectionStyle::new()
+            .with_decoration(LabelDecoration::from(
+                config.dividers.section_decoration.as_str(),
+            ))
+            .with_divider_style(rule_style);
+        match config.dividers.section_align.as_str() {
+            "center" => section_style = section_style.align_center(),
+            "right" => section_style = section_style.align_right(),
+            _ => {}
+        }
+        builder = builder.with_section_style(section_style);
+
+        // Apply body alignment and emoji padding (bl/bc/br/bj/bp layout tokens)
+        builder = builder
+            .with_body_alignment(config.body_align.clone())
+            .with_emoji_padding(config.body_pad_emoji);
+
+        // Apply drop shadow
+        if let Some(shadow_config) = &config.shadow {
+            let mut shadow = Shadow::new().with_glyph(&shadow_config.glyph);
+            if let Some(color) = &shadow_config.color {
+                shadow = shadow.with_color(color);
+            }
+            builder = builder.with_shadow(shadow);
+        }
+
         // TODO: Dividers and vertical padding not yet implemented in API
         // These features exist in CLI via config.dividers and config.padding
         // but require BoxLayout rendering changes to support properly.
@@ -217,6 +360,7 @@ mod tests {
 
     #[test]
     fn test_config_preserves_unicode() {
+        crate::width_calibration::install_uncalibrated();
         let mut config = BoxyConfig::default();
         config.text = "Hello 🌟 世界".to_string();
         config.title = Some("Unicode Test 中文".to_string());
@@ -257,4 +401,24 @@ mod tests {
 
         assert!(output.contains("Status: OK"));
     }
+
+    #[test]
+    fn test_config_embedded_title() {
+        let mut config = BoxyConfig {
+            text: "Body text".to_string(),
+            title: Some("Build".to_string()),
+            ..Default::default()
+        };
+        config.width.fixed_width = Some(20);
+        config.alignment.header_align = "left".to_string();
+        config.border_labels.embed_title = true;
+        config.border_labels.decoration = "bracket".to_string();
+        config.border_labels.inset = 1;
+
+        let output = BoxLayout::from(&config).render();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines[0], "┌─┤ Build ├────────┐");
+        assert_eq!(lines.len(), 3, "title should not add a body line");
+    }
 }
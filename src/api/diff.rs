@@ -0,0 +1,389 @@
+//! Side-by-side diff body - two documents in split panes
+//!
+//! The old document fills the left pane and the new one the right, divided by
+//! a vertical rule in the box style's glyphs that joins the frame with `┬`/`┴`.
+//! Lines are paired by a longest-common-subsequence pass:
+//!
+//! - unchanged lines sit side by side
+//! - a removed line (`-`) faces a blank, an added line (`+`) likewise
+//! - removals directly followed by additions pair up line by line as changes (`~`)
+//! - a middle too large to align (`MAX_LCS_CELLS`) pairs up by position as changes
+//!
+//! Changed lines are colored with `DiffColors` (`BoxColors::diff` in a config). Both
+//! documents can come in one text, split by a `#DIFF#` line.
+//!
+//! # Example
+//! ```rust
+//! use boxy::api::diff::DiffStyle;
+//! use boxy::api::layout::BoxBuilder;
+//!
+//! let layout = BoxBuilder::new("")
+//!     .with_diff("one\ntwo", "one\n2", DiffStyle::new().with_titles("old", "new"))
+//!     .build();
+//! assert!(layout.render().contains("~ two"));
+//! ```
+
+use crate::api::geometry::get_text_width;
+use crate::core::DiffColors;
+use crate::truncate_with_ellipsis;
+
+/// Line that separates the old and new documents in one text
+pub const SEPARATOR: &str = "#DIFF#";
+
+/// Split a text holding two documents at its first `#DIFF#` line
+pub fn split_documents(text: &str) -> Option<(String, String)> {
+    let lines: Vec<&str> = text.lines().collect();
+    let at = lines.iter().position(|line| line.trim() == SEPARATOR)?;
+    Some((lines[..at].join("\n"), lines[at + 1..].join("\n")))
+}
+
+/// How a pair of lines differs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Same,
+    /// Only in the old document
+    Removed,
+    /// Only in the new document
+    Added,
+    /// Replaced: the old line faces its replacement
+    Changed,
+}
+
+impl Change {
+    fn marker(&self) -> &'static str {
+        match self {
+            Self::Same => " ",
+            Self::Removed => "-",
+            Self::Added => "+",
+            Self::Changed => "~",
+        }
+    }
+}
+
+/// A numbered source line (1-based)
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffLine {
+    pub number: usize,
+    pub text: String,
+}
+
+/// Aligned line pair; the side a line is missing from is `None`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffRow {
+    pub old: Option<DiffLine>,
+    pub new: Option<DiffLine>,
+    pub change: Change,
+}
+
+/// Largest LCS table (cells) built for the changed middle of two documents
+///
+/// Beyond it the middle is not aligned: its lines pair up by position as changes.
+pub const MAX_LCS_CELLS: usize = 4_000_000;
+
+/// Pair the lines of `old` and `new`
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffRow> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Only the part between the common head and tail needs the quadratic table
+    let head = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let tail = old[head..]
+        .iter()
+        .rev()
+        .zip(new[head..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (n, m) = (old.len() - head - tail, new.len() - head - tail);
+    let (old_mid, new_mid) = (&old[head..head + n], &new[head..head + m]);
+
+    // lcs[i][j]: common lines of old_mid[i..] and new_mid[j..]; left empty when too large,
+    // which turns the whole middle into one run of removals and additions
+    let aligned = (n + 1).saturating_mul(m + 1) <= MAX_LCS_CELLS;
+    let mut lcs = match aligned {
+        true => vec![vec![0usize; m + 1]; n + 1],
+        false => Vec::new(),
+    };
+    if aligned {
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[i][j] = match old_mid[i] == new_mid[j] {
+                    true => lcs[i + 1][j + 1] + 1,
+                    false => lcs[i + 1][j].max(lcs[i][j + 1]),
+                };
+            }
+        }
+    }
+
+    let line = |lines: &[&str], index: usize| DiffLine {
+        number: index + 1,
+        text: lines[index].to_string(),
+    };
+    let same = |rows: &mut Vec<DiffRow>, i: usize, j: usize| {
+        rows.push(DiffRow {
+            old: Some(line(&old, i)),
+            new: Some(line(&new, j)),
+            change: Change::Same,
+        })
+    };
+
+    let mut rows = Vec::new();
+    (0..head).for_each(|i| same(&mut rows, i, i));
+    let (mut removed, mut added) = (Vec::new(), Vec::new());
+    let (mut i, mut j) = (0, 0);
+    if !aligned {
+        removed.extend((0..n).map(|i| line(&old, head + i)));
+        added.extend((0..m).map(|j| line(&new, head + j)));
+        (i, j) = (n, m);
+    }
+    while i < n || j < m {
+        if i < n && j < m && old_mid[i] == new_mid[j] {
+            pair_changes(&mut rows, &mut removed, &mut added);
+            same(&mut rows, head + i, head + j);
+            i += 1;
+            j += 1;
+        } else if j < m && (i == n || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            added.push(line(&new, head + j));
+            j += 1;
+        } else {
+            removed.push(line(&old, head + i));
+            i += 1;
+        }
+    }
+    pair_changes(&mut rows, &mut removed, &mut added);
+    (0..tail).for_each(|k| same(&mut rows, head + n + k, head + m + k));
+    rows
+}
+
+/// Flush a run of removals and additions: pairs first, then the leftovers
+fn pair_changes(rows: &mut Vec<DiffRow>, removed: &mut Vec<DiffLine>, added: &mut Vec<DiffLine>) {
+    let mut removed = removed.drain(..);
+    let mut added = added.drain(..);
+    loop {
+        let (old, new) = (removed.next(), added.next());
+        let change = match (&old, &new) {
+            (None, None) => break,
+            (Some(_), Some(_)) => Change::Changed,
+            (Some(_), None) => Change::Removed,
+            (None, Some(_)) => Change::Added,
+        };
+        rows.push(DiffRow { old, new, change });
+    }
+}
+
+/// Diff body options: pane titles, line numbers and colors
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DiffStyle {
+    titles: Option<(String, String)>,
+    line_numbers: bool,
+    colors: DiffColors,
+}
+
+impl DiffStyle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Titles above each pane, set off by a rule (e.g. the file names)
+    pub fn with_titles(mut self, old: &str, new: &str) -> Self {
+        self.titles = Some((old.to_string(), new.to_string()));
+        self
+    }
+
+    pub fn with_line_numbers(mut self, enabled: bool) -> Self {
+        self.line_numbers = enabled;
+        self
+    }
+
+    /// Colors of changed lines (a config's `colors.diff`); `DiffColors::none()` for markers only
+    pub fn with_colors(mut self, colors: DiffColors) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Rows of the split body comparing `old` with `new`
+    pub fn rows(&self, old: &str, new: &str) -> Vec<PaneRow> {
+        let pairs = diff_lines(old, new);
+        let mut rows = Vec::new();
+        if let Some((old_title, new_title)) = &self.titles {
+            rows.push(PaneRow::Text(
+                PaneText::plain(old_title),
+                PaneText::plain(new_title),
+            ));
+            rows.push(PaneRow::Rule);
+        }
+
+        let last = pairs
+            .iter()
+            .flat_map(|row| [&row.old, &row.new])
+            .flatten()
+            .map(|line| line.number)
+            .max()
+            .unwrap_or(0);
+        let digits = match self.line_numbers {
+            true => last.to_string().len(),
+            false => 0,
+        };
+        for pair in pairs {
+            let side = |line: &Option<DiffLine>, color: &str| match line {
+                Some(line) => PaneText {
+                    text: self.pane_line(line, pair.change, digits),
+                    color: (pair.change != Change::Same).then(|| color.to_string()),
+                },
+                None => PaneText::default(),
+            };
+            let (old_color, new_color) = match pair.change {
+                Change::Changed => (&self.colors.changed, &self.colors.changed),
+                _ => (&self.colors.removed, &self.colors.added),
+            };
+            rows.push(PaneRow::Text(
+                side(&pair.old, old_color),
+                side(&pair.new, new_color),
+            ));
+        }
+        rows
+    }
+
+    /// Marker, optional line number, then the text: `~ 12 let x = 2;`
+    fn pane_line(&self, line: &DiffLine, change: Change, digits: usize) -> String {
+        match digits {
+            0 => format!("{} {}", change.marker(), line.text),
+            _ => format!(
+                "{} {:>digits$} {}",
+                change.marker(),
+                line.number,
+                line.text,
+                digits = digits
+            ),
+        }
+    }
+}
+
+/// Text in one pane, colored when its line changed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PaneText {
+    pub text: String,
+    pub color: Option<String>,
+}
+
+impl PaneText {
+    pub fn plain(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            color: None,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        get_text_width(&self.text)
+    }
+
+    /// Exactly `width` columns: truncated with an ellipsis or padded, then colored
+    pub fn fit(&self, width: usize) -> String {
+        let text = match self.width() > width {
+            true if width > 3 => truncate_with_ellipsis(&self.text, width),
+            true => String::new(),
+            false => self.text.clone(),
+        };
+        let fill = " ".repeat(width.saturating_sub(get_text_width(&text)));
+        let code = self.color.as_deref().map(crate::get_color_code);
+        match code {
+            Some(code) if !code.is_empty() && !text.is_empty() => {
+                format!("{}{}{}{}", code, text, crate::RESET, fill)
+            }
+            _ => format!("{}{}", text, fill),
+        }
+    }
+}
+
+/// One row of a split body
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaneRow {
+    /// Old (left) and new (right) pane text
+    Text(PaneText, PaneText),
+    /// Rule across both panes, crossing the center divider
+    Rule,
+}
+
+/// Widths of the (left, right) panes sharing `width` columns; the left gets the odd one out
+pub fn pane_widths(width: usize) -> (usize, usize) {
+    (width - width / 2, width / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn changes(old: &str, new: &str) -> Vec<Change> {
+        diff_lines(old, new)
+            .into_iter()
+            .map(|row| row.change)
+            .collect()
+    }
+
+    #[test]
+    fn test_lines_pair_up() {
+        use Change::*;
+
+        assert_eq!(changes("a\nb\nc", "a\nb\nc"), [Same, Same, Same]);
+        assert_eq!(changes("a\nb\nc", "a\nc"), [Same, Removed, Same]);
+        assert_eq!(changes("a\nc", "a\nb\nc"), [Same, Added, Same]);
+        assert_eq!(
+            changes("a\nb\nc", "a\nB\nc\nd"),
+            [Same, Changed, Same, Added]
+        );
+        // A longer replacement pairs what it can and adds the rest
+        assert_eq!(
+            changes("x\nold\ny", "x\nnew 1\nnew 2\ny"),
+            [Same, Changed, Added, Same]
+        );
+
+        let rows = diff_lines("a\nb", "b");
+        assert_eq!(rows[0].old.as_ref().map(|l| l.number), Some(1));
+        assert!(rows[0].new.is_none());
+        assert_eq!(rows[1].new.as_ref().map(|l| l.number), Some(1));
+    }
+
+    #[test]
+    fn test_large_inputs_pair_by_position() {
+        let lines = |prefix: &str| {
+            (0..2100)
+                .map(|i| format!("{}{}", prefix, i % 7))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let (old, new) = (lines("a"), format!("{}\nlast", lines("b")));
+
+        let rows = diff_lines(&format!("same\n{}", old), &format!("same\n{}", new));
+        assert_eq!(rows.len(), 2102);
+        assert_eq!(rows[0].change, Change::Same);
+        assert!(
+            rows[1..2101]
+                .iter()
+                .all(|row| row.change == Change::Changed)
+        );
+        assert_eq!(rows[2101].change, Change::Added);
+    }
+
+    #[test]
+    fn test_split_documents() {
+        let (old, new) = split_documents("one\ntwo\n#DIFF#\nthree").unwrap();
+        assert_eq!((old.as_str(), new.as_str()), ("one\ntwo", "three"));
+        assert!(split_documents("no separator").is_none());
+    }
+
+    #[test]
+    fn test_pane_text_fits_wide_glyphs() {
+        crate::width_calibration::install_uncalibrated();
+        let text = PaneText::plain("中文字符串");
+        assert_eq!(get_text_width(&text.fit(7)), 7);
+        assert_eq!(get_text_width(&PaneText::plain("🚀 ok").fit(8)), 8);
+
+        let colored = PaneText {
+            text: "- gone".to_string(),
+            color: Some("red".to_string()),
+        };
+        let fitted = colored.fit(10);
+        assert!(fitted.starts_with(crate::get_color_code("red")));
+        assert_eq!(get_text_width(&fitted), 10);
+    }
+}
@@ -0,0 +1,531 @@
+//! Export - turn colored terminal output into markup
+//!
+//! A rendered box is a grid of cells styled by SGR escapes (`ESC[...m`) and
+//! optionally linked with OSC 8 hyperlinks. This module reads those escapes
+//! back into per-run styles so boxes can be shown where no terminal exists:
+//! HTML with inline styles or SVG with one monospace cell per column, for
+//! docs, PR comments and CI reports.
+//!
+//! # Example
+//! ```rust
+//! use boxy::api::export::{ExportOptions, ansi_to_html};
+//! use boxy::api::layout::BoxBuilder;
+//! use boxy::api::theming::ColorScheme;
+//!
+//! let html = ansi_to_html("\x1b[38;5;9mred\x1b[0m <ok>");
+//! assert_eq!(html, "<span style=\"color:#ff0000\">red</span> &lt;ok&gt;");
+//!
+//! let layout = BoxBuilder::new("Hello").build();
+//! let options = ExportOptions::new()
+//!     .with_scheme(ColorScheme::default())
+//!     .with_font_size(16.0);
+//! assert!(layout.to_svg_with(&options).starts_with("<svg"));
+//! ```
+
+use crate::api::theming::ColorScheme;
+use crate::core::hyperlink::link_target;
+use crate::read_escape_sequence;
+use crate::width_plugin::{get_display_width, split_clusters};
+
+/// Markup format for `--output`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Html,
+    Svg,
+}
+
+impl ExportFormat {
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name.to_lowercase().as_str() {
+            "html" => Ok(Self::Html),
+            "svg" => Ok(Self::Svg),
+            _ => Err(format!(
+                "Invalid output format '{}'. Valid: html, svg",
+                name
+            )),
+        }
+    }
+}
+
+/// Font, cell and color settings for exported markup
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    pub font_family: String,
+    /// Font size in px
+    pub font_size: f32,
+    /// Width of one terminal column in px
+    pub cell_width: f32,
+    /// Height of one line in px
+    pub cell_height: f32,
+    /// Space around the box in px
+    pub padding: f32,
+    pub background: String,
+    /// Color of unstyled text
+    pub foreground: String,
+    /// Colors applied before export; `None` keeps whatever the text carries
+    pub scheme: Option<ColorScheme>,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            font_family: "ui-monospace, Menlo, Consolas, 'DejaVu Sans Mono', monospace".to_string(),
+            font_size: 14.0,
+            cell_width: 8.4,
+            cell_height: 17.0,
+            padding: 12.0,
+            background: "#1e1e1e".to_string(),
+            foreground: "#d4d4d4".to_string(),
+            scheme: None,
+        }
+    }
+}
+
+impl ExportOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_font(mut self, family: &str) -> Self {
+        self.font_family = family.to_string();
+        self
+    }
+
+    /// Set the font size; cells scale with it (0.6em wide, 1.2em high)
+    pub fn with_font_size(mut self, size: f32) -> Self {
+        self.font_size = size;
+        self.cell_width = size * 0.6;
+        self.cell_height = (size * 1.2).round();
+        self
+    }
+
+    /// Override the cell size derived from the font size
+    pub fn with_cell_size(mut self, width: f32, height: f32) -> Self {
+        self.cell_width = width;
+        self.cell_height = height;
+        self
+    }
+
+    pub fn with_padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn with_colors(mut self, background: &str, foreground: &str) -> Self {
+        self.background = background.to_string();
+        self.foreground = foreground.to_string();
+        self
+    }
+
+    pub fn with_scheme(mut self, scheme: ColorScheme) -> Self {
+        self.scheme = Some(scheme);
+        self
+    }
+}
+
+/// RGB triple
+pub type Rgb = (u8, u8, u8);
+
+/// Style of a run of cells, as set by SGR escapes
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CellStyle {
+    pub fg: Option<Rgb>,
+    pub bg: Option<Rgb>,
+    pub bold: bool,
+    pub italic: bool,
+    /// Target of an enclosing OSC 8 hyperlink
+    pub link: Option<String>,
+}
+
+impl CellStyle {
+    /// Apply the parameters of one SGR sequence (`1;38;5;9` from `ESC[1;38;5;9m`)
+    pub fn apply_sgr(&mut self, params: &str) {
+        let codes: Vec<u32> = params
+            .split(';')
+            .map(|code| code.parse().unwrap_or(0))
+            .collect();
+        let mut i = 0;
+        while i < codes.len() {
+            match codes[i] {
+                0 => {
+                    // Reset clears colors, not the link
+                    let link = self.link.take();
+                    *self = CellStyle {
+                        link,
+                        ..CellStyle::default()
+                    };
+                }
+                1 => self.bold = true,
+                3 => self.italic = true,
+                22 => self.bold = false,
+                23 => self.italic = false,
+                code @ 30..=37 => self.fg = Some(xterm_color((code - 30) as u8)),
+                code @ 90..=97 => self.fg = Some(xterm_color((code - 90 + 8) as u8)),
+                code @ 40..=47 => self.bg = Some(xterm_color((code - 40) as u8)),
+                code @ 100..=107 => self.bg = Some(xterm_color((code - 100 + 8) as u8)),
+                39 => self.fg = None,
+                49 => self.bg = None,
+                code @ (38 | 48) => {
+                    let (color, used) = extended_color(&codes[i + 1..]);
+                    if code == 38 {
+                        self.fg = color;
+                    } else {
+                        self.bg = color;
+                    }
+                    i += used;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+
+    /// CSS declarations for this style (empty when unstyled)
+    pub fn css(&self) -> String {
+        let mut rules = Vec::new();
+        if let Some(fg) = self.fg {
+            rules.push(format!("color:{}", hex(fg)));
+        }
+        if let Some(bg) = self.bg {
+            rules.push(format!("background:{}", hex(bg)));
+        }
+        if self.bold {
+            rules.push("font-weight:bold".to_string());
+        }
+        if self.italic {
+            rules.push("font-style:italic".to_string());
+        }
+        rules.join(";")
+    }
+}
+
+/// `38;5;n` / `38;2;r;g;b` tail: the color and how many codes it used
+fn extended_color(codes: &[u32]) -> (Option<Rgb>, usize) {
+    match codes {
+        [5, index, ..] => (Some(xterm_color(*index as u8)), 2),
+        [2, r, g, b, ..] => (Some((*r as u8, *g as u8, *b as u8)), 4),
+        _ => (None, codes.len()),
+    }
+}
+
+/// RGB value of an xterm 256-color palette index
+pub fn xterm_color(index: u8) -> Rgb {
+    const BASIC: [Rgb; 16] = [
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    match index {
+        0..=15 => BASIC[index as usize],
+        16..=231 => {
+            let cube = index - 16;
+            (
+                LEVELS[(cube / 36) as usize],
+                LEVELS[(cube / 6 % 6) as usize],
+                LEVELS[(cube % 6) as usize],
+            )
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// `#rrggbb`
+pub fn hex((r, g, b): Rgb) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// Escape text for HTML and XML
+pub fn escape_markup(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Split each line into runs of identically styled text, escapes removed
+///
+/// Style carries over from one line to the next, as it does in a terminal.
+pub fn styled_lines(text: &str) -> Vec<Vec<(String, CellStyle)>> {
+    let mut style = CellStyle::default();
+    text.split('\n')
+        .map(|line| {
+            let mut runs: Vec<(String, CellStyle)> = Vec::new();
+            let mut chars = line.chars().peekable();
+            while let Some(ch) = chars.next() {
+                if ch == '\x1b' {
+                    let sequence = read_escape_sequence(&mut chars);
+                    if let Some(url) = link_target(&sequence) {
+                        style.link = (!url.is_empty()).then(|| url.to_string());
+                    } else if let Some(params) = sequence
+                        .strip_prefix("\x1b[")
+                        .and_then(|s| s.strip_suffix('m'))
+                    {
+                        style.apply_sgr(params);
+                    }
+                    continue;
+                }
+                match runs.last_mut() {
+                    Some((run, run_style)) if *run_style == style => run.push(ch),
+                    _ => runs.push((ch.to_string(), style.clone())),
+                }
+            }
+            runs
+        })
+        .collect()
+}
+
+/// Convert ANSI-colored text to HTML spans (for use inside `<pre>`)
+pub fn ansi_to_html(text: &str) -> String {
+    styled_lines(text)
+        .iter()
+        .map(|runs| {
+            runs.iter()
+                .map(|(run, style)| {
+                    let css = style.css();
+                    let mut html = match css.is_empty() {
+                        true => escape_markup(run),
+                        false => format!("<span style=\"{}\">{}</span>", css, escape_markup(run)),
+                    };
+                    if let Some(url) = &style.link {
+                        html = format!("<a href=\"{}\">{}</a>", escape_markup(url), html);
+                    }
+                    html
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// HTML `<pre>` block with inline styles for ANSI-colored text
+pub fn to_html(text: &str, options: &ExportOptions) -> String {
+    format!(
+        "<pre style=\"display:inline-block;margin:0;padding:{}px;background:{};color:{};font-family:{};font-size:{}px;line-height:{}px\">{}</pre>",
+        options.padding,
+        escape_markup(&options.background),
+        escape_markup(&options.foreground),
+        escape_markup(&options.font_family),
+        options.font_size,
+        options.cell_height,
+        ansi_to_html(text)
+    )
+}
+
+/// SVG image of ANSI-colored text, one fixed-size cell per terminal column
+///
+/// Every run is placed at its column and stretched to its cell count with
+/// `textLength`, so borders line up whatever font the viewer substitutes.
+pub fn to_svg(text: &str, options: &ExportOptions) -> String {
+    let lines = styled_lines(text);
+    let columns = lines
+        .iter()
+        .map(|runs| {
+            runs.iter()
+                .map(|(run, _)| get_display_width(run))
+                .sum::<usize>()
+        })
+        .max()
+        .unwrap_or(0);
+    let width = columns as f32 * options.cell_width + 2.0 * options.padding;
+    let height = lines.len() as f32 * options.cell_height + 2.0 * options.padding;
+
+    let mut backgrounds = String::new();
+    let mut glyphs = String::new();
+    for (row, runs) in lines.iter().enumerate() {
+        let top = options.padding + row as f32 * options.cell_height;
+        let baseline = top + options.cell_height * 0.8;
+        let mut column = 0;
+        for (run, style) in runs {
+            let run_start = column;
+            for (segment, cells) in cell_segments(run) {
+                let x = options.padding + column as f32 * options.cell_width;
+                column += cells;
+                if segment.trim().is_empty() {
+                    continue;
+                }
+                let mut attributes = String::new();
+                if let Some(fg) = style.fg {
+                    attributes.push_str(&format!(" fill=\"{}\"", hex(fg)));
+                }
+                if style.bold {
+                    attributes.push_str(" font-weight=\"bold\"");
+                }
+                if style.italic {
+                    attributes.push_str(" font-style=\"italic\"");
+                }
+                // Single-width runs are stretched to the grid; wide glyphs keep their shape
+                if cells == segment.chars().count() {
+                    attributes.push_str(&format!(
+                        " textLength=\"{}\" lengthAdjust=\"spacingAndGlyphs\"",
+                        px(cells as f32 * options.cell_width)
+                    ));
+                }
+                let element = format!(
+                    "<text x=\"{}\" y=\"{}\"{}>{}</text>",
+                    px(x),
+                    px(baseline),
+                    attributes,
+                    escape_markup(&segment)
+                );
+                match &style.link {
+                    Some(url) => glyphs.push_str(&format!(
+                        "<a href=\"{}\">{}</a>\n",
+                        escape_markup(url),
+                        element
+                    )),
+                    None => {
+                        glyphs.push_str(&element);
+                        glyphs.push('\n');
+                    }
+                }
+            }
+            if let Some(bg) = style.bg {
+                backgrounds.push_str(&format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+                    px(options.padding + run_start as f32 * options.cell_width),
+                    px(top),
+                    px((column - run_start) as f32 * options.cell_width),
+                    px(options.cell_height),
+                    hex(bg)
+                ));
+            }
+        }
+    }
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"{bg}\"/>\n\
+         {backgrounds}\
+         <g font-family=\"{font}\" font-size=\"{size}\" fill=\"{fg}\" xml:space=\"preserve\">\n\
+         {glyphs}</g>\n</svg>\n",
+        w = px(width),
+        h = px(height),
+        bg = escape_markup(&options.background),
+        fg = escape_markup(&options.foreground),
+        font = escape_markup(&options.font_family),
+        size = options.font_size,
+    )
+}
+
+/// Pixel value with at most two decimals (`26.4`, not `26.400002`)
+fn px(value: f32) -> String {
+    let rounded = format!("{:.2}", value);
+    rounded
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// Split a run into pieces of single-width text and individual wide clusters
+fn cell_segments(run: &str) -> Vec<(String, usize)> {
+    let mut segments: Vec<(String, usize)> = Vec::new();
+    for cluster in split_clusters(run) {
+        let cells = get_display_width(&cluster);
+        let narrow = cells == 1 && cluster.chars().count() == 1;
+        match segments.last_mut() {
+            Some((text, count)) if narrow && *count == text.chars().count() => {
+                text.push_str(&cluster);
+                *count += 1;
+            }
+            _ => segments.push((cluster, cells)),
+        }
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette_colors() {
+        assert_eq!(xterm_color(9), (255, 0, 0));
+        assert_eq!(xterm_color(196), (255, 0, 0));
+        assert_eq!(xterm_color(244), (128, 128, 128));
+    }
+
+    #[test]
+    fn test_sgr_runs() {
+        let lines = styled_lines("\x1b[1;38;2;1;2;3mab\x1b[0mc\n\x1b[44md");
+        assert_eq!(lines[0].len(), 2);
+        assert_eq!(lines[0][0].0, "ab");
+        assert_eq!(lines[0][0].1.fg, Some((1, 2, 3)));
+        assert!(lines[0][0].1.bold);
+        assert_eq!(lines[0][1].1, CellStyle::default());
+        assert_eq!(lines[1][0].1.bg, Some(xterm_color(4)));
+    }
+
+    #[test]
+    fn test_hyperlinks_become_anchors() {
+        let html = ansi_to_html(&crate::hyperlink("https://x.io/?a=1&b=2", "x"));
+        assert_eq!(html, "<a href=\"https://x.io/?a=1&amp;b=2\">x</a>");
+    }
+
+    #[test]
+    fn test_svg_places_runs_on_the_grid() {
+        crate::width_calibration::install_uncalibrated();
+        let options = ExportOptions::new()
+            .with_cell_size(10.0, 20.0)
+            .with_padding(0.0);
+        let svg = to_svg("\x1b[38;5;9m│\x1b[0m ab 中 │\n└──┘", &options);
+
+        assert!(svg.contains("width=\"90\" height=\"40\""));
+        assert!(svg.contains("<text x=\"0\" y=\"16\" fill=\"#ff0000\" textLength=\"10\""));
+        // The wide glyph takes two cells and is not stretched
+        assert!(svg.contains("<text x=\"50\" y=\"16\">中</text>"));
+        assert!(svg.contains("<text x=\"70\" y=\"16\" textLength=\"20\""));
+    }
+
+    #[test]
+    fn test_cell_segments_keep_clusters() {
+        crate::width_calibration::install_uncalibrated();
+        assert_eq!(
+            cell_segments("ab✅c"),
+            vec![
+                ("ab".to_string(), 2),
+                ("✅".to_string(), 2),
+                ("c".to_string(), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_colors_are_escaped() {
+        let options = ExportOptions::new().with_colors("red\"><script>", "#fff\" onload=\"x");
+        for markup in [to_html("a", &options), to_svg("a", &options)] {
+            assert!(!markup.contains("<script>"), "{}", markup);
+            assert!(!markup.contains("\" onload"), "{}", markup);
+            assert!(markup.contains("red&quot;&gt;&lt;script&gt;"), "{}", markup);
+        }
+    }
+
+    #[test]
+    fn test_export_format_names() {
+        assert_eq!(ExportFormat::from_name("SVG"), Ok(ExportFormat::Svg));
+        assert!(ExportFormat::from_name("png").is_err());
+    }
+}
@@ -18,6 +18,7 @@
 //! - Curated re-exports from existing boxy internals
 
 use crate::{
+    core::Spacing,
     get_display_width,
     visual::{ASCII, BoxStyle, COLON, DASHED, DOT, DOUBLE, HEAVY, NORMAL, ROUNDED, STAR, THICKSII},
 };
@@ -52,6 +53,14 @@ pub struct BoxDimensions {
     pub h_padding: usize,
     /// Vertical padding applied
     pub v_padding: usize,
+    /// Padding on each side inside the border
+    pub padding: Spacing,
+    /// Blank space on each side outside the border
+    pub margin: Spacing,
+    /// Width including margin (and shadow, when measured from a layout)
+    pub outer_width: usize,
+    /// Height including margin (and shadow, when measured from a layout)
+    pub outer_height: usize,
 }
 
 /// Calculate comprehensive text metrics
@@ -207,6 +216,38 @@ pub fn calculate_box_dimensions(
     h_padding: usize,
     v_padding: usize,
     fixed_width: Option<usize>,
+) -> BoxDimensions {
+    calculate_box_dimensions_with(
+        content,
+        _style,
+        Spacing::symmetric(v_padding, h_padding),
+        Spacing::default(),
+        fixed_width,
+    )
+}
+
+/// Calculate box dimensions with per-side padding and an outer margin
+///
+/// Same as [`calculate_box_dimensions`], but padding may differ per side and
+/// the margin is added to `outer_width`/`outer_height`. `h_padding`/`v_padding`
+/// report the larger of the two sides.
+///
+/// ```rust
+/// use boxy::api::geometry::calculate_box_dimensions_with;
+/// use boxy::visual::NORMAL;
+/// use boxy::Spacing;
+///
+/// let padding = Spacing::new(0, 1, 0, 3);
+/// let dims = calculate_box_dimensions_with("Hello", NORMAL, padding, Spacing::uniform(1), None);
+/// assert_eq!(dims.inner_width, 9);
+/// assert_eq!(dims.outer_width, 13);
+/// ```
+pub fn calculate_box_dimensions_with(
+    content: &str,
+    _style: BoxStyle,
+    padding: Spacing,
+    margin: Spacing,
+    fixed_width: Option<usize>,
 ) -> BoxDimensions {
     // Use existing boxy calculation logic
     let content_width = if content.is_empty() {
@@ -217,13 +258,13 @@ pub fn calculate_box_dimensions(
 
     let inner_width = match fixed_width {
         Some(fw) => fw.saturating_sub(2), // Account for borders
-        None => content_width + (2 * h_padding),
+        None => content_width + padding.width(),
     };
 
     let total_width = inner_width + 2; // Add border width
 
     let content_lines = content.lines().count().max(1);
-    let inner_height = content_lines + (2 * v_padding);
+    let inner_height = content_lines + padding.height();
     let total_height = inner_height + 2; // Add border height
 
     BoxDimensions {
@@ -231,8 +272,12 @@ pub fn calculate_box_dimensions(
         inner_width,
         total_height,
         inner_height,
-        h_padding,
-        v_padding,
+        h_padding: padding.left.max(padding.right),
+        v_padding: padding.top.max(padding.bottom),
+        padding,
+        margin,
+        outer_width: total_width + margin.width(),
+        outer_height: total_height + margin.height(),
     }
 }
 
@@ -278,6 +323,7 @@ mod tests {
 
     #[test]
     fn test_emoji_width_calculation() {
+        crate::width_calibration::install_uncalibrated();
         let metrics = get_text_metrics("Hello 🌟 World");
         assert_eq!(metrics.display_width, 14); // "Hello " (6) + 🌟 (2) + " World" (6) = 14
         assert_eq!(metrics.char_count, 13); // 13 characters including emoji as 1
@@ -286,6 +332,7 @@ mod tests {
 
     #[test]
     fn test_cjk_width_calculation() {
+        crate::width_calibration::install_uncalibrated();
         let metrics = get_text_metrics("Hello 中文 World");
         assert_eq!(metrics.display_width, 16); // 中文 = 4 columns
         assert_eq!(metrics.char_count, 14); // But only 2 characters
@@ -321,6 +368,7 @@ mod tests {
 
     #[test]
     fn test_unicode_content_with_styles() {
+        crate::width_calibration::install_uncalibrated();
         // Regression test: Unicode content width should be consistent across styles
         let emoji_content = "Hello 🌟 World";
         let cjk_content = "Hello 中文 World";
@@ -353,4 +401,21 @@ mod tests {
         assert_eq!(dims.total_width, 20);
         assert_eq!(dims.inner_width, 18); // 20 - 2 borders
     }
+
+    #[test]
+    fn test_per_side_padding_and_margin() {
+        let dims = calculate_box_dimensions_with(
+            "Hello",
+            NORMAL,
+            Spacing::new(1, 0, 2, 3),
+            Spacing::symmetric(1, 2),
+            None,
+        );
+
+        assert_eq!(dims.inner_width, 8); // 5 + 3 left padding
+        assert_eq!(dims.inner_height, 4); // 1 line + 1 top + 2 bottom
+        assert_eq!(dims.outer_width, 14); // 10 + 2*2 margin
+        assert_eq!(dims.outer_height, 8); // 6 + 2*1 margin
+        assert_eq!((dims.h_padding, dims.v_padding), (3, 2));
+    }
 }
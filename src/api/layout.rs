@@ -16,9 +16,17 @@
 //! - Builder pattern for flexibility
 //! - Pure component structure output
 
+use crate::api::diff::{DiffStyle, PaneRow, pane_widths};
 use crate::api::geometry::get_text_width;
+use crate::api::tree::{GUIDE_WIDTH, Guide, TreeGlyphs, TreeStyle};
+use crate::core::{
+    AlignedLine, BodyAlignment, BorderSides, MAX_HEIGHT, MAX_WIDTH, MIN_HEIGHT, MIN_WIDTH,
+    Overflow, OverflowPolicy, SanitizeOptions, SizeSpec, Spacing, align_line, expand_tabs,
+    leading_icon, sanitize_text, split_after_icon, split_aligned_lines,
+};
 use crate::truncate_with_ellipsis;
-use crate::visual::{BoxStyle, NORMAL};
+use crate::visual::divider::column_tees;
+use crate::visual::{BoxStyle, DividerStyle, NORMAL};
 
 /// Layout information for a positioned component
 #[derive(Debug, Clone)]
@@ -44,28 +52,397 @@ pub enum HorizontalAlign {
 }
 
 /// Vertical alignment options
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum VerticalAlign {
     Top,
     Middle,
     Bottom,
 }
 
+impl From<&str> for VerticalAlign {
+    fn from(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "middle" | "center" => Self::Middle,
+            "bottom" => Self::Bottom,
+            _ => Self::Top,
+        }
+    }
+}
+
+impl VerticalAlign {
+    /// Split `fill` blank rows into (above, below) the content
+    pub fn distribute(&self, fill: usize) -> (usize, usize) {
+        match self {
+            Self::Top => (0, fill),
+            Self::Middle => (fill / 2, fill - fill / 2),
+            Self::Bottom => (fill, 0),
+        }
+    }
+}
+
 /// Layout mode for box rendering
+///
+/// `Box` draws the frame edges selected by `BorderSides` (all four by default).
+/// `Bar` is not a `BorderSides` preset: sides are drawn whatever the content,
+/// while a bar only exists where a label is, so a bare body gets no rules at all.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LayoutMode {
     /// Full box with all borders (default)
     Box,
     /// Barmode - horizontal lines only, no vertical borders
+    ///
+    /// Looks like `BorderSides` `tb`, but the top bar is drawn only for an explicit
+    /// header and the bottom bar only for an explicit footer (colors and
+    /// `BoxLayout::edge_rows` follow the same rule).
     Bar,
 }
 
+/// Drop shadow cast to the right of and below a box
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shadow {
+    /// Single-column glyph the shadow is drawn with
+    pub glyph: String,
+    /// Optional color name, embedded as ANSI around the shadow glyphs
+    pub color: Option<String>,
+    /// Shadow columns to the right of the box
+    pub offset_x: usize,
+    /// Shadow rows below the box
+    pub offset_y: usize,
+}
+
+impl Default for Shadow {
+    fn default() -> Self {
+        Self {
+            glyph: "░".to_string(),
+            color: None,
+            offset_x: 1,
+            offset_y: 1,
+        }
+    }
+}
+
+impl Shadow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_glyph(mut self, glyph: &str) -> Self {
+        self.glyph = glyph.to_string();
+        self
+    }
+
+    pub fn with_color(mut self, color: &str) -> Self {
+        self.color = Some(color.to_string());
+        self
+    }
+
+    pub fn with_offset(mut self, x: usize, y: usize) -> Self {
+        self.offset_x = x;
+        self.offset_y = y;
+        self
+    }
+
+    /// Add the shadow to rendered lines, returning one entry per terminal row
+    ///
+    /// The first `offset_y` rows get blank columns so every row has the same width.
+    fn cast(&self, lines: &[String]) -> Vec<String> {
+        let column = self.paint(self.columns());
+        let blank = " ".repeat(self.columns());
+        let box_rows: Vec<&str> = lines.iter().flat_map(|c| c.lines()).collect();
+        let box_width = box_rows
+            .iter()
+            .map(|l| get_text_width(l))
+            .max()
+            .unwrap_or(0);
+
+        let mut rows: Vec<String> = box_rows
+            .into_iter()
+            .enumerate()
+            .map(|(row, line)| {
+                let fill = " ".repeat(box_width.saturating_sub(get_text_width(line)));
+                let edge = if row < self.offset_y { &blank } else { &column };
+                format!("{}{}{}", line, fill, edge)
+            })
+            .collect();
+
+        for _ in 0..self.offset_y {
+            rows.push(format!("{}{}", blank, self.paint(box_width)));
+        }
+        rows
+    }
+
+    /// Terminal columns the shadow occupies right of the box
+    pub fn columns(&self) -> usize {
+        self.offset_x * self.glyph_width()
+    }
+
+    fn glyph_width(&self) -> usize {
+        get_text_width(&self.glyph).max(1)
+    }
+
+    /// Glyphs covering `width` columns, colored when a color is set
+    fn paint(&self, width: usize) -> String {
+        let glyphs = self.glyph.repeat(width / self.glyph_width());
+        match self.color.as_deref().map(crate::get_color_code) {
+            Some(code) if !code.is_empty() && !glyphs.is_empty() => {
+                format!("{}{}{}", code, glyphs, crate::RESET)
+            }
+            _ => glyphs,
+        }
+    }
+}
+
+/// Indent rows by the left margin, pad them by the right one and add blank rows
+fn apply_margin(lines: &[String], margin: &Spacing) -> Vec<String> {
+    let rows: Vec<&str> = lines.iter().flat_map(|chunk| chunk.lines()).collect();
+    let width = rows.iter().map(|r| get_text_width(r)).max().unwrap_or(0);
+    let blank = " ".repeat(width + margin.width());
+    let left = " ".repeat(margin.left);
+
+    let mut result = vec![blank.clone(); margin.top];
+    result.extend(rows.iter().map(|row| {
+        let fill = width - get_text_width(row) + margin.right;
+        format!("{}{}{}", left, row, " ".repeat(fill))
+    }));
+    result.extend(std::iter::repeat_n(blank, margin.bottom));
+    result
+}
+
+/// Rows and columns of the frame edges in rendered output
+struct FrameMap {
+    top: Option<usize>,
+    bottom: Option<usize>,
+    left: Option<usize>,
+    right: Option<usize>,
+    dividers: Vec<usize>,
+    /// First column right of the box and first row below it, where a shadow falls
+    shadow: Option<(usize, usize)>,
+}
+
+impl FrameMap {
+    fn new(layout: &BoxLayout, rows: &[&str]) -> Self {
+        let sides = match layout.layout_mode {
+            LayoutMode::Box => layout.borders,
+            // Bars stand in for the header and footer only
+            LayoutMode::Bar => BorderSides {
+                top: layout.header.is_some(),
+                bottom: layout.footer.is_some(),
+                ..BorderSides::NONE
+            },
+        };
+        let first = layout.margin.top;
+        let end = rows
+            .len()
+            .saturating_sub(layout.margin.bottom + layout.shadow_extent().1);
+        let width = (layout.total_width + sides.columns()).saturating_sub(2);
+
+        let top = (sides.top && first < end).then_some(first);
+        let bottom = (sides.bottom && first < end).then(|| end - 1);
+        let left = sides.left.then_some(layout.margin.left);
+        let right = sides
+            .right
+            .then(|| layout.margin.left + width.saturating_sub(1));
+
+        // Any rule glyph a divider style can draw in this frame
+        let rules: Vec<char> = DividerStyle::ALL
+            .iter()
+            .filter_map(|d| d.glyphs(&layout.style).horizontal.chars().next())
+            .collect();
+        let is_rule = |ch: Option<char>| ch.is_some_and(|ch| rules.contains(&ch));
+        let dividers = (first..end)
+            .filter(|&row| Some(row) != top && Some(row) != bottom)
+            .filter(|&row| {
+                // Labeled dividers may start with text; one of the ends is still a rule
+                let plain = crate::strip_ansi_codes(rows[row]);
+                let mut inner = plain
+                    .chars()
+                    .skip(layout.margin.left + sides.left as usize)
+                    .take(width.saturating_sub(sides.columns()));
+                is_rule(inner.next()) || is_rule(inner.last())
+            })
+            .collect();
+
+        let shadow = layout
+            .shadow
+            .as_ref()
+            .map(|_| (layout.margin.left + width, end));
+
+        Self {
+            top,
+            bottom,
+            left,
+            right,
+            dividers,
+            shadow,
+        }
+    }
+
+    fn part(&self, row: usize, column: usize) -> crate::api::theming::BorderPart {
+        use crate::api::theming::BorderPart;
+
+        if let Some((shadow_column, shadow_row)) = self.shadow
+            && (column >= shadow_column || row >= shadow_row)
+        {
+            return BorderPart::Shadow;
+        }
+        let (left, right) = (Some(column) == self.left, Some(column) == self.right);
+        if Some(row) == self.top {
+            return match (left, right) {
+                (true, _) => BorderPart::TopLeft,
+                (_, true) => BorderPart::TopRight,
+                _ => BorderPart::Top,
+            };
+        }
+        if Some(row) == self.bottom {
+            return match (left, right) {
+                (true, _) => BorderPart::BottomLeft,
+                (_, true) => BorderPart::BottomRight,
+                _ => BorderPart::Bottom,
+            };
+        }
+        match (left, right) {
+            (true, _) => BorderPart::Left,
+            (_, true) => BorderPart::Right,
+            _ if self.dividers.contains(&row) => BorderPart::Divider,
+            _ => BorderPart::Inner,
+        }
+    }
+}
+
+/// Decoration around a label embedded in a top/bottom border line
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LabelDecoration {
+    /// Text sits directly on the border: `┌──Build──┐`
+    #[default]
+    Plain,
+    /// Text padded with a space on each side: `┌── Build ──┐`
+    Spaced,
+    /// Text enclosed by the style's tee glyphs: `┌─┤ Build ├─┐`
+    Bracket,
+}
+
+impl From<&str> for LabelDecoration {
+    fn from(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "spaced" => Self::Spaced,
+            "bracket" => Self::Bracket,
+            _ => Self::Plain,
+        }
+    }
+}
+
+/// Display width a border label needs, including decoration and inset
+fn label_width(text: &str, decoration: LabelDecoration, inset: usize) -> usize {
+    let chrome = match decoration {
+        LabelDecoration::Plain => 0,
+        LabelDecoration::Spaced => 2,
+        LabelDecoration::Bracket => 4,
+    };
+    get_text_width(text) + chrome + inset
+}
+
+/// Look of labeled section dividers in the body: `├─ Errors ─────┤`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SectionStyle {
+    align: HorizontalAlign,
+    decoration: LabelDecoration,
+    inset: usize,
+    divider: DividerStyle,
+}
+
+impl SectionStyle {
+    /// Left-aligned, spaced label one glyph in from the edge, frame glyphs
+    pub fn new() -> Self {
+        Self {
+            align: HorizontalAlign::Left,
+            decoration: LabelDecoration::Spaced,
+            inset: 1,
+            divider: DividerStyle::Frame,
+        }
+    }
+
+    pub fn align_left(mut self) -> Self {
+        self.align = HorizontalAlign::Left;
+        self
+    }
+
+    pub fn align_center(mut self) -> Self {
+        self.align = HorizontalAlign::Center;
+        self
+    }
+
+    pub fn align_right(mut self) -> Self {
+        self.align = HorizontalAlign::Right;
+        self
+    }
+
+    /// Set the decoration drawn around the label
+    pub fn with_decoration(mut self, decoration: LabelDecoration) -> Self {
+        self.decoration = decoration;
+        self
+    }
+
+    /// Keep `inset` rule glyphs between the edge and a left/right placed label
+    pub fn with_inset(mut self, inset: usize) -> Self {
+        self.inset = inset;
+        self
+    }
+
+    /// Rule weight or pattern for dividers that do not set their own
+    pub fn with_divider_style(mut self, divider: DividerStyle) -> Self {
+        self.divider = divider;
+        self
+    }
+
+    /// Inner width a divider needs to show `label` in full
+    fn width_for(&self, label: &str) -> usize {
+        let inset = match self.align {
+            HorizontalAlign::Center => 0,
+            _ => self.inset,
+        };
+        label_width(label, self.decoration, inset)
+    }
+
+    /// Divider row across `inner_width`, label (if any) embedded in the rule
+    fn render(&self, divider: &Divider, inner_width: usize, frame: &BoxStyle) -> String {
+        let weight = divider.style.unwrap_or(self.divider);
+        let glyphs = weight.glyphs(frame);
+        let rule = match &divider.label {
+            Some(text) => {
+                let (open, close) = weight.label_brackets(frame);
+                HeaderBuilder {
+                    content: None,
+                    align: self.align.clone(),
+                    style: BoxStyle {
+                        tee_right: open,
+                        tee_left: close,
+                        ..glyphs
+                    },
+                    decoration: self.decoration,
+                    inset: self.inset,
+                }
+                .render_header_line(text, inner_width)
+            }
+            None => glyphs.horizontal.repeat(inner_width),
+        };
+        format!("{}{}{}", glyphs.tee_left, rule, glyphs.tee_right)
+    }
+}
+
+impl Default for SectionStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Builder for header components
 #[derive(Debug, Clone)]
 pub struct HeaderBuilder {
     content: Option<String>,
     align: HorizontalAlign,
     style: BoxStyle,
+    decoration: LabelDecoration,
+    inset: usize,
 }
 
 impl HeaderBuilder {
@@ -74,6 +451,8 @@ impl HeaderBuilder {
             content: Some(content.to_string()),
             align: HorizontalAlign::Center,
             style: NORMAL,
+            decoration: LabelDecoration::Plain,
+            inset: 0,
         }
     }
 
@@ -82,9 +461,30 @@ impl HeaderBuilder {
             content: None,
             align: HorizontalAlign::Center,
             style: NORMAL,
+            decoration: LabelDecoration::Plain,
+            inset: 0,
         }
     }
 
+    /// Resolve tabs and control characters in the label
+    fn sanitize(&mut self, options: &SanitizeOptions) {
+        if let Some(content) = &mut self.content {
+            *content = sanitize_text(content, options).0;
+        }
+    }
+
+    /// Set the decoration drawn around the label in the border line
+    pub fn with_decoration(mut self, decoration: LabelDecoration) -> Self {
+        self.decoration = decoration;
+        self
+    }
+
+    /// Keep `inset` border glyphs between the corner and a left/right placed label
+    pub fn with_inset(mut self, inset: usize) -> Self {
+        self.inset = inset;
+        self
+    }
+
     pub fn align_left(mut self) -> Self {
         self.align = HorizontalAlign::Left;
         self
@@ -132,18 +532,42 @@ impl HeaderBuilder {
     }
 
     fn render_header_line(&self, text: &str, inner_width: usize) -> String {
-        let text_width = get_text_width(text);
-
-        if text_width >= inner_width {
-            // Truncate if too long
-            return self.truncate_to_width(text, inner_width);
-        }
+        let (open, close) = match self.decoration {
+            LabelDecoration::Plain => (String::new(), String::new()),
+            LabelDecoration::Spaced => (" ".to_string(), " ".to_string()),
+            LabelDecoration::Bracket => (
+                format!("{} ", self.style.tee_right),
+                format!(" {}", self.style.tee_left),
+            ),
+        };
+        let inset = match self.align {
+            HorizontalAlign::Center => 0,
+            HorizontalAlign::Left | HorizontalAlign::Right => self.inset,
+        };
+        let chrome_width = get_text_width(&open) + get_text_width(&close) + inset;
+        let available = inner_width.saturating_sub(chrome_width);
 
-        let remaining = inner_width - text_width;
+        let label = if get_text_width(text) > available {
+            if available <= 3 {
+                // No room for a readable label - plain border
+                return self.style.horizontal.repeat(inner_width);
+            }
+            truncate_with_ellipsis(text, available)
+        } else {
+            text.to_string()
+        };
+        let label = format!("{}{}{}", open, label, close);
+        let remaining = inner_width.saturating_sub(get_text_width(&label) + inset);
+        let inset_fill = self.style.horizontal.repeat(inset);
 
         match self.align {
             HorizontalAlign::Left => {
-                format!("{}{}", text, self.style.horizontal.repeat(remaining))
+                format!(
+                    "{}{}{}",
+                    inset_fill,
+                    label,
+                    self.style.horizontal.repeat(remaining)
+                )
             }
             HorizontalAlign::Center => {
                 let left_fill = remaining / 2;
@@ -151,36 +575,20 @@ impl HeaderBuilder {
                 format!(
                     "{}{}{}",
                     self.style.horizontal.repeat(left_fill),
-                    text,
+                    label,
                     self.style.horizontal.repeat(right_fill)
                 )
             }
             HorizontalAlign::Right => {
-                format!("{}{}", self.style.horizontal.repeat(remaining), text)
+                format!(
+                    "{}{}{}",
+                    self.style.horizontal.repeat(remaining),
+                    label,
+                    inset_fill
+                )
             }
         }
     }
-
-    fn truncate_to_width(&self, text: &str, max_width: usize) -> String {
-        if max_width <= 3 {
-            return self.style.horizontal.repeat(max_width);
-        }
-
-        // Pass the full width - truncate_with_ellipsis handles ellipsis internally
-        let truncated = truncate_with_ellipsis(text, max_width);
-        let truncated_width = get_text_width(&truncated);
-
-        // Fill remaining with horizontal line
-        if truncated_width < max_width {
-            format!(
-                "{}{}",
-                truncated,
-                self.style.horizontal.repeat(max_width - truncated_width)
-            )
-        } else {
-            truncated
-        }
-    }
 }
 
 /// Builder for footer components
@@ -189,6 +597,8 @@ pub struct FooterBuilder {
     content: Option<String>,
     align: HorizontalAlign,
     style: BoxStyle,
+    decoration: LabelDecoration,
+    inset: usize,
 }
 
 impl FooterBuilder {
@@ -197,6 +607,8 @@ impl FooterBuilder {
             content: Some(content.to_string()),
             align: HorizontalAlign::Center,
             style: NORMAL,
+            decoration: LabelDecoration::Plain,
+            inset: 0,
         }
     }
 
@@ -205,9 +617,30 @@ impl FooterBuilder {
             content: None,
             align: HorizontalAlign::Center,
             style: NORMAL,
+            decoration: LabelDecoration::Plain,
+            inset: 0,
+        }
+    }
+
+    /// Resolve tabs and control characters in the label
+    fn sanitize(&mut self, options: &SanitizeOptions) {
+        if let Some(content) = &mut self.content {
+            *content = sanitize_text(content, options).0;
         }
     }
 
+    /// Set the decoration drawn around the label in the border line
+    pub fn with_decoration(mut self, decoration: LabelDecoration) -> Self {
+        self.decoration = decoration;
+        self
+    }
+
+    /// Keep `inset` border glyphs between the corner and a left/right placed label
+    pub fn with_inset(mut self, inset: usize) -> Self {
+        self.inset = inset;
+        self
+    }
+
     pub fn align_left(mut self) -> Self {
         self.align = HorizontalAlign::Left;
         self
@@ -259,42 +692,198 @@ impl FooterBuilder {
             content: Some(text.to_string()),
             align: self.align.clone(),
             style: self.style,
+            decoration: self.decoration,
+            inset: self.inset,
         };
         header_builder.render_header_line(text, inner_width)
     }
 }
 
+/// Zone of the status line a segment is anchored to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusZone {
+    Left,
+    Center,
+    Right,
+}
+
+impl StatusZone {
+    /// Tie-break when priorities match: center gives way first, then right, then left
+    fn yield_rank(self) -> u8 {
+        match self {
+            Self::Center => 0,
+            Self::Right => 1,
+            Self::Left => 2,
+        }
+    }
+
+    fn from_prefix(prefix: &str) -> Option<Self> {
+        match prefix {
+            "sl" => Some(Self::Left),
+            "sc" => Some(Self::Center),
+            "sr" => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+/// One piece of status text pinned to a zone
+///
+/// Segment colors are embedded as ANSI codes around the segment text, so a
+/// colored segment is the one place a status component carries escapes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusSegment {
+    pub text: String,
+    pub zone: StatusZone,
+    /// Higher priority segments keep their text longer when zones collide
+    pub priority: u8,
+    pub color: Option<String>,
+}
+
+impl StatusSegment {
+    pub fn new(text: &str, zone: StatusZone) -> Self {
+        Self {
+            text: text.to_string(),
+            zone,
+            priority: STATUS_DEFAULT_PRIORITY,
+            color: None,
+        }
+    }
+
+    pub fn with_priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_color(mut self, color: &str) -> Self {
+        self.color = Some(color.to_string());
+        self
+    }
+
+    /// Parse a `sl:` / `sc:` / `sr:` spec
+    ///
+    /// Attributes may follow the zone before the colon: a number sets the
+    /// priority, anything else is a color name (`sc,red,3:3 errors`).
+    /// Returns None when the text has no zone prefix.
+    pub fn parse(spec: &str) -> Option<Self> {
+        let (head, text) = spec.split_once(':')?;
+        let mut attrs = head.split(',');
+        let zone = StatusZone::from_prefix(attrs.next()?)?;
+        let mut segment = Self::new(text, zone);
+
+        for attr in attrs {
+            let attr = attr.trim();
+            if attr.is_empty() || attr.contains(' ') {
+                return None;
+            }
+            match attr.parse::<u8>() {
+                Ok(priority) => segment.priority = priority,
+                Err(_) => segment.color = Some(attr.to_string()),
+            }
+        }
+        Some(segment)
+    }
+}
+
+/// Priority given to segments that don't set one
+pub const STATUS_DEFAULT_PRIORITY: u8 = 1;
+
+/// Minimum blank columns kept between neighbouring zones
+const STATUS_ZONE_GAP: usize = 2;
+
 /// Builder for status bar components
 #[derive(Debug)]
 pub struct StatusBuilder {
-    content: String,
-    align: HorizontalAlign,
+    segments: Vec<StatusSegment>,
+    color: Option<String>,
     show_divider: bool,
     padding: usize,
+    divider_style: DividerStyle,
 }
 
 impl StatusBuilder {
     pub fn new(content: &str) -> Self {
         Self {
-            content: content.to_string(),
-            align: HorizontalAlign::Left,
+            segments: vec![StatusSegment::new(content, StatusZone::Left)],
+            color: None,
             show_divider: true,
             padding: 1,
+            divider_style: DividerStyle::Frame,
         }
     }
 
-    pub fn align_left(mut self) -> Self {
-        self.align = HorizontalAlign::Left;
+    /// Start an empty status line and add zones with left/center/right
+    pub fn segmented() -> Self {
+        Self {
+            segments: Vec::new(),
+            ..Self::new("")
+        }
+    }
+
+    /// Build from CLI-style status text
+    ///
+    /// `sl:`/`sc:`/`sr:` prefixes pick the zone; `|` joins several prefixed
+    /// segments on one line (`sl:main|sc,red:3 errors|sr:12:04`). Text that
+    /// isn't fully prefixed stays a single segment.
+    pub fn parse(spec: &str) -> Self {
+        let parts: Option<Vec<StatusSegment>> = spec.split('|').map(StatusSegment::parse).collect();
+        let segments = match parts {
+            Some(parts) => parts,
+            None => vec![
+                StatusSegment::parse(spec)
+                    .unwrap_or_else(|| StatusSegment::new(spec, StatusZone::Left)),
+            ],
+        };
+        Self {
+            segments,
+            ..Self::new("")
+        }
+    }
+
+    pub fn left(self, text: &str) -> Self {
+        self.with_segment(StatusSegment::new(text, StatusZone::Left))
+    }
+
+    pub fn center(self, text: &str) -> Self {
+        self.with_segment(StatusSegment::new(text, StatusZone::Center))
+    }
+
+    pub fn right(self, text: &str) -> Self {
+        self.with_segment(StatusSegment::new(text, StatusZone::Right))
+    }
+
+    pub fn with_segment(mut self, segment: StatusSegment) -> Self {
+        self.segments.push(segment);
         self
     }
 
-    pub fn align_center(mut self) -> Self {
-        self.align = HorizontalAlign::Center;
+    /// Color for segments that don't set their own
+    pub fn with_color(mut self, color: &str) -> Self {
+        self.color = Some(color.to_string());
         self
     }
 
-    pub fn align_right(mut self) -> Self {
-        self.align = HorizontalAlign::Right;
+    pub fn segments(&self) -> &[StatusSegment] {
+        &self.segments
+    }
+
+    pub fn align_left(self) -> Self {
+        self.align_primary(StatusZone::Left)
+    }
+
+    pub fn align_center(self) -> Self {
+        self.align_primary(StatusZone::Center)
+    }
+
+    pub fn align_right(self) -> Self {
+        self.align_primary(StatusZone::Right)
+    }
+
+    /// Alignment moves the first segment, which is all a single-text status has
+    fn align_primary(mut self, zone: StatusZone) -> Self {
+        if let Some(first) = self.segments.first_mut() {
+            first.zone = zone;
+        }
         self
     }
 
@@ -308,18 +897,32 @@ impl StatusBuilder {
         self
     }
 
+    /// Resolve tabs and control characters in every segment
+    fn sanitize(&mut self, options: &SanitizeOptions) {
+        for segment in &mut self.segments {
+            segment.text = sanitize_text(&segment.text, options).0;
+        }
+    }
+
+    /// Draw the divider above the status line with its own weight or pattern
+    pub fn with_divider_style(mut self, style: DividerStyle) -> Self {
+        self.divider_style = style;
+        self
+    }
+
+    /// Width the status line needs with every segment shown in full
+    pub fn natural_width(&self) -> usize {
+        let texts: Vec<Option<String>> =
+            self.segments.iter().map(|s| Some(s.text.clone())).collect();
+        self.zones_width(&texts)
+    }
+
     pub fn build_for_width(self, inner_width: usize, style: BoxStyle) -> BoxyLayout {
         let mut lines = Vec::new();
 
         // Add divider if requested
         if self.show_divider {
-            let divider = format!(
-                "{}{}{}",
-                style.tee_left,
-                style.horizontal.repeat(inner_width),
-                style.tee_right
-            );
-            lines.push(divider);
+            lines.push(self.divider_style.line(&style, inner_width));
         }
 
         // Add padding lines
@@ -334,7 +937,7 @@ impl StatusBuilder {
         }
 
         // Add status content line
-        let status_line = self.render_status_line(&self.content, inner_width, &style);
+        let status_line = self.render_status_line(inner_width, &style);
         lines.push(status_line);
 
         // Add bottom padding lines
@@ -359,98 +962,378 @@ impl StatusBuilder {
         }
     }
 
-    fn render_status_line(&self, text: &str, inner_width: usize, style: &BoxStyle) -> String {
+    fn render_status_line(&self, inner_width: usize, style: &BoxStyle) -> String {
         let available_width = inner_width.saturating_sub(2 * self.padding);
-        let text_width = get_text_width(text);
+        let texts = self.fit_segments(available_width);
+
+        let zone_width = |zone: StatusZone| get_text_width(&self.zone_text(&texts, zone, false));
+        let (left_w, center_w, right_w) = (
+            zone_width(StatusZone::Left),
+            zone_width(StatusZone::Center),
+            zone_width(StatusZone::Right),
+        );
 
-        let (left_pad, right_pad) = if text_width >= available_width {
-            (0, 0) // Will truncate
+        // Center sits in the middle unless that would crowd a neighbouring zone
+        let center_start = if center_w == 0 {
+            left_w
         } else {
-            let space = available_width - text_width;
-            match self.align {
-                HorizontalAlign::Left => (0, space),
-                HorizontalAlign::Center => {
-                    let left = space / 2;
-                    (left, space - left)
-                }
-                HorizontalAlign::Right => (space, 0),
+            let lower = if left_w > 0 {
+                left_w + STATUS_ZONE_GAP
+            } else {
+                0
+            };
+            let upper = if right_w > 0 {
+                available_width.saturating_sub(right_w + STATUS_ZONE_GAP + center_w)
+            } else {
+                available_width.saturating_sub(center_w)
+            };
+            (available_width.saturating_sub(center_w) / 2)
+                .max(lower)
+                .min(upper)
+        };
+        let center_end = center_start + center_w;
+        let right_start = available_width.saturating_sub(right_w).max(center_end);
+
+        let status_content = format!(
+            "{}{}{}{}{}",
+            self.zone_text(&texts, StatusZone::Left, true),
+            " ".repeat(center_start.saturating_sub(left_w)),
+            self.zone_text(&texts, StatusZone::Center, true),
+            " ".repeat(right_start - center_end),
+            self.zone_text(&texts, StatusZone::Right, true),
+        );
+        let content_width = right_start + right_w;
+
+        format!(
+            "{}{}{}{}{}{}",
+            style.vertical,
+            " ".repeat(self.padding),
+            status_content,
+            " ".repeat(available_width.saturating_sub(content_width)),
+            " ".repeat(self.padding),
+            style.vertical
+        )
+    }
+
+    /// Shrink segments until every zone fits, lowest priority first
+    ///
+    /// A segment is truncated with an ellipsis while it can still show a
+    /// character, then dropped. Returns the text to show per segment.
+    fn fit_segments(&self, available_width: usize) -> Vec<Option<String>> {
+        let mut texts: Vec<Option<String>> =
+            self.segments.iter().map(|s| Some(s.text.clone())).collect();
+
+        loop {
+            let required = self.zones_width(&texts);
+            if required <= available_width {
+                return texts;
             }
+
+            let victim = (0..self.segments.len())
+                .filter(|&i| texts[i].is_some())
+                .min_by_key(|&i| {
+                    let segment = &self.segments[i];
+                    (
+                        segment.priority,
+                        segment.zone.yield_rank(),
+                        std::cmp::Reverse(i),
+                    )
+                });
+            let Some(victim) = victim else {
+                return texts;
+            };
+
+            let current = texts[victim].as_deref().map(get_text_width).unwrap_or(0);
+            let target = current.saturating_sub(required - available_width);
+            texts[victim] = if target >= 2 {
+                Some(truncate_with_ellipsis(&self.segments[victim].text, target))
+            } else {
+                None
+            };
+        }
+    }
+
+    fn zones_width(&self, texts: &[Option<String>]) -> usize {
+        let widths: Vec<usize> = [StatusZone::Left, StatusZone::Center, StatusZone::Right]
+            .into_iter()
+            .map(|zone| get_text_width(&self.zone_text(texts, zone, false)))
+            .filter(|&w| w > 0)
+            .collect();
+        widths.iter().sum::<usize>() + STATUS_ZONE_GAP * widths.len().saturating_sub(1)
+    }
+
+    /// Segments sharing a zone are joined by a single space
+    fn zone_text(&self, texts: &[Option<String>], zone: StatusZone, colored: bool) -> String {
+        self.segments
+            .iter()
+            .zip(texts)
+            .filter(|(segment, text)| {
+                segment.zone == zone && text.as_ref().is_some_and(|t| !t.is_empty())
+            })
+            .map(|(segment, text)| {
+                let text = text.as_deref().unwrap_or_default();
+                let color = segment.color.as_ref().or(self.color.as_ref());
+                match color.map(|c| crate::get_color_code(c)) {
+                    Some(code) if colored && !code.is_empty() => {
+                        format!("{}{}{}", code, text, crate::RESET)
+                    }
+                    _ => text.to_string(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Layout options for code bodies (`BodyBuilder::code`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeStyle {
+    /// Columns per tab stop
+    pub tab_width: usize,
+    /// Number lines in a gutter separated by the style's vertical glyph
+    pub line_numbers: bool,
+    /// Break long lines onto continuation rows instead of truncating them
+    pub soft_wrap: bool,
+    /// Marker that starts each continuation row
+    pub continuation: String,
+}
+
+impl CodeStyle {
+    pub fn new() -> Self {
+        Self {
+            tab_width: 4,
+            line_numbers: false,
+            soft_wrap: false,
+            continuation: "↪".to_string(),
+        }
+    }
+
+    pub fn with_tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    pub fn with_line_numbers(mut self, enabled: bool) -> Self {
+        self.line_numbers = enabled;
+        self
+    }
+
+    pub fn with_soft_wrap(mut self, enabled: bool) -> Self {
+        self.soft_wrap = enabled;
+        self
+    }
+
+    pub fn with_continuation(mut self, marker: &str) -> Self {
+        self.continuation = marker.to_string();
+        self
+    }
+
+    /// Lay out one (tab-expanded) code line within `width` columns
+    fn layout_line(
+        &self,
+        text: &str,
+        number: usize,
+        digits: usize,
+        width: usize,
+        style: &BoxStyle,
+    ) -> Vec<String> {
+        let (gutter, blank_gutter) = match self.line_numbers {
+            true => (
+                format!("{:>digits$} {} ", number, style.vertical),
+                format!("{} {} ", " ".repeat(digits), style.vertical),
+            ),
+            false => (String::new(), String::new()),
         };
+        let room = width.saturating_sub(get_text_width(&gutter));
+
+        if get_text_width(text) <= room {
+            return vec![format!("{}{}", gutter, text)];
+        }
+        let marker = format!("{} ", self.continuation);
+        let marker_width = get_text_width(&marker);
+        if !self.soft_wrap || room <= marker_width {
+            return vec![format!("{}{}", gutter, truncate_with_ellipsis(text, room))];
+        }
+
+        let (head, mut rest) = split_at_columns(text, room);
+        let mut rows = vec![format!("{}{}", gutter, head)];
+        while !rest.is_empty() {
+            let (head, tail) = split_at_columns(&rest, room - marker_width);
+            rows.push(format!("{}{}{}", blank_gutter, marker, head));
+            rest = tail;
+        }
+        rows
+    }
+}
+
+impl Default for CodeStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Split `text` after at most `width` display columns (at least one character)
+fn split_at_columns(text: &str, width: usize) -> (String, String) {
+    let mut used = 0;
+    for (index, ch) in text.char_indices() {
+        let ch_width = get_text_width(ch.encode_utf8(&mut [0; 4]));
+        if used + ch_width > width && index > 0 {
+            return (text[..index].to_string(), text[index..].to_string());
+        }
+        used += ch_width;
+    }
+    (text.to_string(), String::new())
+}
+
+/// How a body row is laid out
+#[derive(Debug, Clone, PartialEq)]
+enum BodyRow {
+    /// Plain text: inline markers honored, wrapped when enabled
+    Text,
+    /// Row of a nested box: never wrapped or split
+    Block,
+    /// Text after a marker (e.g. a list bullet); wrapped lines indent past the marker
+    Hanging(String),
+    /// Horizontal divider across the full inner width, optionally labeled
+    Rule(Divider),
+    /// Tree node: connectors drawn in the box's glyphs; the label is truncated, never wrapped
+    Tree(Vec<Guide>),
+    /// Code line: never word-wrapped; `number` is 1-based, `digits` sizes the gutter
+    Code {
+        number: usize,
+        digits: usize,
+        style: CodeStyle,
+    },
+    /// Row of a split body: two panes either side of a center rule
+    Pane(PaneRow),
+}
 
-        let status_content = if text_width > available_width {
-            self.truncate_status(text, available_width)
-        } else {
-            format!("{}{}{}", " ".repeat(left_pad), text, " ".repeat(right_pad))
-        };
+/// A divider row in the body; `style` overrides the body's section style
+#[derive(Debug, Clone, PartialEq)]
+struct Divider {
+    label: Option<String>,
+    style: Option<DividerStyle>,
+}
 
-        format!(
-            "{}{}{}{}{}",
-            style.vertical,
-            " ".repeat(self.padding),
-            status_content,
-            " ".repeat(self.padding),
-            style.vertical
-        )
+impl Divider {
+    fn new(label: &str, style: Option<DividerStyle>) -> Self {
+        let label = label.trim();
+        Self {
+            label: (!label.is_empty()).then(|| label.to_string()),
+            style,
+        }
     }
+}
 
-    fn truncate_status(&self, text: &str, max_width: usize) -> String {
-        if max_width <= 3 {
-            return " ".repeat(max_width);
-        }
+/// A laid-out body row: aligned text, a divider or a row drawn whole
+enum BodyLine {
+    Text(AlignedLine),
+    /// Row sized by its own layout (nested box, tree, code, pane); never emoji-indented
+    Fixed(AlignedLine),
+    Rule(Divider),
+    /// Complete row, frame edges included (rules crossing a split body)
+    Drawn(String),
+}
 
-        let truncated = truncate_with_ellipsis(text, max_width);
-        let truncated_width = get_text_width(&truncated);
+/// Parse a divider marker line: `#DIV#`, `#DIV:Label#`, with an optional
+/// style after a dot: `#DIV.double:Totals#`, `#DIV.dashed#`
+fn divider_marker(line: &str) -> Option<Divider> {
+    let rest = line.trim().strip_prefix("#DIV")?.strip_suffix('#')?;
+    let (spec, label) = rest.split_once(':').unwrap_or((rest, ""));
+    let style = match spec {
+        "" => None,
+        _ => Some(DividerStyle::from_name(spec.strip_prefix('.')?).ok()?),
+    };
+    Some(Divider::new(label, style))
+}
 
-        // Pad to full width
-        format!(
-            "{}{}",
-            truncated,
-            " ".repeat(max_width.saturating_sub(truncated_width))
-        )
+/// Rows for plain text; marker lines (also between `#NL#` hints) become dividers
+fn text_rows(lines: Vec<String>) -> (Vec<String>, Vec<BodyRow>) {
+    let mut texts = Vec::new();
+    let mut rows = Vec::new();
+    for line in lines {
+        if !line.contains("#DIV") {
+            texts.push(line);
+            rows.push(BodyRow::Text);
+            continue;
+        }
+        let mut pending: Vec<&str> = Vec::new();
+        for piece in line.split("#NL#") {
+            let Some(divider) = divider_marker(piece) else {
+                pending.push(piece);
+                continue;
+            };
+            if !pending.is_empty() {
+                texts.push(pending.join("#NL#"));
+                rows.push(BodyRow::Text);
+                pending.clear();
+            }
+            texts.push(String::new());
+            rows.push(BodyRow::Rule(divider));
+        }
+        if !pending.is_empty() {
+            texts.push(pending.join("#NL#"));
+            rows.push(BodyRow::Text);
+        }
     }
+    (texts, rows)
 }
 
 /// Builder for body content components
+///
+/// A line `#DIV:Label#` in the text (or between `#NL#` hints) becomes a
+/// labeled divider, `#DIV#` a plain one; see also `section`.
 #[derive(Debug)]
 pub struct BodyBuilder {
     lines: Vec<String>,
-    h_padding: usize,
-    v_padding: usize,
+    /// Parallel to `lines`: how each row is laid out
+    rows: Vec<BodyRow>,
+    padding: Spacing,
     enable_wrapping: bool,
     title: Option<String>,
     icon: Option<String>,
+    alignment: BodyAlignment,
+    pad_emoji: bool,
+    section_style: SectionStyle,
+    sanitize: SanitizeOptions,
 }
 
 impl BodyBuilder {
     pub fn new(content: &str) -> Self {
-        Self {
-            lines: content.lines().map(|s| s.to_string()).collect(),
-            h_padding: 2,
-            v_padding: 0,
-            enable_wrapping: true,
-            title: None,
-            icon: None,
-        }
+        Self::from_lines(content.lines().map(|s| s.to_string()).collect())
     }
 
     pub fn from_lines(lines: Vec<String>) -> Self {
+        let (lines, rows) = text_rows(lines);
         Self {
+            rows,
             lines,
-            h_padding: 2,
-            v_padding: 0,
+            padding: Spacing::horizontal(2),
             enable_wrapping: true,
             title: None,
             icon: None,
+            alignment: BodyAlignment::Left,
+            pad_emoji: false,
+            section_style: SectionStyle::new(),
+            sanitize: SanitizeOptions::default(),
         }
     }
 
     pub fn with_h_padding(mut self, padding: usize) -> Self {
-        self.h_padding = padding;
+        self.padding.left = padding;
+        self.padding.right = padding;
         self
     }
 
     pub fn with_v_padding(mut self, padding: usize) -> Self {
-        self.v_padding = padding;
+        self.padding.top = padding;
+        self.padding.bottom = padding;
+        self
+    }
+
+    /// Set padding for each side independently
+    pub fn with_padding_sides(mut self, padding: Spacing) -> Self {
+        self.padding = padding;
         self
     }
 
@@ -474,65 +1357,394 @@ impl BodyBuilder {
         self
     }
 
-    pub fn build_for_width(self, inner_width: usize, style: BoxStyle) -> BoxyLayout {
+    /// Set default alignment for body lines (left/center/right/justify)
+    /// Individual lines can override it with a leading `#L#`/`#C#`/`#R#`/`#J#` marker
+    pub fn with_alignment(mut self, alignment: BodyAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Indent lines after the icon line so they align with the text after the icon
+    pub fn with_emoji_padding(mut self, enabled: bool) -> Self {
+        self.pad_emoji = enabled;
+        self
+    }
+
+    /// Tab width and control character handling for text rows
+    pub fn with_sanitize_options(mut self, options: SanitizeOptions) -> Self {
+        self.sanitize = options;
+        self
+    }
+
+    /// Resolve tabs and control characters in the title, every row of text
+    /// and every divider label
+    fn sanitize_rows(&mut self) {
+        let options = &self.sanitize;
+        if let Some(title) = &mut self.title {
+            *title = sanitize_text(title, options).0;
+        }
+        for (line, row) in self.lines.iter_mut().zip(&mut self.rows) {
+            match row {
+                BodyRow::Rule(divider) => {
+                    divider.label = divider
+                        .label
+                        .as_deref()
+                        .map(|label| sanitize_text(label, options).0);
+                }
+                BodyRow::Pane(_) => {}
+                _ => *line = sanitize_text(line, options).0,
+            }
+        }
+    }
+
+    /// Append a labeled divider followed by `content`: `├─ Errors ─────┤`
+    pub fn section(self, label: &str, content: &str) -> Self {
+        let mut body = self.push_divider(Divider::new(label, None));
+        let (lines, rows) = text_rows(content.lines().map(|s| s.to_string()).collect());
+        body.lines.extend(lines);
+        body.rows.extend(rows);
+        body
+    }
+
+    /// Append a divider of its own weight or pattern; an empty label draws a plain rule
+    pub fn divider(self, label: &str, style: DividerStyle) -> Self {
+        self.push_divider(Divider::new(label, Some(style)))
+    }
+
+    fn push_divider(mut self, divider: Divider) -> Self {
+        self.lines.push(String::new());
+        self.rows.push(BodyRow::Rule(divider));
+        self
+    }
+
+    /// Set label alignment, decoration and rule style for section dividers
+    pub fn with_section_style(mut self, style: SectionStyle) -> Self {
+        self.section_style = style;
+        self
+    }
+
+    /// Append a nested box below the current content
+    /// Its rows are kept whole: never wrapped, and the parent grows to fit them
+    pub fn with_child(self, child: BoxLayout) -> Self {
+        self.with_block(&child.render())
+    }
+
+    /// Append pre-rendered text (e.g. an already colored box) as one atomic block
+    /// Rows are padded to the block's widest row so the block keeps its shape when aligned
+    pub fn with_block(mut self, block: &str) -> Self {
+        let rows: Vec<&str> = block.lines().collect();
+        let block_width = rows.iter().map(|r| get_text_width(r)).max().unwrap_or(0);
+        for row in rows {
+            let fill = block_width.saturating_sub(get_text_width(row));
+            self.lines.push(format!("{}{}", row, " ".repeat(fill)));
+            self.rows.push(BodyRow::Block);
+        }
+        self
+    }
+
+    /// Body holding source code (see `CodeStyle`)
+    pub fn code(source: &str, style: CodeStyle) -> Self {
+        Self::from_lines(Vec::new()).with_code(source, style)
+    }
+
+    /// Append source code: indentation kept, tabs expanded, long lines truncated or soft-wrapped
+    pub fn with_code(mut self, source: &str, style: CodeStyle) -> Self {
+        let lines: Vec<&str> = source.lines().collect();
+        let digits = lines.len().to_string().len();
+        for (index, line) in lines.iter().enumerate() {
+            self.lines.push(expand_tabs(line, style.tab_width));
+            self.rows.push(BodyRow::Code {
+                number: index + 1,
+                digits,
+                style: style.clone(),
+            });
+        }
+        self
+    }
+
+    /// Body holding a tree (see `api::tree`)
+    pub fn tree(source: &str, style: TreeStyle) -> Self {
+        Self::from_lines(Vec::new()).with_tree(source, style)
+    }
+
+    /// Append indented text, paths or JSON drawn as a tree with `├──`/`└──` connectors
+    pub fn with_tree(mut self, source: &str, style: TreeStyle) -> Self {
+        for row in style.rows(source) {
+            self.lines.push(row.label);
+            self.rows.push(BodyRow::Tree(row.guides));
+        }
+        self
+    }
+
+    /// Body holding a side-by-side diff (see `api::diff`)
+    pub fn diff(old: &str, new: &str, style: DiffStyle) -> Self {
+        Self::from_lines(Vec::new()).with_diff(old, new, style)
+    }
+
+    /// Append `old` and `new` in split panes, lines aligned and changes marked
+    pub fn with_diff(mut self, old: &str, new: &str, style: DiffStyle) -> Self {
+        for row in style.rows(old, new) {
+            self.lines.push(String::new());
+            self.rows.push(BodyRow::Pane(row));
+        }
+        self
+    }
+
+    /// Append markdown rendered into body rows (see `api::markdown`)
+    /// Fenced code blocks become nested boxes; list items wrap with a hanging indent
+    pub fn with_markdown(mut self, source: &str) -> Self {
+        use crate::api::markdown::{MarkdownRow, parse_markdown};
+
+        for row in parse_markdown(source) {
+            match row {
+                MarkdownRow::Line(text) => {
+                    self.lines.push(text);
+                    self.rows.push(BodyRow::Text);
+                }
+                MarkdownRow::Item { marker, text } => {
+                    self.lines.push(text);
+                    self.rows.push(BodyRow::Hanging(marker));
+                }
+                MarkdownRow::Rule => {
+                    self.lines.push(String::new());
+                    self.rows.push(BodyRow::Rule(Divider::new("", None)));
+                }
+                MarkdownRow::Code { lang, lines } => {
+                    let mut code = BoxBuilder::new("")
+                        .with_code(&lines.join("\n"), CodeStyle::new())
+                        .with_style(crate::ROUNDED)
+                        .with_h_padding(1);
+                    if let Some(lang) = lang {
+                        code = code.with_header(
+                            HeaderBuilder::new(&lang)
+                                .align_left()
+                                .with_decoration(LabelDecoration::Spaced),
+                        );
+                    }
+                    self = self.with_child(code.build());
+                }
+            }
+        }
+        self
+    }
+
+    /// Widest body row; inline alignment markers take no space, block rows count as-is
+    fn content_width(&self) -> usize {
+        self.lines
+            .iter()
+            .zip(&self.rows)
+            .map(|(line, row)| match row {
+                BodyRow::Text => get_text_width(BodyAlignment::split_marker(line).1),
+                BodyRow::Block => get_text_width(line),
+                BodyRow::Hanging(marker) => get_text_width(marker) + get_text_width(line),
+                BodyRow::Tree(guides) => guides.len() * GUIDE_WIDTH + get_text_width(line),
+                // A label spans the whole inner width, padding included
+                BodyRow::Rule(divider) => match &divider.label {
+                    Some(label) => self
+                        .section_style
+                        .width_for(label)
+                        .saturating_sub(self.padding.width()),
+                    None => 0,
+                },
+                BodyRow::Code { digits, style, .. } => {
+                    let gutter = if style.line_numbers { digits + 3 } else { 0 };
+                    gutter + get_text_width(line)
+                }
+                // Both panes as wide as the wider one, with the rule and its gaps between
+                BodyRow::Pane(PaneRow::Text(old, new)) => {
+                    2 * old.width().max(new.width()) + 1 + self.padding.width()
+                }
+                BodyRow::Pane(PaneRow::Rule) => 0,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Widths of the (left, right) panes of a split body
+    fn panes(&self, inner_width: usize) -> (usize, usize) {
+        pane_widths(inner_width.saturating_sub(2 * self.padding.width() + 1))
+    }
+
+    /// Column of the center rule within the inner width, when the body is split
+    fn pane_column(&self, inner_width: usize) -> Option<usize> {
+        self.rows
+            .iter()
+            .any(|row| matches!(row, BodyRow::Pane(_)))
+            .then(|| self.padding.width() + self.panes(inner_width).0)
+    }
+
+    /// Icon that emoji padding lines up with: the explicit icon, or one the
+    /// first line already leads with (the CLI prepends icons to the text)
+    fn pad_icon(&self) -> Option<&str> {
+        if !self.pad_emoji {
+            return None;
+        }
+        if let Some(icon) = &self.icon {
+            return Some(icon);
+        }
+        match (&self.title, self.rows.first()) {
+            (Some(title), _) => leading_icon(title),
+            (None, Some(BodyRow::Text)) => leading_icon(&self.lines[0]),
+            _ => None,
+        }
+    }
+
+    /// Width of the emoji-padding indent applied to lines after the icon line
+    fn emoji_indent(&self) -> usize {
+        use crate::get_display_width;
+
+        self.pad_icon()
+            .map_or(0, |icon| get_display_width(icon) + 1)
+    }
+
+    pub fn build_for_width(mut self, inner_width: usize, style: BoxStyle) -> BoxyLayout {
+        self.sanitize_rows();
         let mut result_lines = Vec::new();
 
         // Add top padding
-        for _ in 0..self.v_padding {
+        for _ in 0..self.padding.top {
             result_lines.push(self.create_padding_line(inner_width, &style));
         }
 
         // Prepend title to content if present (CLI behavior: title renders inside body)
         let mut content_lines = self.lines.clone();
+        let mut rows = self.rows.clone();
         if let Some(title) = &self.title {
             content_lines.insert(0, title.clone());
+            rows.insert(0, BodyRow::Text);
         }
 
-        // Add content lines (with optional wrapping)
-        if self.enable_wrapping {
-            // Import wrap function
-            use crate::core::wrap_text_at_word_boundaries;
-
-            // Calculate available width (inner_width minus h_padding on both sides)
-            let available_width = inner_width.saturating_sub(self.h_padding * 2);
-
-            // Join all lines back into single text for wrapping
-            let full_text = content_lines.join("\n");
-
-            // Wrap the text
-            let wrapped_lines = wrap_text_at_word_boundaries(&full_text, available_width);
-
-            // Create content lines from wrapped text
-            for (idx, line) in wrapped_lines.iter().enumerate() {
-                // Apply icon to first line if present
-                if idx == 0 && self.icon.is_some() {
-                    result_lines.push(self.create_content_line_with_icon(
-                        line,
-                        inner_width,
-                        &style,
-                    ));
-                } else {
-                    result_lines.push(self.create_content_line(line, inner_width, &style));
+        // Calculate available width (inner_width minus left and right padding)
+        let available_width = inner_width.saturating_sub(self.padding.width());
+
+        // Split into aligned lines (with optional wrapping); inline markers are consumed here.
+        // Nested box rows bypass both: they are placed whole with the body alignment.
+        // Text after the first line wraps beside the emoji-padding indent.
+        let emoji_indent = self.emoji_indent();
+        let wrap_width = self
+            .enable_wrapping
+            .then_some(available_width.saturating_sub(emoji_indent));
+        let leading_icon = match self.icon {
+            None => self.pad_icon().map(str::to_string),
+            Some(_) => None,
+        };
+        let block_align = match self.alignment {
+            BodyAlignment::Justify => BodyAlignment::Left,
+            ref align => align.clone(),
+        };
+        let tree_glyphs = TreeGlyphs::for_style(&style);
+        let (left_pane, right_pane) = self.panes(inner_width);
+        let mut aligned_lines: Vec<BodyLine> = Vec::new();
+        for (index, (line, row)) in content_lines.iter().zip(rows).enumerate() {
+            match row {
+                BodyRow::Text => {
+                    let lines = match &leading_icon {
+                        Some(icon) if index == 0 => {
+                            split_after_icon(line, icon, &self.alignment, wrap_width)
+                        }
+                        _ => split_aligned_lines(line, &self.alignment, wrap_width),
+                    };
+                    aligned_lines.extend(lines.into_iter().map(BodyLine::Text))
+                }
+                BodyRow::Block => aligned_lines.push(BodyLine::Fixed(AlignedLine {
+                    text: line.clone(),
+                    align: block_align.clone(),
+                    paragraph_end: true,
+                })),
+                BodyRow::Hanging(marker) => aligned_lines.extend(
+                    hanging_lines(&marker, line, wrap_width)
+                        .into_iter()
+                        .map(BodyLine::Text),
+                ),
+                BodyRow::Rule(divider) => aligned_lines.push(BodyLine::Rule(divider)),
+                BodyRow::Tree(guides) => {
+                    // Deep nodes lose the end of their label, never their connectors
+                    let prefix = tree_glyphs.prefix(&guides);
+                    let room = available_width.saturating_sub(get_text_width(&prefix));
+                    let text = match get_text_width(line) > room && room > 3 {
+                        true => format!("{}{}", prefix, truncate_with_ellipsis(line, room)),
+                        false => format!("{}{}", prefix, line),
+                    };
+                    aligned_lines.push(BodyLine::Fixed(AlignedLine {
+                        text,
+                        align: BodyAlignment::Left,
+                        paragraph_end: true,
+                    }));
+                }
+                BodyRow::Code {
+                    number,
+                    digits,
+                    style: code,
+                } => aligned_lines.extend(
+                    code.layout_line(line, number, digits, available_width, &style)
+                        .into_iter()
+                        .map(|text| {
+                            BodyLine::Fixed(AlignedLine {
+                                text,
+                                align: BodyAlignment::Left,
+                                paragraph_end: true,
+                            })
+                        }),
+                ),
+                // Each pane keeps the body padding on both sides of the center rule
+                BodyRow::Pane(PaneRow::Text(old, new)) => {
+                    aligned_lines.push(BodyLine::Fixed(AlignedLine {
+                        text: format!(
+                            "{}{}{}{}{}",
+                            old.fit(left_pane),
+                            " ".repeat(self.padding.right),
+                            style.vertical,
+                            " ".repeat(self.padding.left),
+                            new.fit(right_pane)
+                        ),
+                        align: BodyAlignment::Left,
+                        paragraph_end: true,
+                    }))
+                }
+                BodyRow::Pane(PaneRow::Rule) => {
+                    let gaps = self.padding.width();
+                    aligned_lines.push(BodyLine::Drawn(format!(
+                        "{}{}{}{}{}",
+                        style.tee_left,
+                        style.horizontal.repeat(left_pane + gaps),
+                        style.cross,
+                        style.horizontal.repeat(right_pane + gaps),
+                        style.tee_right
+                    )))
                 }
             }
-        } else {
-            // No wrapping - original behavior
-            for (idx, line) in content_lines.iter().enumerate() {
-                // Apply icon to first line if present
-                if idx == 0 && self.icon.is_some() {
-                    result_lines.push(self.create_content_line_with_icon(
-                        line,
-                        inner_width,
-                        &style,
-                    ));
-                } else {
-                    result_lines.push(self.create_content_line(line, inner_width, &style));
+        }
+        if aligned_lines.is_empty() {
+            aligned_lines = split_aligned_lines("", &self.alignment, wrap_width)
+                .into_iter()
+                .map(BodyLine::Text)
+                .collect();
+        }
+
+        for (idx, line) in aligned_lines.iter().enumerate() {
+            let (line, flows) = match line {
+                BodyLine::Text(line) => (line, true),
+                BodyLine::Fixed(line) => (line, false),
+                BodyLine::Rule(divider) => {
+                    result_lines.push(self.section_style.render(divider, inner_width, &style));
+                    continue;
+                }
+                BodyLine::Drawn(row) => {
+                    result_lines.push(row.clone());
+                    continue;
                 }
+            };
+            // Apply icon to first line if present
+            if idx == 0 && self.icon.is_some() {
+                result_lines.push(self.create_content_line_with_icon(line, inner_width, &style));
+            } else {
+                let indent = if idx > 0 && flows { emoji_indent } else { 0 };
+                result_lines.push(self.create_content_line(line, indent, inner_width, &style));
             }
         }
 
         // Add bottom padding
-        for _ in 0..self.v_padding {
+        for _ in 0..self.padding.bottom {
             result_lines.push(self.create_padding_line(inner_width, &style));
         }
 
@@ -548,30 +1760,43 @@ impl BodyBuilder {
     }
 
     fn create_padding_line(&self, inner_width: usize, style: &BoxStyle) -> String {
-        format!(
-            "{}{}{}",
-            style.vertical,
-            " ".repeat(inner_width),
-            style.vertical
-        )
+        let blank = match self.pane_column(inner_width) {
+            Some(column) => format!(
+                "{}{}{}",
+                " ".repeat(column),
+                style.vertical,
+                " ".repeat(inner_width.saturating_sub(column + 1))
+            ),
+            None => " ".repeat(inner_width),
+        };
+        format!("{}{}{}", style.vertical, blank, style.vertical)
     }
 
-    fn create_content_line(&self, line: &str, inner_width: usize, style: &BoxStyle) -> String {
-        let line_width = get_text_width(line);
-        let available_width = inner_width.saturating_sub(2 * self.h_padding);
+    fn create_content_line(
+        &self,
+        line: &AlignedLine,
+        indent: usize,
+        inner_width: usize,
+        style: &BoxStyle,
+    ) -> String {
+        let line_width = get_text_width(&line.text);
+        let available_width = inner_width
+            .saturating_sub(self.padding.width())
+            .saturating_sub(indent);
 
         let content = if line_width > available_width {
-            self.truncate_line(line, available_width)
+            self.truncate_line(&line.text, available_width)
         } else {
-            format!("{}{}", line, " ".repeat(available_width - line_width))
+            align_line(&line.text, available_width, &line.align, line.paragraph_end)
         };
 
         format!(
-            "{}{}{}{}{}",
+            "{}{}{}{}{}{}",
             style.vertical,
-            " ".repeat(self.h_padding),
+            " ".repeat(self.padding.left),
+            " ".repeat(indent),
             content,
-            " ".repeat(self.h_padding),
+            " ".repeat(self.padding.right),
             style.vertical
         )
     }
@@ -594,20 +1819,25 @@ impl BodyBuilder {
 
     fn create_content_line_with_icon(
         &self,
-        line: &str,
+        line: &AlignedLine,
         inner_width: usize,
         style: &BoxStyle,
     ) -> String {
         use crate::get_display_width;
 
         let icon = self.icon.as_ref().unwrap();
-        let available_width = inner_width.saturating_sub(2 * self.h_padding);
+        let available_width = inner_width.saturating_sub(self.padding.width());
 
         // Check if line starts with emoji (avoid double-icons)
-        let starts_with_emoji = line.chars().next().map(|c| !c.is_ascii()).unwrap_or(false);
+        let starts_with_emoji = line
+            .text
+            .chars()
+            .next()
+            .map(|c| !c.is_ascii())
+            .unwrap_or(false);
         if starts_with_emoji {
             // Fall through to normal rendering without icon injection
-            return self.create_content_line(line, inner_width, style);
+            return self.create_content_line(line, 0, inner_width, style);
         }
 
         // Calculate icon width (icon + space)
@@ -615,26 +1845,56 @@ impl BodyBuilder {
 
         // Calculate remaining width for content
         let content_width = available_width.saturating_sub(icon_width);
-        let line_width = get_display_width(line);
+        let line_width = get_display_width(&line.text);
 
+        // Icon and text align as one unit; justify keeps the icon line ragged
         let content = if line_width > content_width {
-            self.truncate_line(line, content_width)
+            format!("{} {}", icon, self.truncate_line(&line.text, content_width))
         } else {
-            format!("{}{}", line, " ".repeat(content_width - line_width))
+            align_line(
+                &format!("{} {}", icon, line.text),
+                available_width,
+                &line.align,
+                true,
+            )
         };
 
         format!(
-            "{}{}{} {}{}{}",
+            "{}{}{}{}{}",
             style.vertical,
-            " ".repeat(self.h_padding),
-            icon,
+            " ".repeat(self.padding.left),
             content,
-            " ".repeat(self.h_padding),
+            " ".repeat(self.padding.right),
             style.vertical
         )
     }
 }
 
+/// Wrap `text` behind `marker`; continuation lines are indented to the marker's width
+fn hanging_lines(marker: &str, text: &str, wrap_width: Option<usize>) -> Vec<AlignedLine> {
+    let indent = get_text_width(marker);
+    let fragments = match wrap_width {
+        Some(width) => crate::core::balance_hyperlinks(crate::core::helpers::wrap_single_line(
+            text,
+            width.saturating_sub(indent).max(1),
+        )),
+        None => vec![text.to_string()],
+    };
+    let last = fragments.len().saturating_sub(1);
+    fragments
+        .into_iter()
+        .enumerate()
+        .map(|(i, fragment)| AlignedLine {
+            text: match i {
+                0 => format!("{}{}", marker, fragment),
+                _ => format!("{}{}", " ".repeat(indent), fragment),
+            },
+            align: BodyAlignment::Left,
+            paragraph_end: i == last,
+        })
+        .collect()
+}
+
 /// Complete box builder orchestrating all components
 #[derive(Debug)]
 pub struct BoxBuilder {
@@ -649,8 +1909,16 @@ pub struct BoxBuilder {
     max_width: Option<usize>,
     min_height: Option<usize>,
     max_height: Option<usize>,
+    width_spec: Option<SizeSpec>,
+    height_spec: Option<SizeSpec>,
+    available: Option<(usize, usize)>,
+    overflow: Option<(OverflowPolicy, usize)>,
+    margin: Spacing,
+    borders: BorderSides,
     visible: bool,
     layout_mode: LayoutMode,
+    v_align: VerticalAlign,
+    shadow: Option<Shadow>,
 }
 
 impl BoxBuilder {
@@ -667,8 +1935,16 @@ impl BoxBuilder {
             max_width: None,
             min_height: None,
             max_height: None,
+            width_spec: None,
+            height_spec: None,
+            available: None,
+            overflow: None,
+            margin: Spacing::default(),
+            borders: BorderSides::ALL,
             visible: true,
             layout_mode: LayoutMode::Box,
+            v_align: VerticalAlign::Top,
+            shadow: None,
         }
     }
 
@@ -710,6 +1986,42 @@ impl BoxBuilder {
         self
     }
 
+    /// Set width from a spec (`50%`, `max`, `-4`, `min(80,100%)`, `auto`)
+    /// Resolved at build time against `with_available_size` or the terminal width;
+    /// takes precedence over `with_fixed_width`
+    pub fn with_width_spec(mut self, spec: SizeSpec) -> Self {
+        self.width_spec = Some(spec);
+        self
+    }
+
+    /// Set height from a spec, resolved against the available rows
+    /// (default: terminal height minus room for the prompt)
+    pub fn with_height_spec(mut self, spec: SizeSpec) -> Self {
+        self.height_spec = Some(spec);
+        self
+    }
+
+    /// Space that relative width/height specs resolve against, instead of the terminal
+    /// Useful for boxes placed inside a pane or grid cell
+    pub fn with_available_size(mut self, columns: usize, rows: usize) -> Self {
+        self.available = Some((columns, rows));
+        self
+    }
+
+    /// Keep the box (shadow and margin included) within `limit` columns, e.g. the terminal width
+    /// The policy decides how content is fitted; see `OverflowPolicy`
+    pub fn with_overflow(mut self, policy: OverflowPolicy, limit: usize) -> Self {
+        self.overflow = Some((policy, limit));
+        self
+    }
+
+    /// Set vertical placement of body content when a height constraint adds blank rows
+    /// Top (default) fills below the content, Middle splits the fill, Bottom fills above
+    pub fn with_vertical_align(mut self, align: VerticalAlign) -> Self {
+        self.v_align = align;
+        self
+    }
+
     /// Set minimum width - box will be at least this wide, but can grow if content requires it
     /// Useful for dynamic grid cells that can expand
     pub fn with_min_width(mut self, width: usize) -> Self {
@@ -770,6 +2082,19 @@ impl BoxBuilder {
         self
     }
 
+    /// Set body padding per side (convenience method that forwards to body)
+    pub fn with_padding_sides(mut self, padding: Spacing) -> Self {
+        self.body = self.body.with_padding_sides(padding);
+        self
+    }
+
+    /// Set outer margin: the box is indented by `left` columns and surrounded by
+    /// blank rows; `right` pads rows so the box keeps a fixed footprint in a layout
+    pub fn with_margin(mut self, margin: Spacing) -> Self {
+        self.margin = margin;
+        self
+    }
+
     /// Enable text wrapping at word boundaries (convenience method that forwards to body)
     /// When enabled, long text will wrap to the next line instead of being truncated.
     /// Perfect for responsive layouts and dynamic content.
@@ -792,19 +2117,137 @@ impl BoxBuilder {
         self
     }
 
+    /// Append a nested box to the body (convenience method that forwards to body)
+    pub fn with_child(mut self, child: BoxLayout) -> Self {
+        self.body = self.body.with_child(child);
+        self
+    }
+
+    /// Append pre-rendered text as an atomic body block (convenience method that forwards to body)
+    pub fn with_block(mut self, block: &str) -> Self {
+        self.body = self.body.with_block(block);
+        self
+    }
+
+    /// Append source code to the body (convenience method that forwards to body)
+    pub fn with_code(mut self, source: &str, style: CodeStyle) -> Self {
+        self.body = self.body.with_code(source, style);
+        self
+    }
+
+    /// Append a tree to the body (convenience method that forwards to body)
+    pub fn with_tree(mut self, source: &str, style: TreeStyle) -> Self {
+        self.body = self.body.with_tree(source, style);
+        self
+    }
+
+    /// Append a side-by-side diff to the body (convenience method that forwards to body)
+    pub fn with_diff(mut self, old: &str, new: &str, style: DiffStyle) -> Self {
+        self.body = self.body.with_diff(old, new, style);
+        self
+    }
+
+    /// Append markdown content to the body (convenience method that forwards to body)
+    pub fn with_markdown(mut self, source: &str) -> Self {
+        self.body = self.body.with_markdown(source);
+        self
+    }
+
+    /// Append a labeled section to the body (convenience method that forwards to body)
+    pub fn with_section(mut self, label: &str, content: &str) -> Self {
+        self.body = self.body.section(label, content);
+        self
+    }
+
+    /// Append a divider of its own style to the body (convenience method that forwards to body)
+    pub fn with_divider(mut self, label: &str, style: DividerStyle) -> Self {
+        self.body = self.body.divider(label, style);
+        self
+    }
+
+    /// Set section divider style (convenience method that forwards to body)
+    pub fn with_section_style(mut self, style: SectionStyle) -> Self {
+        self.body = self.body.with_section_style(style);
+        self
+    }
+
+    /// Set default body alignment (convenience method that forwards to body)
+    pub fn with_body_alignment(mut self, alignment: BodyAlignment) -> Self {
+        self.body = self.body.with_alignment(alignment);
+        self
+    }
+
+    /// Indent body lines to match the icon column (convenience method that forwards to body)
+    pub fn with_emoji_padding(mut self, enabled: bool) -> Self {
+        self.body = self.body.with_emoji_padding(enabled);
+        self
+    }
+
+    /// Tab width and control character handling for every text in the box
+    pub fn with_sanitize_options(mut self, options: SanitizeOptions) -> Self {
+        self.body = self.body.with_sanitize_options(options);
+        self
+    }
+
+    /// Cast a drop shadow right of and below the box
+    /// The shadow adds to the rendered size but not to `total_width`, which stays the frame width
+    pub fn with_shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
     /// Enable barmode layout - horizontal lines only, no vertical borders
     /// Perfect for document integration and text separation
+    /// Unlike `with_borders` `tb`, a bar is drawn only for an explicit header or footer
     pub fn with_barmode(mut self) -> Self {
         self.layout_mode = LayoutMode::Bar;
         self
     }
 
-    pub fn build(self) -> BoxLayout {
+    /// Choose which frame edges are drawn, e.g. `tb` for rules above and below
+    /// or `l` for a quote-style left rule; padding is kept either way
+    pub fn with_borders(mut self, borders: BorderSides) -> Self {
+        self.borders = borders;
+        self
+    }
+
+    /// Resolve tabs and control characters before anything is measured
+    fn sanitize(&mut self) {
+        let options = self.body.sanitize.clone();
+        if let Some(header) = &mut self.header {
+            header.sanitize(&options);
+        }
+        if let Some(footer) = &mut self.footer {
+            footer.sanitize(&options);
+        }
+        if let Some(status) = &mut self.status {
+            status.sanitize(&options);
+        }
+        self.body.sanitize_rows();
+    }
+
+    /// Build, failing if the box overflows its limit under `OverflowPolicy::Error`
+    pub fn try_build(self) -> Result<BoxLayout, String> {
+        let layout = self.build();
+        match &layout.overflow {
+            Some(overflow) if overflow.policy == OverflowPolicy::Error => Err(overflow.message()),
+            _ => Ok(layout),
+        }
+    }
+
+    /// Build the layout
+    ///
+    /// An overflowing box is always fitted to its limit, even under
+    /// `OverflowPolicy::Error` (wrapped); the result records it in `BoxLayout::overflow`.
+    pub fn build(mut self) -> BoxLayout {
+        self.sanitize();
+        self.resolve_size_specs();
+        let overflow = self.fit_to_limit();
         let inner_width = self.calculate_inner_width();
 
         // Auto-add empty header/footer only in Box mode to ensure closed box
         // In Bar mode, only render explicitly requested components
-        let header = match self.layout_mode {
+        let mut header = match self.layout_mode {
             LayoutMode::Box => self
                 .header
                 .or_else(|| Some(HeaderBuilder::empty()))
@@ -814,7 +2257,7 @@ impl BoxBuilder {
                 .map(|h| h.with_style(self.style).build_for_width(inner_width)),
         };
 
-        let footer = match self.layout_mode {
+        let mut footer = match self.layout_mode {
             LayoutMode::Box => self
                 .footer
                 .or_else(|| Some(FooterBuilder::empty()))
@@ -823,10 +2266,14 @@ impl BoxBuilder {
                 .footer
                 .map(|f| f.with_style(self.style).build_for_width(inner_width)),
         };
-        let status = self
+        let mut status = self
             .status
             .map(|s| s.build_for_width(inner_width, self.style));
+        let padding = self.body.padding;
+        let pane_column = self.body.pane_column(inner_width);
         let mut body = self.body.build_for_width(inner_width, self.style);
+        body.v_align = self.v_align;
+        let mut body_fill = (0, 0);
 
         // Calculate current total height
         let header_height = header.as_ref().map(|h| h.height).unwrap_or(0);
@@ -884,10 +2331,11 @@ impl BoxBuilder {
                     self.style,
                 );
             } else if body.height < available_body_height {
-                // Pad body to fill available height
+                // Pad body to fill available height, split according to vertical alignment
+                body_fill = self.v_align.distribute(available_body_height - body.height);
                 body = Self::pad_body_to_height(
                     body,
-                    available_body_height,
+                    body_fill,
                     inner_width,
                     self.style,
                     self.layout_mode,
@@ -895,6 +2343,30 @@ impl BoxBuilder {
             }
         }
 
+        // A split body's center rule runs into the rows above and below it
+        if let Some(column) = pane_column {
+            let (down, up) = column_tees(&self.style);
+            let rows: Vec<&str> = body.content.lines().collect();
+            // Changed lines carry their diff colors; measure without them
+            let reaches = |row: Option<&&str>| {
+                row.is_some_and(|row| {
+                    let plain = crate::strip_ansi_codes(row);
+                    glyph_at(&plain, column + 1).map(|(_, glyph)| glyph)
+                        == Some(self.style.vertical)
+                })
+            };
+            if reaches(rows.first())
+                && let Some(header) = header.as_mut()
+            {
+                join_rule(header, false, column + 1, &self.style, down);
+            }
+            if reaches(rows.last())
+                && let Some(below) = status.as_mut().or(footer.as_mut())
+            {
+                join_rule(below, true, column + 1, &self.style, up);
+            }
+        }
+
         BoxLayout {
             header,
             footer,
@@ -904,28 +2376,80 @@ impl BoxBuilder {
             style: self.style,
             layout_mode: self.layout_mode,
             visible: self.visible,
+            body_fill,
+            shadow: self.shadow,
+            overflow,
+            padding,
+            margin: self.margin,
+            borders: self.borders,
         }
     }
 
+    /// Turn width/height specs into fixed sizes against the available space
+    fn resolve_size_specs(&mut self) {
+        if let Some(spec) = self.width_spec.take() {
+            let columns = self
+                .available
+                .map(|(columns, _)| columns)
+                .unwrap_or_else(crate::width_plugin::get_terminal_width);
+            self.fixed_width = spec.resolve_within(columns, MIN_WIDTH, MAX_WIDTH);
+        }
+        if let Some(spec) = self.height_spec.take() {
+            let rows = self
+                .available
+                .map(|(_, rows)| rows)
+                .unwrap_or_else(crate::height_plugin::get_max_safe_height);
+            self.fixed_height = spec.resolve_within(rows, MIN_HEIGHT, MAX_HEIGHT);
+        }
+    }
+
+    /// Apply the overflow policy when the box is wider than its limit
+    fn fit_to_limit(&mut self) -> Option<Overflow> {
+        let (policy, limit) = self.overflow?;
+        let shadow_width = self.shadow.as_ref().map_or(0, Shadow::columns) + self.margin.width();
+        let limit = limit.saturating_sub(shadow_width).max(MIN_WIDTH);
+        let width = self.calculate_inner_width() + 2;
+        if width <= limit {
+            return None;
+        }
+
+        match policy {
+            OverflowPolicy::Truncate => self.body.enable_wrapping = false,
+            OverflowPolicy::Wrap | OverflowPolicy::Error => self.body.enable_wrapping = true,
+            OverflowPolicy::Shrink => {
+                let padding = &mut self.body.padding;
+                for _ in limit..width {
+                    match padding.right >= padding.left {
+                        true => padding.right = padding.right.saturating_sub(1),
+                        false => padding.left -= 1,
+                    }
+                }
+                self.body.enable_wrapping = true;
+            }
+        }
+        self.fixed_width = Some(limit);
+        self.min_width = self.min_width.map(|w| w.min(limit));
+
+        Some(Overflow {
+            policy,
+            width: width + shadow_width,
+            limit: limit + shadow_width,
+        })
+    }
+
     fn calculate_inner_width(&self) -> usize {
         let base_width = match self.fixed_width {
             Some(w) => w.saturating_sub(2),
-            None => {
-                // Calculate width from body lines
-                let body_lines_width = self
-                    .body
-                    .lines
-                    .iter()
-                    .map(|line| get_text_width(line))
-                    .max()
-                    .unwrap_or(0);
+            None => {
+                // Calculate width from body lines (nested box rows measured whole)
+                let body_lines_width = self.body.content_width();
 
                 // Include title width if present (title renders as first body line)
                 let title_width = self
                     .body
                     .title
                     .as_ref()
-                    .map(|t| get_text_width(t))
+                    .map(|t| get_text_width(BodyAlignment::split_marker(t).1))
                     .unwrap_or(0);
 
                 // Include icon width if present (icon prepends to first line)
@@ -946,29 +2470,35 @@ impl BoxBuilder {
                 } else {
                     body_content_width
                 };
-                let body_width = body_with_icon_width + (2 * self.body.h_padding);
+                let body_width = body_with_icon_width + self.body.padding.width();
 
-                // Calculate width from header if present
+                // Calculate width from header if present (label decoration and inset included)
                 let header_width = self
                     .header
                     .as_ref()
-                    .and_then(|h| h.content.as_ref())
-                    .map(|text| get_text_width(text))
+                    .and_then(|h| {
+                        h.content
+                            .as_ref()
+                            .map(|text| label_width(text, h.decoration, h.inset))
+                    })
                     .unwrap_or(0);
 
                 // Calculate width from footer if present
                 let footer_width = self
                     .footer
                     .as_ref()
-                    .and_then(|f| f.content.as_ref())
-                    .map(|text| get_text_width(text))
+                    .and_then(|f| {
+                        f.content
+                            .as_ref()
+                            .map(|text| label_width(text, f.decoration, f.inset))
+                    })
                     .unwrap_or(0);
 
                 // Calculate width from status if present (including padding)
                 let status_width = self
                     .status
                     .as_ref()
-                    .map(|s| get_text_width(&s.content) + (2 * s.padding))
+                    .map(|s| s.natural_width() + (2 * s.padding))
                     .unwrap_or(0);
 
                 // Use the maximum width from all components
@@ -1062,24 +2592,19 @@ impl BoxBuilder {
         }
     }
 
-    /// Pad body content to fill a target height
+    /// Pad body content with (above, below) blank rows to fill a target height
     fn pad_body_to_height(
         body: BoxyLayout,
-        target_height: usize,
+        fill: (usize, usize),
         inner_width: usize,
         style: BoxStyle,
         layout_mode: LayoutMode,
     ) -> BoxyLayout {
-        let current_lines: Vec<&str> = body.content.lines().collect();
-        let current_height = current_lines.len();
-
-        if current_height >= target_height {
+        let (fill_above, fill_below) = fill;
+        if fill_above + fill_below == 0 {
             return body;
         }
 
-        let padding_needed = target_height - current_height;
-        let mut result_lines: Vec<String> = current_lines.iter().map(|s| s.to_string()).collect();
-
         // Add empty padded lines (format depends on layout mode)
         let empty_line = match layout_mode {
             LayoutMode::Box => {
@@ -1097,13 +2622,13 @@ impl BoxBuilder {
             }
         };
 
-        for _ in 0..padding_needed {
-            result_lines.push(empty_line.clone());
-        }
+        let mut result_lines: Vec<String> = vec![empty_line.clone(); fill_above];
+        result_lines.extend(body.content.lines().map(|s| s.to_string()));
+        result_lines.extend(std::iter::repeat_n(empty_line, fill_below));
 
         BoxyLayout {
             width: inner_width + 2,
-            height: target_height,
+            height: result_lines.len(),
             content: result_lines.join("\n"),
             h_align: body.h_align,
             v_align: body.v_align,
@@ -1111,6 +2636,38 @@ impl BoxBuilder {
     }
 }
 
+/// Byte offset and glyph covering display `column` of a row
+fn glyph_at(row: &str, column: usize) -> Option<(usize, &str)> {
+    let mut start = 0;
+    for (index, ch) in row.char_indices() {
+        if start == column {
+            return Some((index, &row[index..index + ch.len_utf8()]));
+        }
+        start += get_text_width(ch.encode_utf8(&mut [0; 4]));
+        if start > column {
+            return None;
+        }
+    }
+    None
+}
+
+/// Put `junction` where a vertical rule meets the first (or last) row of
+/// `part`, if that row has a plain frame rule at `column`
+fn join_rule(part: &mut BoxyLayout, first: bool, column: usize, style: &BoxStyle, junction: &str) {
+    let mut rows: Vec<String> = part.content.lines().map(str::to_string).collect();
+    let row = match first {
+        true => rows.first_mut(),
+        false => rows.last_mut(),
+    };
+    if let Some(row) = row
+        && let Some((index, glyph)) = glyph_at(row, column)
+        && glyph == style.horizontal
+    {
+        row.replace_range(index..index + glyph.len(), junction);
+        part.content = rows.join("\n");
+    }
+}
+
 /// Helper trait for padding strings to a specific width
 trait PadToWidth {
     fn pad_to_width(&self, width: usize) -> String;
@@ -1139,6 +2696,18 @@ pub struct BoxLayout {
     pub style: BoxStyle,
     pub layout_mode: LayoutMode,
     pub visible: bool,
+    /// Blank rows (above, below) the body content added by height constraints
+    pub body_fill: (usize, usize),
+    /// Drop shadow drawn outside the frame
+    pub shadow: Option<Shadow>,
+    /// Set when the box was fitted to its overflow limit
+    pub overflow: Option<Overflow>,
+    /// Body padding inside the border
+    pub padding: Spacing,
+    /// Blank space around the box, outside any shadow
+    pub margin: Spacing,
+    /// Frame edges drawn in `LayoutMode::Box`
+    pub borders: BorderSides,
 }
 
 impl BoxLayout {
@@ -1148,9 +2717,46 @@ impl BoxLayout {
     }
 
     /// Render the complete box with colors applied using ColorScheme
+    ///
+    /// Per-edge colors (`ColorScheme::edges`) are placed using this layout's
+    /// geometry: margin, border sides and shadow are accounted for.
     pub fn render_with_colors(&self, scheme: &crate::api::theming::ColorScheme) -> String {
+        use crate::api::theming::{apply_colors_by_part, apply_colors_to_rendered_box};
+
         let rendered = self.render();
-        crate::api::theming::apply_colors_to_rendered_box(&rendered, scheme)
+        if scheme.edges.is_empty() && self.shadow.is_none() {
+            return apply_colors_to_rendered_box(&rendered, scheme);
+        }
+        let rows: Vec<&str> = rendered.lines().collect();
+        let frame = FrameMap::new(self, &rows);
+        apply_colors_by_part(&rendered, scheme, |row, column| frame.part(row, column))
+    }
+
+    /// Export as an HTML `<pre>` block with inline styles
+    pub fn to_html(&self) -> String {
+        self.to_html_with(&crate::api::export::ExportOptions::default())
+    }
+
+    /// Export as HTML with the font, cell size and colors in `options`
+    pub fn to_html_with(&self, options: &crate::api::export::ExportOptions) -> String {
+        crate::api::export::to_html(&self.render_for_export(options), options)
+    }
+
+    /// Export as an SVG image with one monospace cell per column
+    pub fn to_svg(&self) -> String {
+        self.to_svg_with(&crate::api::export::ExportOptions::default())
+    }
+
+    /// Export as SVG with the font, cell size and colors in `options`
+    pub fn to_svg_with(&self, options: &crate::api::export::ExportOptions) -> String {
+        crate::api::export::to_svg(&self.render_for_export(options), options)
+    }
+
+    fn render_for_export(&self, options: &crate::api::export::ExportOptions) -> String {
+        match &options.scheme {
+            Some(scheme) => self.render_with_colors(scheme),
+            None => self.render(),
+        }
     }
 
     /// QOL: Render as individual lines for layout engines
@@ -1161,12 +2767,74 @@ impl BoxLayout {
             return Vec::new();
         }
 
-        match self.layout_mode {
+        let lines = match self.layout_mode {
+            LayoutMode::Box if !self.borders.is_all() => self.render_sided_lines(),
             LayoutMode::Box => self.render_box_lines(),
             LayoutMode::Bar => self.render_bar_lines(),
+        };
+
+        // A shadow needs row-level access, so shadowed output is one entry per row
+        let lines = match &self.shadow {
+            Some(shadow) => shadow.cast(&lines),
+            None => lines,
+        };
+        match self.margin.is_zero() {
+            true => lines,
+            false => apply_margin(&lines, &self.margin),
         }
     }
 
+    /// Columns and rows the box occupies, shadow and margin included
+    pub fn outer_size(&self) -> (usize, usize) {
+        let rendered = self.render_lines();
+        let rows = rendered.iter().flat_map(|chunk| chunk.lines());
+        let columns = rows.clone().map(get_text_width).max().unwrap_or(0);
+        (columns, rows.count())
+    }
+
+    /// Rows the header and footer edges take up once border sides are applied
+    pub fn edge_rows(&self) -> (usize, usize) {
+        let rows = |edge: &Option<BoxyLayout>, drawn: bool| match edge {
+            Some(edge) => {
+                (drawn
+                    || self.layout_mode == LayoutMode::Bar
+                    || self.edge_label_row(&edge.content).is_some()) as usize
+            }
+            None => 0,
+        };
+        (
+            rows(&self.header, self.borders.top),
+            rows(&self.footer, self.borders.bottom),
+        )
+    }
+
+    /// Geometry of the built box, padding and margin included
+    pub fn dimensions(&self) -> crate::api::geometry::BoxDimensions {
+        let (outer_width, outer_height) = self.outer_size();
+        let (_, shadow_y) = self.shadow_extent();
+        let total_height = outer_height.saturating_sub(shadow_y + self.margin.height());
+        crate::api::geometry::BoxDimensions {
+            total_width: self.total_width,
+            inner_width: self.total_width.saturating_sub(2),
+            total_height,
+            inner_height: total_height.saturating_sub(2),
+            h_padding: self.padding.left.max(self.padding.right),
+            v_padding: self.padding.top.max(self.padding.bottom),
+            padding: self.padding,
+            margin: self.margin,
+            outer_width,
+            outer_height,
+        }
+    }
+
+    /// Columns and rows the shadow adds outside the frame
+    pub fn shadow_extent(&self) -> (usize, usize) {
+        self.shadow
+            .as_ref()
+            .map(|s| (s.columns(), s.offset_y))
+            .unwrap_or((0, 0))
+    }
+
     /// Render standard box layout with full borders
     fn render_box_lines(&self) -> Vec<String> {
         let mut lines = Vec::new();
@@ -1188,6 +2856,63 @@ impl BoxLayout {
         lines
     }
 
+    /// Render a box with only some frame edges drawn
+    ///
+    /// The full box is rendered first; edges are then removed row by row, so
+    /// headers, dividers and status lines stay aligned in every combination.
+    fn render_sided_lines(&self) -> Vec<String> {
+        let full = self.render_box_lines();
+        let rows: Vec<&str> = full.iter().flat_map(|chunk| chunk.lines()).collect();
+        let last = rows.len().saturating_sub(1);
+
+        rows.iter()
+            .enumerate()
+            .filter_map(|(i, row)| {
+                let row = match i {
+                    0 if !self.borders.top => self.edge_label_row(row)?,
+                    i if i == last && !self.borders.bottom => self.edge_label_row(row)?,
+                    _ => row.to_string(),
+                };
+                let mut chars: Vec<char> = row.chars().collect();
+                if !self.borders.right {
+                    chars.pop();
+                }
+                if !self.borders.left && !chars.is_empty() {
+                    chars.remove(0);
+                }
+                Some(chars.into_iter().collect())
+            })
+            .collect()
+    }
+
+    /// A top/bottom edge without its rule glyphs, or None when it carries no label
+    ///
+    /// Corners become plain vertical edges so a kept left/right side stays continuous.
+    fn edge_label_row(&self, row: &str) -> Option<String> {
+        let chars: Vec<char> = row.chars().collect();
+        if chars.len() < 2 {
+            return None;
+        }
+        // Only the rule runs either side are blanked; the label keeps its own glyphs
+        let rule = self.style.horizontal;
+        let inner: String = chars[1..chars.len() - 1].iter().collect();
+        let label = inner.trim_start_matches(rule);
+        let lead = (inner.len() - label.len()) / rule.len();
+        let trimmed = label.trim_end_matches(rule);
+        let trail = (label.len() - trimmed.len()) / rule.len();
+        if trimmed.trim().is_empty() {
+            return None;
+        }
+        Some(format!(
+            "{}{}{}{}{}",
+            self.style.vertical,
+            " ".repeat(lead),
+            trimmed,
+            " ".repeat(trail),
+            self.style.vertical
+        ))
+    }
+
     /// Render barmode layout with horizontal lines only
     /// Uses the box style's horizontal character, but no corners or vertical borders
     fn render_bar_lines(&self) -> Vec<String> {
@@ -1264,13 +2989,11 @@ impl BoxLayout {
         let top_right = self.style.top_right.chars().next().unwrap_or('┐');
         let bottom_left = self.style.bottom_left.chars().next().unwrap_or('└');
         let bottom_right = self.style.bottom_right.chars().next().unwrap_or('┘');
-        let tee_left = self.style.tee_left.chars().next().unwrap_or('├');
-        let tee_right = self.style.tee_right.chars().next().unwrap_or('┤');
 
         // Check if line starts and ends with corner characters (typical header/footer pattern)
         let is_corner_line = (first_char == top_left && last_char == top_right)
             || (first_char == bottom_left && last_char == bottom_right)
-            || (first_char == tee_left && last_char == tee_right);
+            || self.divider_tees().contains(&(first_char, last_char));
 
         is_corner_line
     }
@@ -1285,6 +3008,14 @@ impl BoxLayout {
             // Use inner width (total_width - 2) for barmode to align with content
             let bar_width = self.total_width.saturating_sub(2);
 
+            // Border lines built for this width already carry label placement,
+            // inset and decorations - keep them verbatim between the corners
+            let inner_content = self.extract_content_from_line(line.trim());
+            // (plain dividers of a different weight included)
+            if get_text_width(&inner_content) == bar_width {
+                return Some(inner_content);
+            }
+
             if extracted_text.is_empty() {
                 // No text content, just a full horizontal bar
                 Some(self.style.horizontal.repeat(bar_width))
@@ -1352,18 +3083,17 @@ impl BoxLayout {
         let top_right = self.style.top_right.chars().next().unwrap_or('┐');
         let bottom_left = self.style.bottom_left.chars().next().unwrap_or('└');
         let bottom_right = self.style.bottom_right.chars().next().unwrap_or('┘');
-        let tee_left = self.style.tee_left.chars().next().unwrap_or('├');
-        let tee_right = self.style.tee_right.chars().next().unwrap_or('┤');
+        let tees = self.divider_tees();
 
         // Check if first and last characters are any border characters
         let is_left_border = first_char == vertical_char
             || first_char == top_left
             || first_char == bottom_left
-            || first_char == tee_left;
+            || tees.iter().any(|&(left, _)| left == first_char);
         let is_right_border = last_char == vertical_char
             || last_char == top_right
             || last_char == bottom_right
-            || last_char == tee_right;
+            || tees.iter().any(|&(_, right)| right == last_char);
 
         // If line starts and ends with border characters, extract the middle
         if is_left_border && is_right_border {
@@ -1373,6 +3103,15 @@ impl BoxLayout {
         }
     }
 
+    /// Junction pairs a divider can start and end with: the frame's tees and mixed weights
+    fn divider_tees(&self) -> Vec<(char, char)> {
+        DividerStyle::ALL
+            .iter()
+            .map(|d| d.glyphs(&self.style))
+            .filter_map(|g| Some((g.tee_left.chars().next()?, g.tee_right.chars().next()?)))
+            .collect()
+    }
+
     /// Get individual component layouts for Room Runtime positioning
     #[allow(dead_code)]
     pub fn components(&self) -> Vec<&BoxyLayout> {
@@ -1492,126 +3231,571 @@ pub fn render_box_lines(content: &str, options: BoxOptions) -> Vec<String> {
         builder = builder.with_style(style);
     }
 
-    if let Some(LayoutMode::Bar) = options.layout_mode {
-        builder = builder.with_barmode();
+    if let Some(LayoutMode::Bar) = options.layout_mode {
+        builder = builder.with_barmode();
+    }
+
+    builder.build().render_lines()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_builder() {
+        let header = HeaderBuilder::new("Test Header")
+            .align_center()
+            .build_for_width(20);
+
+        assert_eq!(header.width, 22); // 20 + 2 borders
+        assert_eq!(header.height, 1);
+        assert!(header.content.contains("Test Header"));
+    }
+
+    #[test]
+    fn test_box_builder_complete() {
+        let layout = BoxBuilder::new("Hello\nWorld")
+            .with_header(HeaderBuilder::new("Title"))
+            .with_footer(FooterBuilder::new("Footer"))
+            .with_status(StatusBuilder::new("Status"))
+            .build();
+
+        assert!(layout.header.is_some());
+        assert!(layout.footer.is_some());
+        assert!(layout.status.is_some());
+        assert_eq!(layout.body.height, 2); // 2 lines of content + padding
+
+        let components = layout.components();
+        assert_eq!(components.len(), 4); // header, body, status, footer
+    }
+
+    #[test]
+    fn test_emoji_in_layout() {
+        crate::width_calibration::install_uncalibrated();
+        let layout = BoxBuilder::new("Hello 🌟 World").build();
+        let rendered = layout.render();
+
+        // Should handle emoji width correctly
+        assert!(rendered.contains("Hello 🌟 World"));
+    }
+
+    #[test]
+    fn test_box_has_complete_borders() {
+        let layout = BoxBuilder::new("test content").build();
+        let rendered = layout.render();
+
+        // Check all borders are present
+        assert!(rendered.starts_with("┌"), "Missing top-left corner");
+
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert!(lines[0].ends_with("┐"), "Missing top-right corner");
+
+        // Check all middle lines have side borders
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 && i < lines.len() - 1 {
+                assert!(line.starts_with("│"), "Missing left border on line {}", i);
+                assert!(line.ends_with("│"), "Missing right border on line {}", i);
+            }
+        }
+
+        let last_line = lines.last().expect("Box should have lines");
+        assert!(last_line.starts_with("└"), "Missing bottom-left corner");
+        assert!(last_line.ends_with("┘"), "Missing bottom-right corner");
+    }
+
+    #[test]
+    fn test_box_width_consistency() {
+        let layout = BoxBuilder::new("test").with_fixed_width(30).build();
+        let rendered = layout.render();
+
+        let lines: Vec<&str> = rendered.lines().collect();
+        let expected_width = layout.total_width;
+
+        for (i, line) in lines.iter().enumerate() {
+            let line_width = get_text_width(line);
+            assert_eq!(
+                line_width, expected_width,
+                "Line {} has width {} but expected {}. Content: '{}'",
+                i, line_width, expected_width, line
+            );
+        }
+    }
+
+    #[test]
+    fn test_body_borders_complete() {
+        let body = BodyBuilder::new("test line")
+            .with_h_padding(2)
+            .build_for_width(20, NORMAL);
+
+        // Check each line in body has both borders
+        for line in body.content.lines() {
+            assert!(line.starts_with("│"), "Body line missing left border");
+            assert!(line.ends_with("│"), "Body line missing right border");
+        }
+    }
+
+    #[test]
+    fn test_status_borders_complete() {
+        let status = StatusBuilder::new("status text")
+            .with_divider(true)
+            .build_for_width(20, NORMAL);
+
+        let lines: Vec<&str> = status.content.lines().collect();
+
+        // First line should be divider
+        assert!(lines[0].starts_with("├"), "Status divider missing left tee");
+        assert!(lines[0].ends_with("┤"), "Status divider missing right tee");
+
+        // All other lines should have vertical borders
+        for line in lines.iter().skip(1) {
+            assert!(line.starts_with("│"), "Status line missing left border");
+            assert!(line.ends_with("│"), "Status line missing right border");
+        }
+    }
+
+    fn status_text_line(status: StatusBuilder, inner_width: usize) -> String {
+        let layout = status
+            .with_divider(false)
+            .with_padding(0)
+            .build_for_width(inner_width, NORMAL);
+        let line = layout.content.lines().next().unwrap().to_string();
+        line.trim_start_matches('│')
+            .trim_end_matches('│')
+            .to_string()
+    }
+
+    #[test]
+    fn test_status_segment_parse() {
+        let segment = StatusSegment::parse("sc,red,3:3 errors").unwrap();
+        assert_eq!(segment.zone, StatusZone::Center);
+        assert_eq!(segment.priority, 3);
+        assert_eq!(segment.color.as_deref(), Some("red"));
+        assert_eq!(segment.text, "3 errors");
+
+        assert_eq!(StatusSegment::parse("sr:12:04").unwrap().text, "12:04");
+        assert!(StatusSegment::parse("Status: OK").is_none());
+        assert!(StatusSegment::parse("sl, bad attr:text").is_none());
+    }
+
+    #[test]
+    fn test_status_parse_zones() {
+        let status = StatusBuilder::parse("sl:main|sc:3 errors|sr:12:04");
+        let zones: Vec<StatusZone> = status.segments().iter().map(|s| s.zone).collect();
+        assert_eq!(
+            zones,
+            vec![StatusZone::Left, StatusZone::Center, StatusZone::Right]
+        );
+
+        // Unprefixed pipes stay literal text
+        let literal = StatusBuilder::parse("a | b");
+        assert_eq!(literal.segments().len(), 1);
+        assert_eq!(literal.segments()[0].text, "a | b");
+
+        let single = StatusBuilder::parse("sr:Done | 2 left");
+        assert_eq!(single.segments()[0].zone, StatusZone::Right);
+        assert_eq!(single.segments()[0].text, "Done | 2 left");
+    }
+
+    #[test]
+    fn test_status_zones_placement() {
+        let status = StatusBuilder::segmented()
+            .left("main")
+            .center("3 errors")
+            .right("12:04");
+        let line = status_text_line(status, 30);
+
+        assert_eq!(get_text_width(&line), 30);
+        assert!(line.starts_with("main "));
+        assert!(line.ends_with(" 12:04"));
+        assert_eq!(line.find("3 errors"), Some(11));
+    }
+
+    #[test]
+    fn test_status_center_yields_to_neighbours() {
+        let status = StatusBuilder::segmented()
+            .left("a-long-branch-name")
+            .center("ok")
+            .right("1");
+        let line = status_text_line(status, 26);
+
+        assert!(line.starts_with("a-long-branch-name  ok"));
+        assert!(line.ends_with("1"));
+    }
+
+    #[test]
+    fn test_status_priority_truncation() {
+        let status = StatusBuilder::segmented()
+            .with_segment(
+                StatusSegment::new("feature/segmented-status", StatusZone::Left).with_priority(0),
+            )
+            .center("3 errors")
+            .right("12:04");
+        let line = status_text_line(status, 30);
+
+        // The low-priority branch gives way, the others survive intact
+        assert!(line.contains('…'));
+        assert!(line.contains("3 errors"));
+        assert!(line.ends_with("12:04"));
+        assert_eq!(get_text_width(&line), 30);
+    }
+
+    #[test]
+    fn test_status_drops_segment_without_room() {
+        let status = StatusBuilder::segmented()
+            .left("main")
+            .center("centered")
+            .right("12:04");
+        let line = status_text_line(status, 12);
+
+        // Center yields first on a priority tie and has no room left to show
+        assert_eq!(line, "main   12:04");
+    }
+
+    #[test]
+    fn test_status_segment_colors() {
+        let status = StatusBuilder::parse("sl:main|sr,red:fail").with_color("green");
+        let line = status_text_line(status, 20);
+
+        assert!(line.contains(&format!(
+            "{}main{}",
+            crate::get_color_code("green"),
+            crate::RESET
+        )));
+        assert!(line.contains(&format!(
+            "{}fail{}",
+            crate::get_color_code("red"),
+            crate::RESET
+        )));
+        assert_eq!(get_text_width(&line), 20);
+    }
+
+    #[test]
+    fn test_status_natural_width_sizes_box() {
+        let layout = BoxBuilder::new("x")
+            .with_status(StatusBuilder::parse("sl:main|sr:12:04"))
+            .build();
+
+        // 4 + gap 2 + 5, plus status padding on both sides
+        assert_eq!(layout.total_width, 4 + 2 + 5 + 2 + 2);
+    }
+
+    #[test]
+    fn test_shadow_offsets_right_and_below() {
+        let layout = BoxBuilder::new("Hi")
+            .with_fixed_width(8)
+            .with_shadow(Shadow::new())
+            .build();
+        let lines = layout.render_lines();
+
+        assert_eq!(layout.total_width, 8, "frame width excludes the shadow");
+        assert_eq!(layout.shadow_extent(), (1, 1));
+        assert_eq!(lines[0], "┌──────┐ ");
+        assert_eq!(lines[1], "│  Hi  │░");
+        assert_eq!(lines[2], "└──────┘░");
+        assert_eq!(lines[3], " ░░░░░░░░");
+    }
+
+    #[test]
+    fn test_shadow_glyph_and_color() {
+        let layout = BoxBuilder::new("Hi")
+            .with_shadow(
+                Shadow::new()
+                    .with_glyph("▒")
+                    .with_color("grey")
+                    .with_offset(2, 1),
+            )
+            .build();
+        let rendered = layout.render();
+        let grey = crate::get_color_code("grey");
+
+        assert!(rendered.contains(&format!("{}▒▒{}", grey, crate::RESET)));
+        assert_eq!(layout.shadow_extent(), (2, 1));
+        assert!(rendered.lines().last().unwrap().starts_with("  "));
+    }
+
+    #[test]
+    fn test_uncolored_shadow_skips_text_color() {
+        use crate::api::theming::ColorScheme;
+
+        let layout = BoxBuilder::new("Hi")
+            .with_fixed_width(8)
+            .with_shadow(Shadow::new())
+            .build();
+        let scheme = ColorScheme {
+            border_color: "blue".to_string(),
+            text_color: "red".to_string(),
+            ..ColorScheme::default()
+        };
+        let colored = layout.render_with_colors(&scheme);
+        let red = crate::get_color_code("red");
+
+        assert!(colored.contains(&format!("{}Hi", red)));
+        assert!(!colored.contains(&format!("{}░", red)), "{:?}", colored);
+        assert!(colored.lines().last().unwrap().ends_with("░░░░░░░░"));
+    }
+
+    #[test]
+    fn test_nested_child_sizes_parent() {
+        let child = BoxBuilder::new("inner").with_style(crate::ROUNDED).build();
+        let layout = BoxBuilder::new("outer")
+            .with_child(child.clone())
+            .with_h_padding(1)
+            .build();
+        let rendered = layout.render();
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert_eq!(layout.total_width, child.total_width + 4);
+        assert_eq!(lines[1], format!("│ {:<11} │", "outer"));
+        assert_eq!(lines[2], "│ ╭─────────╮ │");
+        assert_eq!(lines[3], "│ │  inner  │ │");
+        assert_eq!(lines[4], "│ ╰─────────╯ │");
+        for line in lines {
+            assert_eq!(get_text_width(line), layout.total_width);
+        }
+    }
+
+    #[test]
+    fn test_nested_rows_never_wrap() {
+        let child = BoxBuilder::new("a child box line").build();
+        let layout = BoxBuilder::new("a b c d e f g h")
+            .with_child(child.clone())
+            .with_wrapping(true)
+            .with_h_padding(0)
+            .build();
+        let rendered = layout.render();
+
+        // Text wraps around nothing: the child's row drives the width
+        assert_eq!(layout.total_width, child.total_width + 2);
+        for row in child.render().lines() {
+            assert!(rendered.contains(row), "child row split: {}", row);
+        }
     }
 
-    builder.build().render_lines()
-}
+    #[test]
+    fn test_nested_block_keeps_ansi_and_shape() {
+        let block = "\x1b[31m┌──┐\x1b[0m\n\x1b[31m│ab│\x1b[0m\n\x1b[31m└──┘\x1b[0m";
+        let layout = BoxBuilder::new("")
+            .with_block(block)
+            .with_body_alignment(BodyAlignment::Center)
+            .with_fixed_width(12)
+            .build();
+        let rendered = layout.render();
+        let lines: Vec<&str> = rendered.lines().collect();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        assert_eq!(lines.len(), 5);
+        assert_eq!(crate::strip_ansi_codes(lines[2]), "│   │ab│   │");
+        assert!(lines[2].contains("\x1b[31m│ab│\x1b[0m"));
+    }
 
     #[test]
-    fn test_header_builder() {
-        let header = HeaderBuilder::new("Test Header")
-            .align_center()
-            .build_for_width(20);
+    fn test_markdown_body_rows() {
+        let layout = BoxBuilder::new("")
+            .with_markdown("# Notes\n- alpha beta gamma delta\n\n```\nx = 1\n```")
+            .with_fixed_width(20)
+            .with_h_padding(1)
+            .with_wrapping(true)
+            .build();
+        let rendered = crate::strip_ansi_codes(&layout.render());
+        let lines: Vec<&str> = rendered.lines().collect();
 
-        assert_eq!(header.width, 22); // 20 + 2 borders
-        assert_eq!(header.height, 1);
-        assert!(header.content.contains("Test Header"));
+        assert_eq!(lines[1], "│ Notes            │");
+        assert_eq!(lines[2], "├──────────────────┤");
+        assert_eq!(lines[3], "│ • alpha beta     │");
+        assert_eq!(lines[4], "│   gamma delta    │");
+        assert_eq!(lines[6], "│ ╭───────╮        │");
+        assert_eq!(lines[7], "│ │ x = 1 │        │");
     }
 
     #[test]
-    fn test_box_builder_complete() {
-        let layout = BoxBuilder::new("Hello\nWorld")
-            .with_header(HeaderBuilder::new("Title"))
-            .with_footer(FooterBuilder::new("Footer"))
-            .with_status(StatusBuilder::new("Status"))
+    fn test_section_dividers_from_builder_and_markers() {
+        let layout = BoxBuilder::new("intro")
+            .with_section("Summary", "12 passed")
+            .with_section("", "plain")
+            .with_fixed_width(20)
+            .with_h_padding(1)
             .build();
+        let rendered = layout.render();
+        let lines: Vec<&str> = rendered.lines().collect();
 
-        assert!(layout.header.is_some());
-        assert!(layout.footer.is_some());
-        assert!(layout.status.is_some());
-        assert_eq!(layout.body.height, 2); // 2 lines of content + padding
+        assert_eq!(lines[2], "├─ Summary ────────┤");
+        assert_eq!(lines[3], "│ 12 passed        │");
+        assert_eq!(lines[4], "├──────────────────┤");
 
-        let components = layout.components();
-        assert_eq!(components.len(), 4); // header, body, status, footer
+        // Markers on their own line or between #NL# hints; other text is untouched
+        let body = BodyBuilder::new("a#NL##DIV:Errors##NL#b\n#DIV#\nc #DIV:x# d");
+        assert_eq!(
+            body.rows,
+            vec![
+                BodyRow::Text,
+                BodyRow::Rule(Divider::new("Errors", None)),
+                BodyRow::Text,
+                BodyRow::Rule(Divider::new("", None)),
+                BodyRow::Text,
+            ]
+        );
+        assert_eq!(body.lines[2], "b");
+
+        // Center alignment with brackets; auto width grows to fit the label
+        let layout = BoxBuilder::new("x\n#DIV:Totals#\ny")
+            .with_section_style(
+                SectionStyle::new()
+                    .align_center()
+                    .with_decoration(LabelDecoration::Bracket),
+            )
+            .with_h_padding(1)
+            .build();
+        let rendered = layout.render();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[2], "├┤ Totals ├┤");
+        assert_eq!(layout.total_width, 12);
     }
 
     #[test]
-    fn test_emoji_in_layout() {
-        let layout = BoxBuilder::new("Hello 🌟 World").build();
+    fn test_divider_styles_independent_of_frame() {
+        crate::width_calibration::install_uncalibrated();
+        let layout = BoxBuilder::new("a\n#DIV.double:Sum#\nb")
+            .with_divider("", DividerStyle::Dashed)
+            .with_status(StatusBuilder::new("ok").with_divider_style(DividerStyle::Light))
+            .with_section_style(SectionStyle::new().with_divider_style(DividerStyle::Light))
+            .with_style(crate::HEAVY)
+            .with_fixed_width(11)
+            .with_h_padding(1)
+            .build();
         let rendered = layout.render();
+        let lines: Vec<&str> = rendered.lines().collect();
 
-        // Should handle emoji width correctly
-        assert!(rendered.contains("Hello 🌟 World"));
+        assert_eq!(lines[2], "┣═ Sum ═══┫");
+        assert_eq!(lines[4], "┠┄┄┄┄┄┄┄┄┄┨");
+        assert_eq!(lines[5], "┠─────────┨");
+
+        // Bar mode keeps mixed rules whole instead of treating them as content
+        let bar = BoxBuilder::new("a")
+            .with_divider("", DividerStyle::Heavy)
+            .with_barmode()
+            .with_fixed_width(7)
+            .with_h_padding(1)
+            .build()
+            .render();
+        assert!(bar.lines().any(|line| line == "━━━━━"), "{}", bar);
     }
 
     #[test]
-    fn test_box_has_complete_borders() {
-        let layout = BoxBuilder::new("test content").build();
+    fn test_tree_body_truncates_labels_not_connectors() {
+        let layout = BoxBuilder::new("")
+            .with_tree(
+                "root\n  branch\n    a-rather-long-leaf-name\n  leaf",
+                TreeStyle::new(),
+            )
+            .with_style(crate::ROUNDED)
+            .with_fixed_width(20)
+            .with_h_padding(1)
+            .with_wrapping(true)
+            .build();
         let rendered = layout.render();
-
-        // Check all borders are present
-        assert!(rendered.starts_with("┌"), "Missing top-left corner");
-
         let lines: Vec<&str> = rendered.lines().collect();
-        assert!(lines[0].ends_with("┐"), "Missing top-right corner");
 
-        // Check all middle lines have side borders
-        for (i, line) in lines.iter().enumerate() {
-            if i > 0 && i < lines.len() - 1 {
-                assert!(line.starts_with("│"), "Missing left border on line {}", i);
-                assert!(line.ends_with("│"), "Missing right border on line {}", i);
-            }
-        }
+        assert_eq!(lines[2], "│ ├── branch       │");
+        assert_eq!(lines[3], "│ │   ╰── a-rathe… │");
+        assert_eq!(lines[4], "│ ╰── leaf         │");
 
-        let last_line = lines.last().expect("Box should have lines");
-        assert!(last_line.starts_with("└"), "Missing bottom-left corner");
-        assert!(last_line.ends_with("┘"), "Missing bottom-right corner");
+        // Auto width fits the deepest row in full
+        let layout = BoxBuilder::new("")
+            .with_tree("a/b/c", TreeStyle::new())
+            .with_h_padding(1)
+            .build();
+        // Deepest row `    └── c` is 9 columns, plus padding and borders
+        assert_eq!(layout.total_width, 13);
     }
 
     #[test]
-    fn test_box_width_consistency() {
-        let layout = BoxBuilder::new("test").with_fixed_width(30).build();
+    fn test_diff_body_splits_panes_and_joins_frame() {
+        crate::width_calibration::install_uncalibrated();
+        use crate::api::diff::DiffStyle;
+        use crate::core::DiffColors;
+
+        let style = DiffStyle::new().with_titles("a", "b");
+        let layout = BoxBuilder::new("")
+            .with_diff(
+                "same\n中文\nold",
+                "same\n中文字\nnew\nadded",
+                style.clone().with_colors(DiffColors::none()),
+            )
+            .with_h_padding(1)
+            .build();
         let rendered = layout.render();
-
         let lines: Vec<&str> = rendered.lines().collect();
-        let expected_width = layout.total_width;
 
-        for (i, line) in lines.iter().enumerate() {
-            let line_width = get_text_width(line);
-            assert_eq!(
-                line_width, expected_width,
-                "Line {} has width {} but expected {}. Content: '{}'",
-                i, line_width, expected_width, line
-            );
-        }
+        // Widest pane text `~ 中文字` is 8 columns; each pane keeps the padding
+        assert_eq!(lines[0], "┌──────────┬──────────┐");
+        assert_eq!(lines[1], "│ a        │ b        │");
+        assert_eq!(lines[2], "├──────────┼──────────┤");
+        assert_eq!(lines[3], "│   same   │   same   │");
+        assert_eq!(lines[4], "│ ~ 中文   │ ~ 中文字 │");
+        assert_eq!(lines[5], "│ ~ old    │ ~ new    │");
+        assert_eq!(lines[6], "│          │ + added  │");
+        assert_eq!(lines[7], "└──────────┴──────────┘");
+
+        // Changed lines carry their colors; the frame still lines up
+        let layout = BoxBuilder::new("")
+            .with_diff("gone\nkept", "kept", style)
+            .with_style(crate::HEAVY)
+            .build();
+        let rendered = layout.render();
+        let red = crate::get_color_code("red");
+        assert!(rendered.contains(&format!("{}- gone{}", red, crate::RESET)));
+        let plain = crate::strip_ansi_codes(&rendered);
+        assert!(plain.lines().last().unwrap().contains('┻'));
+        assert!(
+            plain
+                .lines()
+                .all(|line| get_text_width(line) == layout.total_width)
+        );
     }
 
     #[test]
-    fn test_body_borders_complete() {
-        let body = BodyBuilder::new("test line")
-            .with_h_padding(2)
-            .build_for_width(20, NORMAL);
+    fn test_code_body_keeps_indentation_with_gutter() {
+        let source = (1..=10)
+            .map(|n| format!("\tline{}", n))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let layout = BoxBuilder::new("")
+            .with_code(
+                &source,
+                CodeStyle::new().with_line_numbers(true).with_tab_width(2),
+            )
+            .with_h_padding(1)
+            .with_wrapping(true)
+            .build();
+        let rendered = layout.render();
+        let lines: Vec<&str> = rendered.lines().collect();
 
-        // Check each line in body has both borders
-        for line in body.content.lines() {
-            assert!(line.starts_with("│"), "Body line missing left border");
-            assert!(line.ends_with("│"), "Body line missing right border");
-        }
+        assert_eq!(lines[1], "│  1 │   line1  │");
+        assert_eq!(lines[10], "│ 10 │   line10 │");
     }
 
     #[test]
-    fn test_status_borders_complete() {
-        let status = StatusBuilder::new("status text")
-            .with_divider(true)
-            .build_for_width(20, NORMAL);
-
-        let lines: Vec<&str> = status.content.lines().collect();
-
-        // First line should be divider
-        assert!(lines[0].starts_with("├"), "Status divider missing left tee");
-        assert!(lines[0].ends_with("┤"), "Status divider missing right tee");
-
-        // All other lines should have vertical borders
-        for line in lines.iter().skip(1) {
-            assert!(line.starts_with("│"), "Status line missing left border");
-            assert!(line.ends_with("│"), "Status line missing right border");
-        }
+    fn test_code_body_truncates_or_soft_wraps() {
+        crate::width_calibration::install_uncalibrated();
+        let source = "    abcdefghijklmnop";
+        let truncated = BoxBuilder::new("")
+            .with_code(source, CodeStyle::new())
+            .with_fixed_width(14)
+            .with_h_padding(1)
+            .build()
+            .render();
+        assert_eq!(truncated.lines().nth(1), Some("│     abcde… │"));
+
+        let wrapped = BoxBuilder::new("")
+            .with_code(source, CodeStyle::new().with_soft_wrap(true))
+            .with_fixed_width(14)
+            .with_h_padding(1)
+            .build()
+            .render();
+        let lines: Vec<&str> = wrapped.lines().collect();
+        assert_eq!(lines[1], "│     abcdef │");
+        assert_eq!(lines[2], "│ ↪ ghijklmn │");
+        assert_eq!(lines[3], "│ ↪ op       │");
     }
 
     #[test]
@@ -1817,6 +4001,24 @@ mod tests {
         assert_eq!(layout.layout_mode, LayoutMode::Box);
     }
 
+    #[test]
+    fn test_barmode_draws_bars_only_for_labels() {
+        let sided = BoxBuilder::new("Test")
+            .with_header(HeaderBuilder::new("H"))
+            .with_borders(BorderSides::parse("tb").unwrap())
+            .build();
+        let bar = BoxBuilder::new("Test")
+            .with_header(HeaderBuilder::new("H"))
+            .with_barmode()
+            .build();
+
+        assert_eq!(sided.edge_rows(), (1, 1));
+        assert_eq!(bar.edge_rows(), (1, 0));
+        let sided_lines = sided.render_lines();
+        let bar_lines = bar.render_lines();
+        assert_eq!(bar_lines[..], sided_lines[..bar_lines.len()]);
+    }
+
     #[test]
     fn test_wrapping_enabled_by_default() {
         let long_text = "This is a very long line that exceeds the box width and should wrap";
@@ -2048,6 +4250,184 @@ mod tests {
         assert!(rendered.contains("…")); // Should truncate
     }
 
+    #[test]
+    fn test_edge_colors_follow_the_frame() {
+        use crate::api::theming::ColorScheme;
+        use crate::core::EdgeColors;
+        use crate::{RESET, get_color_code};
+
+        let scheme = ColorScheme {
+            border_color: "grey".to_string(),
+            text_color: "none".to_string(),
+            edges: EdgeColors {
+                left: Some("red".to_string()),
+                divider: Some("blue".to_string()),
+                ..EdgeColors::default()
+            },
+            ..ColorScheme::default()
+        };
+        let (red, blue, grey) = (
+            get_color_code("red"),
+            get_color_code("blue"),
+            get_color_code("grey"),
+        );
+
+        let layout = BoxBuilder::new("Hi")
+            .with_status(StatusBuilder::new("ok"))
+            .with_margin(Spacing::new(1, 0, 0, 2))
+            .build();
+        let colored = layout.render_with_colors(&scheme);
+        let rows: Vec<&str> = colored.lines().collect();
+
+        // Accent stripe runs down the left edge, corners included
+        assert!(rows[1].starts_with(&format!("  {}┌{}{}─", red, RESET, grey)));
+        assert!(rows[2].starts_with(&format!("  {}│{}", red, RESET)));
+        assert!(rows[2].ends_with(&format!("{}│{}", grey, RESET)));
+        // Status divider: tee in the stripe color, rule in the divider color
+        assert!(rows[3].starts_with(&format!("  {}├{}{}─", red, RESET, blue)));
+        assert!(rows[3].ends_with(&format!("{}┤{}", grey, RESET)));
+        assert_eq!(crate::strip_ansi_codes(&colored), layout.render());
+
+        // Without edge colors the whole frame keeps the border color
+        let plain = ColorScheme {
+            edges: EdgeColors::default(),
+            ..scheme
+        };
+        assert!(!layout.render_with_colors(&plain).contains(red));
+    }
+
+    #[test]
+    fn test_border_sides_combinations() {
+        let render = |sides: &str| {
+            BoxBuilder::new("Hi")
+                .with_header(HeaderBuilder::new("T"))
+                .with_status(StatusBuilder::new("ok"))
+                .with_borders(BorderSides::parse(sides).unwrap())
+                .build()
+                .render()
+        };
+
+        // Edges go, the divider above the status stays as a plain rule
+        let rows = |sides: &str| render(sides).lines().map(String::from).collect::<Vec<_>>();
+        assert_eq!(
+            rows("tb"),
+            [
+                "──T───",
+                "  Hi  ",
+                "──────",
+                "      ",
+                " ok   ",
+                "      ",
+                "──────"
+            ]
+        );
+        assert_eq!(rows("trb")[..3], ["──T───┐", "  Hi  │", "──────┤"]);
+        // Quote style: the unlabeled bottom edge is dropped, the labeled top keeps its text
+        assert_eq!(rows("l")[..3], ["│  T   ", "│  Hi  ", "├──────"]);
+        assert_eq!(rows("l").len(), 6);
+        assert_eq!(rows("none")[..3], ["  T   ", "  Hi  ", "──────"]);
+        assert_eq!(render("all"), render("trbl"));
+        assert!(render("all").starts_with("┌"));
+    }
+
+    #[test]
+    fn test_edge_label_keeps_its_rule_glyphs() {
+        let output = BoxBuilder::new("Hi there")
+            .with_header(HeaderBuilder::new("a─b"))
+            .with_borders(BorderSides::parse("l").unwrap())
+            .build()
+            .render();
+
+        assert_eq!(output.lines().next(), Some("│    a─b     "));
+    }
+
+    #[test]
+    fn test_per_side_padding_and_margin() {
+        let layout = BoxBuilder::new("Hi")
+            .with_padding_sides(Spacing::new(1, 1, 0, 3))
+            .with_margin(Spacing::new(1, 2, 0, 4))
+            .build();
+        let rows: Vec<String> = layout.render().lines().map(String::from).collect();
+
+        // Top margin, then the frame indented by the left margin
+        assert_eq!(rows[0], " ".repeat(4 + 8 + 2));
+        assert_eq!(rows[1], "    ┌──────┐  ");
+        assert_eq!(rows[2], "    │      │  ");
+        assert_eq!(rows[3], "    │   Hi │  ");
+        assert_eq!(rows[4], "    └──────┘  ");
+        assert_eq!(rows.len(), 5);
+
+        let dims = layout.dimensions();
+        assert_eq!((dims.outer_width, dims.outer_height), (14, 5));
+        assert_eq!((dims.total_width, dims.total_height), (8, 4));
+        assert_eq!(dims.padding, Spacing::new(1, 1, 0, 3));
+    }
+
+    #[test]
+    fn test_margin_counts_against_overflow_limit() {
+        let layout = BoxBuilder::new("word ".repeat(20).trim())
+            .with_margin(Spacing::horizontal(5))
+            .with_overflow(OverflowPolicy::Wrap, 40)
+            .build();
+        assert_eq!(layout.total_width, 30);
+        assert!(layout.render().lines().all(|row| get_text_width(row) == 40));
+    }
+
+    #[test]
+    fn test_overflow_policies_fit_the_limit() {
+        let text = "alpha beta gamma delta epsilon zeta eta theta";
+        let build = |policy| {
+            BoxBuilder::new(text)
+                .with_header(HeaderBuilder::new("A rather long header label"))
+                .with_wrapping(false)
+                .with_overflow(policy, 24)
+                .build()
+        };
+
+        let wrapped = build(OverflowPolicy::Wrap);
+        assert_eq!(wrapped.total_width, 24);
+        assert!(wrapped.render().lines().count() > 3);
+        assert!(wrapped.render().contains('…')); // header truncated
+        assert_eq!(wrapped.overflow.as_ref().unwrap().width, 51);
+
+        let truncated = build(OverflowPolicy::Truncate).render();
+        assert_eq!(truncated.lines().count(), 3);
+        assert!(truncated.lines().all(|l| get_text_width(l) == 24));
+
+        // Shrink gives up padding before wrapping: "alpha beta ... " fills the row
+        let shrunk = build(OverflowPolicy::Shrink).render();
+        assert!(shrunk.lines().nth(1).unwrap().starts_with("│alpha"));
+
+        assert!(
+            BoxBuilder::new(text)
+                .with_overflow(OverflowPolicy::Error, 24)
+                .try_build()
+                .is_err()
+        );
+        // Boxes that fit are untouched
+        let fits = BoxBuilder::new("ok").with_overflow(OverflowPolicy::Error, 24);
+        assert!(fits.try_build().unwrap().overflow.is_none());
+    }
+
+    #[test]
+    fn test_size_specs_resolve_against_available_size() {
+        let build = |width: &str, height: &str| {
+            let layout = BoxBuilder::new("Short")
+                .with_width_spec(SizeSpec::parse(width).unwrap())
+                .with_height_spec(SizeSpec::parse(height).unwrap())
+                .with_available_size(100, 30)
+                .build();
+            (layout.total_width, layout.render().lines().count())
+        };
+
+        assert_eq!(build("50%", "max"), (50, 30));
+        assert_eq!(build("-4", "min(10,100%)"), (96, 10));
+
+        // auto keeps content sizing; relative sizes never exceed the available space
+        let natural = BoxBuilder::new("Short").build().total_width;
+        assert_eq!(build("auto", "max(80,50%)"), (natural, 30));
+    }
+
     #[test]
     fn test_min_height_constraint() {
         let layout = BoxBuilder::new("Short content")
@@ -2227,6 +4607,7 @@ mod tests {
 
     #[test]
     fn test_icon_prepends_to_first_line() {
+        crate::width_calibration::install_uncalibrated();
         let layout = BoxBuilder::new("Content")
             .with_icon("🔥")
             .with_fixed_width(30)
@@ -2245,6 +4626,7 @@ mod tests {
 
     #[test]
     fn test_icon_with_emoji_avoids_double_icons() {
+        crate::width_calibration::install_uncalibrated();
         let layout = BoxBuilder::new("😀 Already has emoji")
             .with_icon("🔥")
             .with_fixed_width(40)
@@ -2260,6 +4642,7 @@ mod tests {
 
     #[test]
     fn test_title_and_icon_together() {
+        crate::width_calibration::install_uncalibrated();
         let layout = BoxBuilder::new("Body text")
             .with_title("Title")
             .with_icon("⭐")
@@ -2281,6 +4664,7 @@ mod tests {
 
     #[test]
     fn test_config_adapter_maps_icon() {
+        crate::width_calibration::install_uncalibrated();
         use crate::api::config::BoxyConfig;
 
         let mut config = BoxyConfig::default();
@@ -2314,6 +4698,7 @@ mod tests {
 
     #[test]
     fn test_auto_width_with_icon_only() {
+        crate::width_calibration::install_uncalibrated();
         // Regression test for blocking issue: icon not included in auto-width
         let layout = BoxBuilder::new("Status").with_icon("⚠️").build();
 
@@ -2329,6 +4714,7 @@ mod tests {
 
     #[test]
     fn test_auto_width_with_title_and_icon() {
+        crate::width_calibration::install_uncalibrated();
         // Regression test: both title and icon should contribute to width
         let layout = BoxBuilder::new("Body")
             .with_title("Long Title Here")
@@ -2344,4 +4730,239 @@ mod tests {
         // Width should accommodate the longest content (title + icon)
         assert!(layout.total_width >= "Long Title Here".len() + 4); // title + icon + padding/borders
     }
+
+    #[test]
+    fn test_body_alignment_center_and_right() {
+        let layout = BoxBuilder::new("ab\n#R#cd")
+            .with_fixed_width(12)
+            .with_h_padding(1)
+            .with_body_alignment(BodyAlignment::Center)
+            .build();
+        let output = layout.render();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines[1], "│    ab    │");
+        assert_eq!(lines[2], "│       cd │");
+    }
+
+    #[test]
+    fn test_body_alignment_justify_wrapped() {
+        let layout = BoxBuilder::new("one two three four")
+            .with_fixed_width(15)
+            .with_h_padding(1)
+            .with_body_alignment(BodyAlignment::Justify)
+            .build();
+        let output = layout.render();
+        let lines: Vec<&str> = output.lines().collect();
+
+        // Inner content width is 11: first line is stretched, last stays ragged
+        assert_eq!(lines[1], "│ one     two │");
+        assert_eq!(lines[2], "│ three four  │");
+    }
+
+    #[test]
+    fn test_body_emoji_padding_indents_following_lines() {
+        crate::width_calibration::install_uncalibrated();
+        let layout = BoxBuilder::new("Line one\nLine two")
+            .with_icon("✅")
+            .with_emoji_padding(true)
+            .with_h_padding(1)
+            .build();
+        let output = layout.render();
+        let lines: Vec<&str> = output.lines().collect();
+
+        let first_text = lines[1].find("Line one").unwrap();
+        let second_text = lines[2].find("Line two").unwrap();
+        assert_eq!(
+            get_text_width(&lines[1][..first_text]),
+            get_text_width(&lines[2][..second_text])
+        );
+    }
+
+    #[test]
+    fn test_body_emoji_padding_wraps_beside_the_indent() {
+        crate::width_calibration::install_uncalibrated();
+        let text = "one two three four five six seven eight nine ten";
+        let with_icon = BoxBuilder::new(text).with_icon("✅");
+        // The CLI prepends the icon to the title instead of setting one
+        let leading = BoxBuilder::new(text).with_title("✅ Done");
+
+        for builder in [with_icon, leading] {
+            let output = builder
+                .with_emoji_padding(true)
+                .with_wrapping(true)
+                .with_fixed_width(22)
+                .with_h_padding(1)
+                .build()
+                .render();
+            let lines: Vec<&str> = output.lines().collect();
+
+            assert!(!output.contains('…'), "wrapped line cut:\n{}", output);
+            assert!(lines.iter().all(|l| get_text_width(l) == 22));
+            assert!(lines[1].starts_with("│ ✅ "));
+            for line in &lines[2..lines.len() - 1] {
+                assert!(line.starts_with("│    "), "not indented: {}", line);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sanitize_options_set_tab_width() {
+        let options = SanitizeOptions {
+            tab_width: 8,
+            ..SanitizeOptions::default()
+        };
+        let output = BoxBuilder::new("a\tb")
+            .with_sanitize_options(options)
+            .with_wrapping(false)
+            .with_h_padding(1)
+            .build()
+            .render();
+
+        assert_eq!(output.lines().nth(1), Some("│ a       b │"));
+    }
+
+    #[test]
+    fn test_header_and_status_are_sanitized() {
+        let options = SanitizeOptions {
+            tab_width: 4,
+            controls: crate::core::ControlStyle::Caret,
+        };
+        let output = BoxBuilder::new("body")
+            .with_header(HeaderBuilder::new("a\tb\x07"))
+            .with_status(StatusBuilder::new("ok\x1b\tdone"))
+            .with_sanitize_options(options)
+            .build()
+            .render();
+
+        assert!(!output.contains(['\t', '\x07', '\x1b']), "{}", output);
+        assert!(output.contains("a   b^G"), "{}", output);
+        assert!(output.contains("ok^[    done"), "{}", output);
+        let widths: Vec<usize> = output.lines().map(get_text_width).collect();
+        assert!(widths.iter().all(|w| *w == widths[0]), "{}", output);
+    }
+
+    #[test]
+    fn test_default_sanitize_covers_every_row() {
+        let output = BoxBuilder::new("")
+            .with_title("t\x07itle")
+            .with_footer(FooterBuilder::new("f\to"))
+            .with_code("x\x08y", CodeStyle::new())
+            .build()
+            .render();
+
+        assert!(!output.contains(['\t', '\x07', '\x08']), "{:?}", output);
+        assert!(
+            output.contains("title") && output.contains("xy"),
+            "{}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_alignment_markers_do_not_affect_auto_width() {
+        let plain = BoxBuilder::new("content").build();
+        let marked = BoxBuilder::new("#C#content").build();
+        assert_eq!(plain.total_width, marked.total_width);
+    }
+
+    #[test]
+    fn test_vertical_align_distributes_fill() {
+        let build = |align| {
+            BoxBuilder::new("msg")
+                .with_fixed_height(7)
+                .with_vertical_align(align)
+                .build()
+        };
+
+        let top = build(VerticalAlign::Top);
+        let middle = build(VerticalAlign::Middle);
+        let bottom = build(VerticalAlign::Bottom);
+
+        // 5 body rows: 1 content line + 4 fill rows
+        assert_eq!(top.body_fill, (0, 4));
+        assert_eq!(middle.body_fill, (2, 2));
+        assert_eq!(bottom.body_fill, (4, 0));
+
+        let lines: Vec<String> = bottom.render().lines().map(String::from).collect();
+        assert_eq!(lines.len(), 7);
+        assert!(lines[5].contains("msg"));
+        assert_eq!(bottom.body.v_align, VerticalAlign::Bottom);
+    }
+
+    #[test]
+    fn test_border_label_decorations_and_inset() {
+        let top = |header: HeaderBuilder| {
+            BoxBuilder::new("body")
+                .with_fixed_width(20)
+                .with_style(crate::visual::ROUNDED)
+                .with_header(header)
+                .build()
+                .header
+                .unwrap()
+                .content
+        };
+
+        assert_eq!(
+            top(HeaderBuilder::new("Build")
+                .with_decoration(LabelDecoration::Spaced)
+                .align_left()
+                .with_inset(1)),
+            "╭─ Build ──────────╮"
+        );
+        assert_eq!(
+            top(HeaderBuilder::new("Build")
+                .with_decoration(LabelDecoration::Bracket)
+                .align_right()
+                .with_inset(2)),
+            "╭───────┤ Build ├──╮"
+        );
+
+        let footer = BoxBuilder::new("body")
+            .with_fixed_width(20)
+            .with_footer(FooterBuilder::new("v1.0").with_decoration(LabelDecoration::Spaced))
+            .build()
+            .footer
+            .unwrap()
+            .content;
+        assert_eq!(footer, "└────── v1.0 ──────┘");
+    }
+
+    #[test]
+    fn test_border_label_truncates_with_ellipsis() {
+        let header = BoxBuilder::new("x")
+            .with_fixed_width(14)
+            .with_header(
+                HeaderBuilder::new("A very long title")
+                    .with_decoration(LabelDecoration::Bracket)
+                    .align_left()
+                    .with_inset(1),
+            )
+            .build()
+            .header
+            .unwrap()
+            .content;
+
+        assert_eq!(get_text_width(&header), 14);
+        assert!(header.contains("…"));
+        assert!(header.starts_with("┌─┤ "));
+    }
+
+    #[test]
+    fn test_border_label_placement_survives_barmode() {
+        let layout = BoxBuilder::new("content")
+            .with_fixed_width(20)
+            .with_header(
+                HeaderBuilder::new("Log")
+                    .with_decoration(LabelDecoration::Spaced)
+                    .align_left()
+                    .with_inset(1),
+            )
+            .with_barmode()
+            .build();
+
+        let output = layout.render();
+        let first = output.lines().next().unwrap();
+        assert_eq!(first, "─ Log ────────────");
+    }
 }
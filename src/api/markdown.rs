@@ -0,0 +1,511 @@
+//! Markdown body mode - turn markdown text into box body rows
+//!
+//! Supports the subset that shows up in release notes and CI summaries:
+//! headings (bold title lines and dividers), bullet and numbered lists
+//! (hanging indent when wrapped), `**bold**`, `*italic*`, `` `inline code` ``
+//! and fenced code blocks (rendered as nested boxes). Everything else is
+//! treated as paragraph text.
+//!
+//! # Example
+//! ```rust
+//! use boxy::api::layout::BoxBuilder;
+//!
+//! let notes = "# v1.2\n- faster *startup*\n- fixed `--wrap`";
+//! let layout = BoxBuilder::new("").with_markdown(notes).with_fixed_width(30).build();
+//! assert!(layout.render().contains("• faster"));
+//! ```
+
+use crate::{BOLD, ITALIC, RESET, expand_link_markup, get_color_code, read_escape_sequence};
+
+/// Color used for inline code spans
+const INLINE_CODE_COLOR: &str = "cyan";
+
+/// Bullet shown for `-`, `*` and `+` list items
+const BULLET: &str = "•";
+
+/// One body row produced from markdown
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkdownRow {
+    /// Styled text line (paragraph, heading or blank separator)
+    Line(String),
+    /// List item: continuation lines wrap under `text`, past the marker
+    Item { marker: String, text: String },
+    /// Horizontal divider
+    Rule,
+    /// Fenced code block, lines kept verbatim
+    Code {
+        lang: Option<String>,
+        lines: Vec<String>,
+    },
+}
+
+/// Parse markdown into body rows
+pub fn parse_markdown(source: &str) -> Vec<MarkdownRow> {
+    let mut parser = Parser::default();
+    for line in source.lines() {
+        parser.feed(line);
+    }
+    parser.finish()
+}
+
+#[derive(Default)]
+struct Parser {
+    rows: Vec<MarkdownRow>,
+    paragraph: Vec<String>,
+    item: Option<(String, Vec<String>)>,
+    /// Open fence: (fence marker, language, lines)
+    fence: Option<(String, Option<String>, Vec<String>)>,
+}
+
+impl Parser {
+    fn feed(&mut self, line: &str) {
+        let trimmed = line.trim();
+
+        if let Some((marker, _, lines)) = &mut self.fence {
+            if trimmed.starts_with(marker.as_str()) {
+                let (_, lang, lines) = self.fence.take().unwrap();
+                self.rows.push(MarkdownRow::Code { lang, lines });
+            } else {
+                lines.push(line.to_string());
+            }
+            return;
+        }
+
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            self.flush();
+            let lang = trimmed[3..].trim_start_matches(['`', '~']).trim();
+            let lang = (!lang.is_empty()).then(|| lang.to_string());
+            self.fence = Some((trimmed[..3].to_string(), lang, Vec::new()));
+        } else if trimmed.is_empty() {
+            self.flush();
+            self.push_blank();
+        } else if let Some((level, text)) = heading(trimmed) {
+            self.flush();
+            self.push_heading(level, text);
+        } else if is_rule(trimmed) {
+            self.flush();
+            self.push_rule();
+        } else if let Some((marker, text)) = list_item(line) {
+            self.flush();
+            self.item = Some((marker, vec![text.to_string()]));
+        } else if let Some((_, parts)) = &mut self.item {
+            // Indented and lazy continuation lines both extend the open item
+            parts.push(trimmed.to_string());
+        } else {
+            self.paragraph.push(trimmed.to_string());
+        }
+    }
+
+    fn finish(mut self) -> Vec<MarkdownRow> {
+        self.flush();
+        // An unterminated fence still shows its code
+        if let Some((_, lang, lines)) = self.fence.take() {
+            self.rows.push(MarkdownRow::Code { lang, lines });
+        }
+        while self.rows.last() == Some(&MarkdownRow::Line(String::new())) {
+            self.rows.pop();
+        }
+        self.rows
+    }
+
+    /// Emit the pending paragraph or list item
+    fn flush(&mut self) {
+        if !self.paragraph.is_empty() {
+            let text = self.paragraph.join(" ");
+            self.rows.push(MarkdownRow::Line(render_inline(&text)));
+            self.paragraph.clear();
+        }
+        if let Some((marker, parts)) = self.item.take() {
+            let text = render_inline(&parts.join(" "));
+            self.rows.push(MarkdownRow::Item { marker, text });
+        }
+    }
+
+    /// Single blank separator; none at the start or right after a divider
+    fn push_blank(&mut self) {
+        match self.rows.last() {
+            None | Some(MarkdownRow::Rule) => {}
+            Some(MarkdownRow::Line(text)) if text.is_empty() => {}
+            _ => self.rows.push(MarkdownRow::Line(String::new())),
+        }
+    }
+
+    fn push_rule(&mut self) {
+        while self.rows.last() == Some(&MarkdownRow::Line(String::new())) {
+            self.rows.pop();
+        }
+        if !matches!(self.rows.last(), None | Some(MarkdownRow::Rule)) {
+            self.rows.push(MarkdownRow::Rule);
+        }
+    }
+
+    /// `#` is a title line underlined by a divider, `##` opens a new divided
+    /// section, deeper levels are plain bold lines
+    fn push_heading(&mut self, level: usize, text: &str) {
+        if level == 2 {
+            self.push_rule();
+        }
+        self.rows.push(MarkdownRow::Line(style_spans(text, true)));
+        if level == 1 {
+            self.rows.push(MarkdownRow::Rule);
+        }
+    }
+}
+
+/// `## Title` -> (2, "Title")
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let rest = &line[level..];
+    if (1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' ')) {
+        Some((level, rest.trim().trim_end_matches('#').trim_end()))
+    } else {
+        None
+    }
+}
+
+/// `---`, `***` or `___` (spaces allowed)
+fn is_rule(line: &str) -> bool {
+    let marks: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    marks.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|&m| marks.chars().all(|c| c == m))
+}
+
+/// Split a list item into its display marker and text; nesting follows the source indent
+fn list_item(line: &str) -> Option<(String, &str)> {
+    let body = line.trim_start();
+    let indent = " ".repeat((line.len() - body.len()) / 2 * 2);
+
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(text) = body.strip_prefix(bullet) {
+            return Some((format!("{}{} ", indent, BULLET), text.trim()));
+        }
+    }
+
+    let digits = body.chars().take_while(|c| c.is_ascii_digit()).count();
+    let rest = &body[digits..];
+    if digits > 0 && (rest.starts_with(". ") || rest.starts_with(") ")) {
+        let marker = format!("{}{} ", indent, &body[..digits + 1]);
+        return Some((marker, rest[2..].trim()));
+    }
+    None
+}
+
+/// Apply bold, italic and inline code styling to a line of markdown text
+pub fn render_inline(text: &str) -> String {
+    style_spans(&expand_link_markup(text), false)
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+struct SpanStyle {
+    bold: bool,
+    italic: bool,
+    code: bool,
+}
+
+/// A piece of inline text, backslash escapes and escape sequences resolved
+enum Token {
+    /// A character that may open or close a span
+    Char(char),
+    /// A backslash-escaped character, never markup
+    Literal(char),
+    /// Passed-through escape sequence (color, hyperlink), copied whole
+    Escape(String),
+}
+
+impl Token {
+    /// The character as far as word edges and whitespace are concerned
+    fn char(&self) -> char {
+        match self {
+            Token::Char(c) | Token::Literal(c) => *c,
+            Token::Escape(_) => '\x1b',
+        }
+    }
+}
+
+/// Split text into tokens in one forward pass
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            '\x1b' => Token::Escape(read_escape_sequence(&mut chars)),
+            '\\' if chars.peek().is_some_and(|n| n.is_ascii_punctuation()) => {
+                Token::Literal(chars.next().unwrap())
+            }
+            c => Token::Char(c),
+        };
+        tokens.push(token);
+    }
+    tokens
+}
+
+/// Markers looked ahead for: `` ` ``, `*`, `_`, `**` and `__`
+const LOOKAHEAD: usize = 5;
+
+fn marker_slot(c: char, doubled: bool) -> Option<usize> {
+    match (c, doubled) {
+        ('`', false) => Some(0),
+        ('*', false) => Some(1),
+        ('_', false) => Some(2),
+        ('*', true) => Some(3),
+        ('_', true) => Some(4),
+        _ => None,
+    }
+}
+
+/// For every token, where each unescaped marker next occurs from there on
+///
+/// Built in one backward pass so closing markers are found without rescanning.
+fn next_markers(tokens: &[Token]) -> Vec<[Option<usize>; LOOKAHEAD]> {
+    let mut table = vec![[None; LOOKAHEAD]; tokens.len() + 1];
+    for i in (0..tokens.len()).rev() {
+        table[i] = table[i + 1];
+        if let Token::Char(c) = tokens[i] {
+            if let Some(slot) = marker_slot(c, false) {
+                table[i][slot] = Some(i);
+            }
+            if matches!(tokens.get(i + 1), Some(Token::Char(n)) if *n == c)
+                && let Some(slot) = marker_slot(c, true)
+            {
+                table[i][slot] = Some(i);
+            }
+        }
+    }
+    table
+}
+
+fn style_spans(text: &str, bold: bool) -> String {
+    let tokens = tokenize(text);
+    let next_marker = next_markers(&tokens);
+    let closes_at = |from: usize, c: char, doubled: bool| {
+        marker_slot(c, doubled).and_then(|slot| next_marker[from.min(tokens.len())][slot])
+    };
+    let mut spans: Vec<(String, SpanStyle)> = Vec::new();
+    let mut style = SpanStyle {
+        bold,
+        ..SpanStyle::default()
+    };
+    let mut current = String::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let c = match &tokens[i] {
+            Token::Char(c) => *c,
+            Token::Literal(c) => {
+                current.push(*c);
+                i += 1;
+                continue;
+            }
+            Token::Escape(sequence) => {
+                current.push_str(sequence);
+                i += 1;
+                continue;
+            }
+        };
+        let next = tokens.get(i + 1).map(Token::char);
+
+        if c == '`'
+            && let Some(end) = closes_at(i + 1, '`', false)
+        {
+            flush(&mut current, style, &mut spans);
+            // Code is shown as typed, backslashes included
+            let code: String = tokens[i + 1..end]
+                .iter()
+                .map(|token| match token {
+                    Token::Literal(c) => format!("\\{}", c),
+                    Token::Char(c) => c.to_string(),
+                    Token::Escape(sequence) => sequence.clone(),
+                })
+                .collect();
+            spans.push((
+                code,
+                SpanStyle {
+                    code: true,
+                    ..style
+                },
+            ));
+            i = end + 1;
+            continue;
+        }
+
+        if (c == '*' || c == '_') && next == Some(c) && matches!(tokens[i + 1], Token::Char(_)) {
+            let closes_later = closes_at(i + 2, c, true).is_some();
+            if style.bold != bold || closes_later {
+                flush(&mut current, style, &mut spans);
+                style.bold = !style.bold;
+            } else {
+                current.extend([c, c]);
+            }
+            i += 2;
+            continue;
+        }
+
+        if c == '*' || c == '_' {
+            let prev = i.checked_sub(1).map(|p| tokens[p].char());
+            let word_edge = |ch: Option<char>| !ch.is_some_and(|ch| ch.is_alphanumeric());
+            let opens = !style.italic
+                && next.is_some_and(|n| !n.is_whitespace())
+                && (c == '*' || word_edge(prev))
+                && closes_at(i + 1, c, false).is_some();
+            let closes = style.italic && (c == '*' || word_edge(next));
+            if opens || closes {
+                flush(&mut current, style, &mut spans);
+                style.italic = !style.italic;
+                i += 1;
+                continue;
+            }
+        }
+
+        current.push(c);
+        i += 1;
+    }
+    flush(&mut current, style, &mut spans);
+
+    spans
+        .into_iter()
+        .map(|(text, style)| paint_words(&text, style))
+        .collect()
+}
+
+fn flush(current: &mut String, style: SpanStyle, spans: &mut Vec<(String, SpanStyle)>) {
+    if !current.is_empty() {
+        spans.push((std::mem::take(current), style));
+    }
+}
+
+/// Style each word separately so wrapping at spaces never splits an escape span
+fn paint_words(text: &str, style: SpanStyle) -> String {
+    let mut codes = String::new();
+    if style.bold {
+        codes.push_str(BOLD);
+    }
+    if style.italic {
+        codes.push_str(ITALIC);
+    }
+    if style.code {
+        codes.push_str(get_color_code(INLINE_CODE_COLOR));
+    }
+    if codes.is_empty() {
+        return text.to_string();
+    }
+
+    text.split(' ')
+        .map(|word| match word {
+            "" => String::new(),
+            _ => format!("{}{}{}", codes, word, RESET),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strip_ansi_codes;
+
+    fn plain(rows: &[MarkdownRow]) -> Vec<String> {
+        rows.iter()
+            .map(|row| match row {
+                MarkdownRow::Line(text) => strip_ansi_codes(text),
+                MarkdownRow::Item { marker, text } => {
+                    format!("{}{}", marker, strip_ansi_codes(text))
+                }
+                MarkdownRow::Rule => "---".to_string(),
+                MarkdownRow::Code { lang, lines } => {
+                    format!("code:{}:{}", lang.as_deref().unwrap_or(""), lines.join("|"))
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_headings_map_to_title_lines_and_dividers() {
+        let rows = parse_markdown("# Release\n\nIntro text\n\n## Fixes\n\n### Minor\nok");
+        assert_eq!(
+            plain(&rows),
+            vec![
+                "Release",
+                "---",
+                "Intro text",
+                "---",
+                "Fixes",
+                "",
+                "Minor",
+                "ok"
+            ]
+        );
+        assert_eq!(
+            rows[0],
+            MarkdownRow::Line(format!("{}Release{}", BOLD, RESET))
+        );
+    }
+
+    #[test]
+    fn test_paragraph_lines_join() {
+        let rows = parse_markdown("one\ntwo\n\nthree");
+        assert_eq!(plain(&rows), vec!["one two", "", "three"]);
+    }
+
+    #[test]
+    fn test_lists_with_nesting_and_continuation() {
+        let rows = parse_markdown("- first\n  continued\n  - nested\n1. numbered\n2) second");
+        assert_eq!(
+            plain(&rows),
+            vec![
+                "• first continued",
+                "  • nested",
+                "1. numbered",
+                "2) second"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fenced_code_keeps_lines_verbatim() {
+        let rows = parse_markdown("before\n```rust\nfn main() {\n    run();\n}\n```\nafter");
+        assert_eq!(
+            plain(&rows),
+            vec!["before", "code:rust:fn main() {|    run();|}", "after"]
+        );
+    }
+
+    #[test]
+    fn test_inline_styles() {
+        let bold = format!("{}very{} {}bold{}", BOLD, RESET, BOLD, RESET);
+        assert_eq!(render_inline("**very bold**"), bold);
+        assert_eq!(
+            render_inline("an *em* word"),
+            format!("an {}em{} word", ITALIC, RESET)
+        );
+        let code = get_color_code(INLINE_CODE_COLOR);
+        assert_eq!(
+            render_inline("run `make **x**`"),
+            format!("run {}make{} {}**x**{}", code, RESET, code, RESET)
+        );
+    }
+
+    #[test]
+    fn test_unmatched_and_intra_word_markers_stay_literal() {
+        assert_eq!(render_inline("2 * 3 = 6"), "2 * 3 = 6");
+        assert_eq!(render_inline("snake_case_name"), "snake_case_name");
+        assert_eq!(render_inline("**open"), "**open");
+        assert_eq!(render_inline(r"\*literal\*"), "*literal*");
+    }
+
+    #[test]
+    fn test_escaped_markers_never_close_a_span() {
+        assert_eq!(render_inline(r"*a \* b"), r"*a * b");
+        assert_eq!(render_inline(r"**a \** b"), r"**a ** b");
+        let italic = |word: &str| format!("{}{}{}", ITALIC, word, RESET);
+        assert_eq!(
+            render_inline(r"*a \* b*"),
+            [italic("a"), italic("*"), italic("b")].join(" ")
+        );
+
+        // Escape sequences are read once, not copied out of the rest of the line
+        let text = format!("{}x", "\x1b[0m".repeat(50_000));
+        assert_eq!(strip_ansi_codes(&render_inline(&text)), "x");
+    }
+}
@@ -70,10 +70,16 @@
 //! ```
 
 pub mod config;
+pub mod diff;
+pub mod export;
 pub mod geometry;
 pub mod layout;
+pub mod markdown;
+pub mod profile;
+pub mod reverse;
 pub mod room_runtime;
 pub mod theming;
+pub mod tree;
 
 // Re-export curated public API (RSB MODULE_SPEC pattern)
 // Core geometry functions - always available
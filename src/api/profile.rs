@@ -0,0 +1,197 @@
+//! Output profiles - make rendered boxes safe to paste elsewhere
+//!
+//! Unicode box glyphs and emoji misalign once a box lands in a proportional
+//! font (GitHub comments, Slack, issue trackers). A profile picks a box style
+//! that survives the destination and post-processes `BoxLayout::render_lines`:
+//!
+//! - `markdown`: plain `NORMAL` style, no colors, wrapped in a fenced code block
+//! - `ascii-strict`: `ASCII` style, no colors, every non-ASCII cluster replaced
+//!   by a placeholder of the same display width so columns still line up
+//!
+//! # Example
+//! ```rust
+//! use boxy::api::layout::BoxBuilder;
+//! use boxy::api::profile::OutputProfile;
+//!
+//! let profile = OutputProfile::AsciiStrict;
+//! let layout = profile.prepare(BoxBuilder::new("Done ✅")).build();
+//! assert_eq!(
+//!     profile.apply(&layout.render_lines()),
+//!     vec!["+-----------+", "|  Done ??  |", "+-----------+"]
+//! );
+//! ```
+
+use crate::api::layout::BoxBuilder;
+use crate::strip_ansi_codes;
+use crate::visual::{ASCII, BoxStyle, NORMAL};
+use crate::width_plugin::{get_display_width, split_clusters};
+
+/// Where rendered output is headed
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum OutputProfile {
+    /// Colors and Unicode as configured
+    #[default]
+    Terminal,
+    /// Fenced code block for GitHub, Slack and other markdown renderers
+    Markdown,
+    /// Pure ASCII for logs, email and fonts without box glyphs
+    AsciiStrict,
+}
+
+impl OutputProfile {
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name.to_lowercase().as_str() {
+            "terminal" | "default" => Ok(Self::Terminal),
+            "markdown" | "md" => Ok(Self::Markdown),
+            "ascii-strict" | "ascii" => Ok(Self::AsciiStrict),
+            _ => Err(format!(
+                "Invalid profile '{}'. Valid: terminal, markdown, ascii-strict",
+                name
+            )),
+        }
+    }
+
+    /// Box style the profile requires; `None` keeps the configured style
+    pub fn forced_style(&self) -> Option<BoxStyle> {
+        match self {
+            Self::Terminal => None,
+            Self::Markdown => Some(NORMAL),
+            Self::AsciiStrict => Some(ASCII),
+        }
+    }
+
+    /// Whether colors (and hyperlink escapes) are dropped
+    pub fn strips_colors(&self) -> bool {
+        *self != Self::Terminal
+    }
+
+    /// Apply the forced style to a builder before it is built
+    pub fn prepare(&self, builder: BoxBuilder) -> BoxBuilder {
+        match self.forced_style() {
+            Some(style) => builder.with_style(style),
+            None => builder,
+        }
+    }
+
+    /// Transform rendered lines (or multi-line chunks) for the destination
+    pub fn apply(&self, lines: &[String]) -> Vec<String> {
+        let rows: Vec<String> = lines
+            .iter()
+            .flat_map(|chunk| chunk.split('\n'))
+            .map(|row| match self.strips_colors() {
+                true => strip_ansi_codes(row),
+                false => row.to_string(),
+            })
+            .collect();
+        match self {
+            Self::Terminal => rows,
+            Self::Markdown => fence(rows),
+            Self::AsciiStrict => rows.iter().map(|row| transliterate(row)).collect(),
+        }
+    }
+}
+
+/// Wrap lines in a fenced code block, longer than any backtick run inside
+pub fn fence(lines: Vec<String>) -> Vec<String> {
+    let longest_run = lines
+        .iter()
+        .flat_map(|line| line.split(|c| c != '`'))
+        .map(str::len)
+        .max()
+        .unwrap_or(0);
+    let marker = "`".repeat(longest_run.max(2) + 1);
+
+    let mut fenced = Vec::with_capacity(lines.len() + 2);
+    fenced.push(format!("{}text", marker));
+    fenced.extend(lines);
+    fenced.push(marker);
+    fenced
+}
+
+/// Replace non-ASCII clusters with ASCII of the same display width
+///
+/// Box drawing, arrows, bullets and shades get a lookalike; anything else
+/// becomes `?` per column (`✅` -> `??`, `中` -> `??`).
+pub fn transliterate(text: &str) -> String {
+    split_clusters(text)
+        .into_iter()
+        .map(|cluster| {
+            let mut chars = cluster.chars();
+            let first = chars.next().unwrap_or(' ');
+            if first.is_ascii() && chars.next().is_none() {
+                return cluster;
+            }
+            let width = get_display_width(&cluster);
+            match (ascii_lookalike(first), width) {
+                (Some(ascii), 1) => ascii.to_string(),
+                _ => "?".repeat(width),
+            }
+        })
+        .collect()
+}
+
+fn ascii_lookalike(ch: char) -> Option<char> {
+    let ascii = match ch {
+        '─' | '━' | '═' | '╌' | '┄' | '┈' | '–' | '—' => '-',
+        '│' | '┃' | '║' | '╎' | '┆' | '┊' => '|',
+        '┌' | '┐' | '└' | '┘' | '├' | '┤' | '┬' | '┴' | '┼' | '╭' | '╮' | '╰' | '╯' => {
+            '+'
+        }
+        '┏' | '┓' | '┗' | '┛' | '┣' | '┫' | '┳' | '┻' | '╋' => '+',
+        '╔' | '╗' | '╚' | '╝' | '╠' | '╣' | '╦' | '╩' | '╬' => '+',
+        '•' | '◦' | '·' | '∙' => '*',
+        '→' | '▶' | '›' | '»' => '>',
+        '←' | '◀' | '‹' | '«' => '<',
+        '↪' => '>',
+        '…' => '.',
+        '‘' | '’' => '\'',
+        '“' | '”' => '"',
+        '░' | '▒' | '▓' | '█' => '#',
+        '\u{00A0}' => ' ',
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transliterate_keeps_width() {
+        crate::width_calibration::install_uncalibrated();
+        for text in ["ok ✅ 中文 ℹ️ done…", "├── café", "👨‍👩‍👧 family"]
+        {
+            let ascii = transliterate(text);
+            assert!(ascii.is_ascii(), "{}", ascii);
+            assert_eq!(get_display_width(&ascii), get_display_width(text));
+        }
+        assert_eq!(transliterate("• a → b"), "* a > b");
+    }
+
+    #[test]
+    fn test_markdown_profile_fences_plain_rows() {
+        let chunks = vec!["\x1b[31m┌──┐\x1b[0m\n│hi│".to_string(), "└──┘".to_string()];
+        assert_eq!(
+            OutputProfile::Markdown.apply(&chunks),
+            vec!["```text", "┌──┐", "│hi│", "└──┘", "```"]
+        );
+    }
+
+    #[test]
+    fn test_fence_outgrows_backticks_in_content() {
+        let fenced = fence(vec!["use ```rust blocks".to_string()]);
+        assert_eq!(fenced[0], "````text");
+        assert_eq!(fenced[2], "````");
+    }
+
+    #[test]
+    fn test_profile_names() {
+        assert_eq!(
+            OutputProfile::from_name("ascii-strict"),
+            Ok(OutputProfile::AsciiStrict)
+        );
+        assert_eq!(OutputProfile::from_name("MD"), Ok(OutputProfile::Markdown));
+        assert!(OutputProfile::from_name("slack").is_err());
+    }
+}
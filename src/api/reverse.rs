@@ -0,0 +1,518 @@
+//! Reverse parsing - recover a BoxyConfig from rendered box text
+//!
+//! Recognizes boxes drawn in any built-in `BoxStyle`, with or without ANSI
+//! colors, and reconstructs header, body, status, footer, style and width.
+//! A title renders as the first body line and comes back as body text.
+//! Section dividers come back as `#DIV#` / `#DIV:Label#` lines and wrapped rows
+//! are rejoined, so re-rendering captured output in a new theme or width
+//! (`boxy --reflow`) wraps it afresh.
+//!
+//! # Example
+//! ```rust
+//! use boxy::api::layout::{BoxBuilder, HeaderBuilder};
+//! use boxy::api::reverse::parse_rendered_box;
+//!
+//! let rendered = BoxBuilder::new("Hello")
+//!     .with_header(HeaderBuilder::new("Greeting"))
+//!     .build()
+//!     .render();
+//!
+//! let parsed = parse_rendered_box(&rendered).unwrap();
+//! assert_eq!(parsed.header.unwrap().text, "Greeting");
+//! assert_eq!(parsed.body, vec!["Hello".to_string()]);
+//! ```
+
+use crate::api::geometry::{get_box_styles, get_text_width};
+use crate::core::helpers::wrap_single_line;
+use crate::core::{BodyAlignment, BoxyConfig, WidthConfig};
+use crate::visual::BoxStyle;
+
+/// Label embedded in a top or bottom border line
+#[derive(Debug, Clone, PartialEq)]
+pub struct BorderLabel {
+    pub text: String,
+    /// left, center or right
+    pub align: String,
+    /// plain, spaced or bracket
+    pub decoration: String,
+    /// Border glyphs between the corner and a left/right placed label
+    pub inset: usize,
+}
+
+/// Components recovered from a rendered box
+#[derive(Debug, Clone)]
+pub struct ParsedBox {
+    pub style_name: &'static str,
+    pub style: BoxStyle,
+    /// Total width in terminal columns, borders included
+    pub width: usize,
+    pub header: Option<BorderLabel>,
+    pub footer: Option<BorderLabel>,
+    pub body: Vec<String>,
+    pub body_align: BodyAlignment,
+    pub h_padding: usize,
+    /// Status text in `sl:`/`sc:`/`sr:` syntax
+    pub status: Option<String>,
+}
+
+impl ParsedBox {
+    /// Build a config that renders this box again
+    pub fn to_config(&self) -> BoxyConfig {
+        let mut config = BoxyConfig {
+            text: self.body.join("\n"),
+            header: self.header.as_ref().map(|l| l.text.clone()),
+            footer: self.footer.as_ref().map(|l| l.text.clone()),
+            status_bar: self.status.clone(),
+            body_align: self.body_align.clone(),
+            style: self.style,
+            width: WidthConfig {
+                fixed_width: Some(self.width),
+                h_padding: self.h_padding,
+                ..WidthConfig::default()
+            },
+            ..BoxyConfig::default()
+        };
+
+        if let Some(header) = &self.header {
+            config.alignment.header_align = header.align.clone();
+        }
+        if let Some(footer) = &self.footer {
+            config.alignment.footer_align = footer.align.clone();
+        }
+        if let Some(label) = self.header.as_ref().or(self.footer.as_ref()) {
+            config.border_labels.decoration = label.decoration.clone();
+            config.border_labels.inset = label.inset;
+        }
+        config
+    }
+}
+
+/// Parse rendered box text into its components
+pub fn parse_rendered_box(text: &str) -> Result<ParsedBox, String> {
+    let lines: Vec<String> = text
+        .lines()
+        .map(|l| crate::strip_ansi_codes(l).trim_end().to_string())
+        .skip_while(|l| l.is_empty())
+        .collect();
+    let end = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .ok_or("No box found in input")?;
+    let lines = &lines[..=end];
+    if lines.len() < 2 {
+        return Err("A box needs at least a top and a bottom border".to_string());
+    }
+
+    let top = lines[0].trim_start();
+    let bottom = lines[lines.len() - 1].trim_start();
+    let (style_name, style) = detect_style(top, bottom)
+        .ok_or("Input does not start and end with a recognized box border")?;
+    let width = get_text_width(top);
+
+    let header = parse_border_label(
+        border_interior(top, style.top_left, style.top_right),
+        &style,
+    );
+    let footer = parse_border_label(
+        border_interior(bottom, style.bottom_left, style.bottom_right),
+        &style,
+    );
+
+    // Split the rows between the borders into sections at divider lines;
+    // `rules[i]` is the divider opening `sections[i + 1]` (its label, if any)
+    let mut sections: Vec<Vec<&str>> = vec![Vec::new()];
+    let mut rules: Vec<Option<String>> = Vec::new();
+    for (index, line) in lines[1..lines.len() - 1].iter().enumerate() {
+        let line = line.trim_start();
+        if let Some(label) = divider_label(line, &style) {
+            sections.push(Vec::new());
+            rules.push(label);
+        } else if let Some(interior) = content_interior(line, &style) {
+            sections.last_mut().unwrap().push(interior);
+        } else {
+            return Err(format!(
+                "Line {} is not part of a {} box",
+                index + 2,
+                style_name
+            ));
+        }
+    }
+
+    // The status line sits behind a plain divider, padded above and below;
+    // any other section belongs to the body
+    let mut status = None;
+    if rules.last().is_some_and(Option::is_none) && is_status_section(sections.last().unwrap()) {
+        status = Some(parse_status(sections.last().unwrap()));
+        sections.pop();
+        rules.pop();
+    }
+
+    let inner_width = width.saturating_sub(2);
+    let (body, body_align, h_padding) = parse_body(&sections, &rules, inner_width);
+
+    Ok(ParsedBox {
+        style_name,
+        style,
+        width,
+        header,
+        footer,
+        body,
+        body_align,
+        h_padding,
+        status,
+    })
+}
+
+/// Parse rendered box text straight into a config
+pub fn config_from_rendered(text: &str) -> Result<BoxyConfig, String> {
+    parse_rendered_box(text).map(|parsed| parsed.to_config())
+}
+
+fn detect_style(top: &str, bottom: &str) -> Option<(&'static str, BoxStyle)> {
+    get_box_styles().into_iter().find(|(_, style)| {
+        top.starts_with(style.top_left)
+            && top.ends_with(style.top_right)
+            && bottom.starts_with(style.bottom_left)
+            && bottom.ends_with(style.bottom_right)
+            && border_interior(top, style.top_left, style.top_right).contains(style.horizontal)
+    })
+}
+
+fn border_interior<'a>(line: &'a str, left: &str, right: &str) -> &'a str {
+    let inner = line.strip_prefix(left).unwrap_or(line);
+    inner.strip_suffix(right).unwrap_or(inner)
+}
+
+/// Label of a divider row: `Some(None)` for a plain rule, `None` if not a divider
+fn divider_label(line: &str, style: &BoxStyle) -> Option<Option<String>> {
+    let interior = line
+        .strip_prefix(style.tee_left)?
+        .strip_suffix(style.tee_right)?;
+    if !interior.contains(style.horizontal) {
+        return None;
+    }
+    if interior.chars().all(|c| style.horizontal.contains(c)) {
+        return Some(None);
+    }
+    parse_border_label(interior, style).map(|label| Some(label.text))
+}
+
+fn content_interior<'a>(line: &'a str, style: &BoxStyle) -> Option<&'a str> {
+    let inner = line.strip_prefix(style.vertical)?;
+    inner.strip_suffix(style.vertical)
+}
+
+fn non_blank<'a>(rows: &[&'a str]) -> Vec<&'a str> {
+    rows.iter()
+        .copied()
+        .filter(|r| !r.trim().is_empty())
+        .collect()
+}
+
+fn leading_spaces(text: &str) -> usize {
+    text.len() - text.trim_start_matches(' ').len()
+}
+
+fn trailing_spaces(text: &str) -> usize {
+    text.len() - text.trim_end_matches(' ').len()
+}
+
+fn parse_border_label(interior: &str, style: &BoxStyle) -> Option<BorderLabel> {
+    let horizontal = style.horizontal;
+    let core = interior.trim_start_matches(horizontal);
+    let left_run = (interior.len() - core.len()) / horizontal.len();
+    let trimmed = core.trim_end_matches(horizontal);
+    let right_run = (core.len() - trimmed.len()) / horizontal.len();
+
+    if trimmed.trim().is_empty() {
+        return None;
+    }
+
+    let (decoration, text) = if let Some(inner) = trimmed
+        .strip_prefix(style.tee_right)
+        .and_then(|t| t.strip_suffix(style.tee_left))
+        .filter(|_| style.tee_right != horizontal)
+    {
+        ("bracket", inner)
+    } else if trimmed.starts_with(' ') && trimmed.ends_with(' ') {
+        ("spaced", trimmed)
+    } else {
+        ("plain", trimmed)
+    };
+
+    let align = if left_run.abs_diff(right_run) <= 1 {
+        "center"
+    } else if left_run < right_run {
+        "left"
+    } else {
+        "right"
+    };
+    let inset = if align == "center" {
+        0
+    } else {
+        left_run.min(right_run)
+    };
+
+    Some(BorderLabel {
+        text: text.trim().to_string(),
+        align: align.to_string(),
+        decoration: decoration.to_string(),
+        inset,
+    })
+}
+
+/// Recover body lines, their alignment and the horizontal padding
+///
+/// Dividers between `sections` come back as `#DIV#` / `#DIV:Label#` lines, and
+/// rows the renderer wrapped are joined so a new width can wrap them again.
+fn parse_body(
+    sections: &[Vec<&str>],
+    rules: &[Option<String>],
+    inner_width: usize,
+) -> (Vec<String>, BodyAlignment, usize) {
+    let filled: Vec<&str> = sections.iter().flat_map(|rows| non_blank(rows)).collect();
+    if filled.is_empty() && rules.is_empty() {
+        return (Vec::new(), BodyAlignment::Left, 1);
+    }
+
+    let gaps: Vec<(usize, usize)> = filled
+        .iter()
+        .map(|r| (leading_spaces(r), trailing_spaces(r)))
+        .collect();
+    let measured = gaps.iter().map(|&(l, t)| l.min(t)).min().unwrap_or(1);
+    let indented = gaps.iter().any(|&(l, _)| l > measured);
+
+    let align = if indented && gaps.iter().all(|&(_, t)| t == measured) {
+        BodyAlignment::Right
+    } else if indented && gaps.iter().all(|&(l, t)| l.abs_diff(t) <= 1) {
+        BodyAlignment::Center
+    } else {
+        BodyAlignment::Left
+    };
+    // Centered rows hide the real padding; assume no more than the default
+    let padding = match align {
+        BodyAlignment::Center => measured.min(WidthConfig::default().h_padding),
+        _ => measured,
+    };
+    let text_width = inner_width.saturating_sub(padding * 2);
+
+    let mut body: Vec<String> = Vec::new();
+    for (i, rows) in sections.iter().enumerate() {
+        if let Some(rule) = i.checked_sub(1).map(|r| &rules[r]) {
+            body.push(match rule {
+                Some(label) => format!("#DIV:{}#", label),
+                None => "#DIV#".to_string(),
+            });
+        }
+        let mut wrapped = false;
+        for (r, row) in rows.iter().enumerate() {
+            let line = match align {
+                BodyAlignment::Left => row.get(padding..).unwrap_or("").trim_end(),
+                _ => row.trim(),
+            };
+            match body.last_mut() {
+                Some(previous) if wrapped && !line.is_empty() => {
+                    previous.push(' ');
+                    previous.push_str(line.trim_start());
+                }
+                _ => body.push(line.to_string()),
+            }
+            wrapped = was_wrapped(line, rows.get(r + 1), text_width);
+        }
+    }
+
+    let first = body
+        .iter()
+        .position(|l| !l.is_empty())
+        .unwrap_or(body.len());
+    let last = body
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(first, |l| l + 1);
+    (body[first..last].to_vec(), align, padding)
+}
+
+/// Whether the renderer broke the row after `line`: wrapping it together with
+/// the next row at `text_width` splits them at the same place
+fn was_wrapped(line: &str, next: Option<&&str>, text_width: usize) -> bool {
+    let Some(next) = next.map(|r| r.trim()).filter(|r| !r.is_empty()) else {
+        return false;
+    };
+    !line.is_empty()
+        && wrap_single_line(&format!("{} {}", line.trim(), next), text_width)
+            .first()
+            .is_some_and(|first| first == line.trim())
+}
+
+/// Rows the status bar renders: one line with the same number of blank
+/// padding rows (at least one) above and below
+fn is_status_section(rows: &[&str]) -> bool {
+    let padding = rows.iter().take_while(|r| r.trim().is_empty()).count();
+    padding >= 1
+        && rows.len() == 2 * padding + 1
+        && rows[padding + 1..].iter().all(|r| r.trim().is_empty())
+}
+
+/// Rebuild status text, splitting zones when text sits on both edges
+fn parse_status(rows: &[&str]) -> String {
+    let row = non_blank(rows)[0];
+    let lead = leading_spaces(row);
+    let trail = trailing_spaces(row);
+    let padding = lead.min(trail);
+    let text = row.trim();
+
+    let groups: Vec<&str> = text
+        .split("  ")
+        .map(str::trim)
+        .filter(|g| !g.is_empty())
+        .collect();
+
+    if lead == padding && trail == padding && groups.len() > 1 {
+        let right = groups[groups.len() - 1];
+        let middle = groups[1..groups.len() - 1].join("  ");
+        let mut zones = vec![format!("sl:{}", groups[0])];
+        if !middle.is_empty() {
+            zones.push(format!("sc:{}", middle));
+        }
+        zones.push(format!("sr:{}", right));
+        return zones.join("|");
+    }
+
+    let prefix = if lead.abs_diff(trail) <= 1 && padding > 1 {
+        "sc"
+    } else if lead > trail {
+        "sr"
+    } else {
+        "sl"
+    };
+    format!("{}:{}", prefix, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::layout::{BoxBuilder, BoxLayout, FooterBuilder, HeaderBuilder, StatusBuilder};
+    use crate::api::theming::ColorScheme;
+
+    fn sample_config() -> BoxyConfig {
+        let mut config = BoxyConfig {
+            text: "First line\nSecond line".to_string(),
+            header: Some("Build".to_string()),
+            footer: Some("v1.0".to_string()),
+            status_bar: Some("sr:OK".to_string()),
+            ..BoxyConfig::default()
+        };
+        config.width.fixed_width = Some(30);
+        config.alignment.footer_align = "right".to_string();
+        config
+    }
+
+    #[test]
+    fn test_parse_every_builtin_style() {
+        for (name, style) in get_box_styles() {
+            let mut config = sample_config();
+            config.style = style;
+            let rendered = BoxLayout::from(&config).render();
+
+            let parsed = parse_rendered_box(&rendered).unwrap();
+            assert_eq!(parsed.style_name, name);
+            assert_eq!(parsed.width, 30, "{}", name);
+            assert_eq!(parsed.header.as_ref().unwrap().text, "Build", "{}", name);
+            assert_eq!(parsed.footer.as_ref().unwrap().align, "right", "{}", name);
+            assert_eq!(parsed.body, vec!["First line", "Second line"], "{}", name);
+            assert_eq!(parsed.status.as_deref(), Some("sr:OK"), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_parse_colored_box() {
+        let mut config = sample_config();
+        config.colors.box_color = "red".to_string();
+        config.colors.text_color = "blue".to_string();
+        let rendered =
+            BoxLayout::from(&config).render_with_colors(&ColorScheme::from_config(&config));
+        assert!(rendered.contains('\x1b'));
+
+        let parsed = parse_rendered_box(&rendered).unwrap();
+        assert_eq!(parsed.body, vec!["First line", "Second line"]);
+        assert_eq!(parsed.header.unwrap().text, "Build");
+    }
+
+    #[test]
+    fn test_reflow_round_trip() {
+        let config = sample_config();
+        let rendered = BoxLayout::from(&config).render();
+
+        let reparsed = config_from_rendered(&rendered).unwrap();
+        assert_eq!(BoxLayout::from(&reparsed).render(), rendered);
+    }
+
+    #[test]
+    fn test_parse_status_zones_and_body_alignment() {
+        let rendered = BoxBuilder::new("centered\nlines here")
+            .with_body_alignment(BodyAlignment::Center)
+            .with_status(StatusBuilder::parse("sl:main|sr:12:04"))
+            .with_footer(FooterBuilder::new("f"))
+            .with_header(HeaderBuilder::new("h").align_left())
+            .with_fixed_width(30)
+            .build()
+            .render();
+
+        let parsed = parse_rendered_box(&rendered).unwrap();
+        assert_eq!(parsed.status.as_deref(), Some("sl:main|sr:12:04"));
+        assert_eq!(parsed.body_align, BodyAlignment::Center);
+        assert_eq!(parsed.body, vec!["centered", "lines here"]);
+        assert_eq!(parsed.header.unwrap().align, "left");
+    }
+
+    #[test]
+    fn test_parse_section_dividers() {
+        let rendered = BoxBuilder::new("a\n#DIV:Errors#\nb\n#DIV#\nc\nd")
+            .with_fixed_width(30)
+            .build()
+            .render();
+
+        let parsed = parse_rendered_box(&rendered).unwrap();
+        assert_eq!(parsed.status, None);
+        assert_eq!(
+            parsed.body,
+            vec!["a", "#DIV:Errors#", "b", "#DIV#", "c", "d"]
+        );
+        assert_eq!(BoxLayout::from(&parsed.to_config()).render(), rendered);
+    }
+
+    #[test]
+    fn test_plain_divider_sections_stay_in_body() {
+        for text in ["a\n#DIV#\nb", "a\n#DIV#\nb\n#DIV#\nc"] {
+            let config = BoxyConfig {
+                text: text.to_string(),
+                ..BoxyConfig::default()
+            };
+            let rendered = BoxLayout::from(&config).render();
+
+            let reparsed = config_from_rendered(&rendered).unwrap();
+            assert_eq!(reparsed.status_bar, None, "{}", text);
+            assert_eq!(reparsed.text, text);
+            assert_eq!(BoxLayout::from(&reparsed).render(), rendered);
+        }
+    }
+
+    #[test]
+    fn test_parse_joins_wrapped_rows() {
+        let text = "one two three four five six seven eight nine ten";
+        let rendered = BoxBuilder::new(&format!("{}\nshort\nlines", text))
+            .with_fixed_width(20)
+            .build()
+            .render();
+        assert!(rendered.lines().count() > 5);
+
+        let parsed = parse_rendered_box(&rendered).unwrap();
+        assert_eq!(parsed.body, vec![text, "short", "lines"]);
+    }
+
+    #[test]
+    fn test_rejects_non_box_input() {
+        assert!(parse_rendered_box("just some text\nmore text").is_err());
+        assert!(parse_rendered_box("").is_err());
+    }
+}
@@ -13,12 +13,15 @@
 
 use crate::api::geometry::BoxDimensions;
 use crate::api::layout::BoxLayout;
+use crate::core::BorderSides;
 
 /// Position information for a component in the layout
 #[derive(Debug, Clone)]
 pub struct ComponentPosition {
-    /// Starting line (0-indexed)
+    /// Starting line (0-indexed, after any top margin)
     pub start_line: usize,
+    /// Starting column (the left margin)
+    pub start_col: usize,
     /// Ending line (exclusive)
     pub end_line: usize,
     /// Component width in columns
@@ -27,6 +30,17 @@ pub struct ComponentPosition {
     pub height: usize,
     /// Component type identifier
     pub component_type: ComponentType,
+    /// Blank fill rows above the content (body vertical alignment)
+    pub fill_top: usize,
+    /// Blank fill rows below the content (body vertical alignment)
+    pub fill_bottom: usize,
+}
+
+impl ComponentPosition {
+    /// Line range holding actual content, excluding vertical-alignment fill
+    pub fn content_lines(&self) -> std::ops::Range<usize> {
+        (self.start_line + self.fill_top)..(self.end_line - self.fill_bottom)
+    }
 }
 
 /// Type of component in the layout
@@ -56,7 +70,9 @@ impl RoomRuntimeAdapter {
     /// Create a new adapter from a BoxLayout
     pub fn new(layout: BoxLayout) -> Self {
         let positions = Self::calculate_positions(&layout);
-        let total_height = positions.iter().map(|p| p.height).sum();
+        let total_height = positions.iter().map(|p| p.height).sum::<usize>()
+            + layout.shadow_extent().1
+            + layout.margin.height();
 
         Self {
             layout,
@@ -78,9 +94,14 @@ impl RoomRuntimeAdapter {
             .map(|pos| (pos, pos.component_type))
     }
 
-    /// Get the rendered lines
+    /// Get the rendered lines, one entry per terminal row
     pub fn lines(&self) -> Vec<String> {
-        self.layout.render_lines()
+        self.layout
+            .render_lines()
+            .iter()
+            .flat_map(|chunk| chunk.lines())
+            .map(String::from)
+            .collect()
     }
 
     /// Get lines for a specific component
@@ -94,30 +115,36 @@ impl RoomRuntimeAdapter {
         Some(all_lines[position.start_line..position.end_line].to_vec())
     }
 
-    /// Get total height
+    /// Get total height (including shadow and margin rows)
     pub fn total_height(&self) -> usize {
         self.total_height
     }
 
-    /// Get total width
+    /// Get total width (including shadow and margin columns, without hidden border sides)
     pub fn total_width(&self) -> usize {
-        self.layout.total_width
+        self.layout.outer_size().0
     }
 
     /// Calculate component positions from layout
     fn calculate_positions(layout: &BoxLayout) -> Vec<ComponentPosition> {
         let mut positions = Vec::new();
-        let mut current_line = 0;
+        let mut current_line = layout.margin.top;
+        // A top/bottom edge turned off without a label renders no row
+        let (header_rows, footer_rows) = layout.edge_rows();
+        let start_col = layout.margin.left;
 
         // Header
-        if let Some(header) = &layout.header {
+        if let Some(header) = layout.header.as_ref().filter(|_| header_rows > 0) {
             let height = header.content.lines().count();
             positions.push(ComponentPosition {
                 start_line: current_line,
+                start_col,
                 end_line: current_line + height,
                 width: header.width,
                 height,
                 component_type: ComponentType::Header,
+                fill_top: 0,
+                fill_bottom: 0,
             });
             current_line += height;
         }
@@ -126,10 +153,13 @@ impl RoomRuntimeAdapter {
         let body_height = layout.body.content.lines().count();
         positions.push(ComponentPosition {
             start_line: current_line,
+            start_col,
             end_line: current_line + body_height,
             width: layout.body.width,
             height: body_height,
             component_type: ComponentType::Body,
+            fill_top: layout.body_fill.0,
+            fill_bottom: layout.body_fill.1,
         });
         current_line += body_height;
 
@@ -138,23 +168,29 @@ impl RoomRuntimeAdapter {
             let height = status.content.lines().count();
             positions.push(ComponentPosition {
                 start_line: current_line,
+                start_col,
                 end_line: current_line + height,
                 width: status.width,
                 height,
                 component_type: ComponentType::Status,
+                fill_top: 0,
+                fill_bottom: 0,
             });
             current_line += height;
         }
 
         // Footer
-        if let Some(footer) = &layout.footer {
+        if let Some(footer) = layout.footer.as_ref().filter(|_| footer_rows > 0) {
             let height = footer.content.lines().count();
             positions.push(ComponentPosition {
                 start_line: current_line,
+                start_col,
                 end_line: current_line + height,
                 width: footer.width,
                 height,
                 component_type: ComponentType::Footer,
+                fill_top: 0,
+                fill_bottom: 0,
             });
         }
 
@@ -177,6 +213,8 @@ pub struct LayoutMetadata {
     pub has_footer: bool,
     /// Has status component
     pub has_status: bool,
+    /// Frame edges drawn around the content
+    pub borders: BorderSides,
 }
 
 impl LayoutMetadata {
@@ -190,14 +228,17 @@ impl LayoutMetadata {
             has_header: layout.header.is_some(),
             has_footer: layout.footer.is_some(),
             has_status: layout.status.is_some(),
+            borders: layout.borders,
         }
     }
 
-    /// Get content area bounds (excluding borders)
+    /// Get content area bounds (excluding borders and margin)
     pub fn content_bounds(&self) -> (usize, usize, usize, usize) {
+        let left = self.borders.left as usize;
+        let top = self.borders.top as usize;
         (
-            1, // start_col (after left border)
-            1, // start_row (after top border)
+            self.dimensions.margin.left + left, // start_col (after left border)
+            self.dimensions.margin.top + top,   // start_row (after top border)
             self.dimensions.inner_width,
             self.dimensions.inner_height,
         )
@@ -207,7 +248,9 @@ impl LayoutMetadata {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::api::layout::{BoxBuilder, FooterBuilder, HeaderBuilder};
+    use crate::api::geometry::get_text_width;
+    use crate::api::layout::{BoxBuilder, FooterBuilder, HeaderBuilder, Shadow, VerticalAlign};
+    use crate::core::{BorderSides, Spacing};
 
     #[test]
     fn test_adapter_positions() {
@@ -241,4 +284,107 @@ mod tests {
         let (pos, comp_type) = adapter.component_at_line(1).unwrap();
         assert_eq!(comp_type, ComponentType::Body);
     }
+
+    #[test]
+    fn test_body_fill_reflects_vertical_align() {
+        let layout = BoxBuilder::new("Centered")
+            .with_fixed_height(9)
+            .with_vertical_align(VerticalAlign::Middle)
+            .build();
+
+        let adapter = RoomRuntimeAdapter::new(layout);
+        let body = adapter
+            .positions()
+            .iter()
+            .find(|p| p.component_type == ComponentType::Body)
+            .unwrap();
+
+        // 9 rows - 2 borders = 7 body rows: 1 content line, 6 fill rows split 3/3
+        assert_eq!(body.height, 7);
+        assert_eq!((body.fill_top, body.fill_bottom), (3, 3));
+        assert_eq!(body.content_lines(), 4..5);
+        let rendered = adapter.lines().join("\n");
+        assert!(rendered.lines().nth(4).unwrap().contains("Centered"));
+    }
+
+    #[test]
+    fn test_shadow_included_in_totals() {
+        let plain = RoomRuntimeAdapter::new(BoxBuilder::new("Shadowed").build());
+        let shadowed = RoomRuntimeAdapter::new(
+            BoxBuilder::new("Shadowed")
+                .with_shadow(Shadow::new())
+                .build(),
+        );
+
+        assert_eq!(shadowed.total_width(), plain.total_width() + 1);
+        assert_eq!(shadowed.total_height(), plain.total_height() + 1);
+
+        let lines = shadowed.lines();
+        assert_eq!(lines.len(), shadowed.total_height());
+        assert!(
+            lines
+                .iter()
+                .all(|l| get_text_width(l) == shadowed.total_width())
+        );
+    }
+
+    #[test]
+    fn test_margin_offsets_positions() {
+        let layout = BoxBuilder::new("Body")
+            .with_header(HeaderBuilder::new("Header"))
+            .with_margin(Spacing::new(2, 1, 1, 3))
+            .build();
+        let dimensions = layout.dimensions();
+        let adapter = RoomRuntimeAdapter::new(layout.clone());
+
+        let header = &adapter.positions()[0];
+        assert_eq!((header.start_line, header.start_col), (2, 3));
+        let (_, comp_type) = adapter.component_at_line(3).unwrap();
+        assert_eq!(comp_type, ComponentType::Body);
+        assert!(adapter.component_at_line(0).is_none());
+
+        let lines = adapter.lines();
+        assert_eq!(lines.len(), adapter.total_height());
+        assert!(
+            lines
+                .iter()
+                .all(|l| get_text_width(l) == adapter.total_width())
+        );
+        assert!(lines[2].starts_with("   ┌"));
+        assert_eq!(
+            adapter.component_lines(ComponentType::Header).unwrap()[0],
+            lines[2]
+        );
+
+        assert_eq!(dimensions.outer_width, adapter.total_width());
+        assert_eq!(dimensions.outer_height, adapter.total_height());
+        let metadata = LayoutMetadata::from_layout(&layout, dimensions);
+        assert_eq!(metadata.content_bounds().0, 4);
+        assert_eq!(metadata.content_bounds().1, 3);
+    }
+
+    #[test]
+    fn test_border_sides_shift_positions() {
+        let layout = BoxBuilder::new("Quoted")
+            .with_footer(FooterBuilder::new("src"))
+            .with_borders(BorderSides::parse("l").unwrap())
+            .build();
+        let adapter = RoomRuntimeAdapter::new(layout.clone());
+
+        // Unlabeled top edge is dropped, labeled bottom edge keeps a row
+        let positions = adapter.positions();
+        assert_eq!(positions[0].component_type, ComponentType::Body);
+        assert_eq!(positions[0].start_line, 0);
+        assert_eq!(positions[1].component_type, ComponentType::Footer);
+
+        let lines = adapter.lines();
+        assert_eq!(lines.len(), adapter.total_height());
+        assert_eq!(adapter.total_width(), layout.total_width - 1);
+        assert!(lines.iter().all(|l| l.starts_with('│')));
+        assert!(lines.last().unwrap().contains("src"));
+
+        let metadata = LayoutMetadata::from_layout(&layout, layout.dimensions());
+        let (start_col, start_row, _, _) = metadata.content_bounds();
+        assert_eq!((start_col, start_row), (1, 0));
+    }
 }
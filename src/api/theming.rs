@@ -16,7 +16,8 @@
 //! - No coupling to geometry/layout modules
 //! - Graceful degradation when colors unavailable
 
-use crate::{RESET, get_color_code};
+use crate::core::EdgeColors;
+use crate::{RESET, get_color_code, read_escape_sequence};
 
 /// Background color specification
 #[derive(Debug, Clone, PartialEq)]
@@ -48,6 +49,27 @@ pub struct ColorScheme {
     pub footer_color: Option<String>,
     /// Status text color
     pub status_color: Option<String>,
+    /// Per-side and divider border colors, overriding `border_color`
+    pub edges: EdgeColors,
+}
+
+/// Where a border glyph sits in the frame, for per-edge coloring
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BorderPart {
+    Top,
+    Right,
+    Bottom,
+    Left,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    /// A rule across the inside of the box
+    Divider,
+    /// Any other glyph: nested boxes, body rules outside a layout
+    Inner,
+    /// Drop shadow outside the frame: keeps its own color, never the text color
+    Shadow,
 }
 
 impl Default for ColorScheme {
@@ -59,6 +81,7 @@ impl Default for ColorScheme {
             header_color: None,
             footer_color: None,
             status_color: None,
+            edges: EdgeColors::default(),
         }
     }
 }
@@ -73,6 +96,7 @@ impl ColorScheme {
             header_color: None,
             footer_color: None,
             status_color: None,
+            edges: EdgeColors::default(),
         }
     }
 
@@ -91,8 +115,29 @@ impl ColorScheme {
             header_color: config.colors.title_color.clone(),
             footer_color: None, // BoxyConfig doesn't have footer_color
             status_color: None, // BoxyConfig doesn't have status_color
+            edges: config.colors.edges.clone(),
         }
     }
+
+    /// Color name for a border glyph at `part`, falling back to `border_color`
+    ///
+    /// Corners follow their left/right edge first, then their top/bottom edge.
+    pub fn border_color_for(&self, part: BorderPart) -> &str {
+        let edges = &self.edges;
+        let color = match part {
+            BorderPart::Top => edges.top.as_ref(),
+            BorderPart::Right => edges.right.as_ref(),
+            BorderPart::Bottom => edges.bottom.as_ref(),
+            BorderPart::Left => edges.left.as_ref(),
+            BorderPart::TopLeft => edges.left.as_ref().or(edges.top.as_ref()),
+            BorderPart::TopRight => edges.right.as_ref().or(edges.top.as_ref()),
+            BorderPart::BottomLeft => edges.left.as_ref().or(edges.bottom.as_ref()),
+            BorderPart::BottomRight => edges.right.as_ref().or(edges.bottom.as_ref()),
+            BorderPart::Divider => edges.divider.as_ref(),
+            BorderPart::Inner | BorderPart::Shadow => None,
+        };
+        color.unwrap_or(&self.border_color)
+    }
 }
 
 /// Apply background color to text content (line-by-line to prevent bleeding)
@@ -197,7 +242,20 @@ pub fn apply_colors(content: &str, scheme: &ColorScheme) -> String {
 
 /// Apply colors to rendered box output (borders and content)
 pub fn apply_colors_to_rendered_box(rendered: &str, scheme: &ColorScheme) -> String {
-    use crate::{RESET, get_color_code, visual::BOX_CHARS};
+    apply_colors_by_part(rendered, scheme, |_, _| BorderPart::Inner)
+}
+
+/// Apply colors to rendered box output, coloring each border glyph by its frame part
+///
+/// `part_at(row, column)` places the glyph at a display column of a rendered
+/// row; `ColorScheme::border_color_for` picks its color. Used by
+/// `BoxLayout::render_with_colors`, which knows where the edges are.
+pub fn apply_colors_by_part(
+    rendered: &str,
+    scheme: &ColorScheme,
+    part_at: impl Fn(usize, usize) -> BorderPart,
+) -> String {
+    use crate::{RESET, get_color_code, get_display_width, visual::BOX_CHARS};
 
     let lines: Vec<&str> = rendered.lines().collect();
     if lines.is_empty() {
@@ -215,58 +273,105 @@ pub fn apply_colors_to_rendered_box(rendered: &str, scheme: &ColorScheme) -> Str
 
     let mut result = Vec::new();
 
-    for line in lines {
+    for (row, line) in lines.into_iter().enumerate() {
         if line.trim().is_empty() {
             result.push(line.to_string());
             continue;
         }
 
-        let chars: Vec<char> = line.chars().collect();
+        let mut chars = line.chars().peekable();
         let mut colored_line = String::new();
-        let mut in_border = false;
+        // Color code of the open border span, if any
+        let mut in_border: Option<&str> = None;
         let mut in_text_color = false;
+        // Inside a span the content colored itself (e.g. a status segment color)
+        let mut in_embedded_color = false;
+        let mut column = 0;
+
+        while let Some(ch) = chars.next() {
+            // Pass embedded escape sequences through untouched
+            if ch == '\x1b' {
+                let sequence = read_escape_sequence(&mut chars);
+                // OSC (hyperlinks) carries no color, so the current state is kept
+                if sequence.starts_with("\x1b]") {
+                    colored_line.push_str(&sequence);
+                    continue;
+                }
+                if in_border.is_some() || in_text_color {
+                    colored_line.push_str(RESET);
+                    in_border = None;
+                    in_text_color = false;
+                }
+                in_embedded_color = sequence != RESET && sequence != "\x1b[m";
+                colored_line.push_str(&sequence);
+                continue;
+            }
+            // A variation selector can widen the glyph before it (`ℹ️`)
+            let glyph_column = column;
+            let mut cell = ch.to_string();
+            if chars.peek() == Some(&'\u{FE0F}') {
+                cell.push('\u{FE0F}');
+            }
+            column += get_display_width(&cell);
+            let part = part_at(row, glyph_column);
+
+            if part == BorderPart::Shadow {
+                if in_border.take().is_some() || in_text_color {
+                    colored_line.push_str(RESET);
+                    in_text_color = false;
+                }
+                colored_line.push(ch);
+                continue;
+            }
 
-        for ch in chars.iter() {
             // Check if this character is a box drawing character
-            if BOX_CHARS.contains(*ch) {
+            if BOX_CHARS.contains(ch) {
                 // Close text color if we were in one
                 if in_text_color {
                     colored_line.push_str(RESET);
                     in_text_color = false;
                 }
-                // Start border color if not already
-                if !in_border {
-                    colored_line.push_str(border_color_code);
-                    in_border = true;
+                // Glyphs inside an embedded span keep their own color (e.g. a nested box)
+                if in_embedded_color {
+                    colored_line.push(ch);
+                    continue;
+                }
+                // Start border color if not already, switching at edge boundaries
+                let code = get_color_code(scheme.border_color_for(part));
+                if in_border != Some(code) {
+                    if in_border.is_some() {
+                        colored_line.push_str(RESET);
+                    }
+                    colored_line.push_str(code);
+                    in_border = Some(code);
                 }
-                colored_line.push(*ch);
+                colored_line.push(ch);
             } else {
                 // Close border color if we were in one
-                if in_border {
+                if in_border.take().is_some() {
                     colored_line.push_str(RESET);
-                    in_border = false;
                 }
 
                 // Apply text color to non-space content
-                if !text_color_code.is_empty() && *ch != ' ' {
+                if !text_color_code.is_empty() && ch != ' ' && !in_embedded_color {
                     if !in_text_color {
                         colored_line.push_str(text_color_code);
                         in_text_color = true;
                     }
-                    colored_line.push(*ch);
+                    colored_line.push(ch);
                 } else {
                     // Close text color for spaces
                     if in_text_color {
                         colored_line.push_str(RESET);
                         in_text_color = false;
                     }
-                    colored_line.push(*ch);
+                    colored_line.push(ch);
                 }
             }
         }
 
         // Close any open colors
-        if in_border || in_text_color {
+        if in_border.is_some() || in_text_color {
             colored_line.push_str(RESET);
         }
 
@@ -531,4 +636,74 @@ mod tests {
         let result = renderer("Hello", &scheme);
         assert_eq!(result, "Hello"); // Colors ignored
     }
+
+    #[test]
+    fn test_embedded_colors_pass_through() {
+        use crate::{RESET, get_color_code};
+
+        let red = get_color_code("red");
+        let rendered = format!("│ {}fail{} ok │{}░{}", red, RESET, red, RESET);
+        let scheme = ColorScheme {
+            text_color: "blue".to_string(),
+            ..ColorScheme::default()
+        };
+
+        let colored = apply_colors_to_rendered_box(&rendered, &scheme);
+        assert!(colored.contains(&format!("{}fail{}", red, RESET)));
+        // Embedded colors right after a border are not cancelled by the border reset
+        assert!(colored.contains(&format!("│{}{}░", RESET, red)));
+        assert!(colored.contains(&format!("{}ok", get_color_code("blue"))));
+    }
+
+    #[test]
+    fn test_nested_box_keeps_its_border_color() {
+        use crate::{RESET, get_color_code};
+
+        let red = get_color_code("red");
+        let rendered = format!("│ {}│x│{} │", red, RESET);
+        let scheme = ColorScheme {
+            border_color: "blue".to_string(),
+            ..ColorScheme::default()
+        };
+
+        let colored = apply_colors_to_rendered_box(&rendered, &scheme);
+        assert!(colored.contains(&format!("{}│x│{}", red, RESET)));
+        assert!(colored.starts_with(get_color_code("blue")));
+    }
+
+    #[test]
+    fn test_border_color_for_edges_and_corners() {
+        let scheme = ColorScheme {
+            border_color: "grey".to_string(),
+            edges: EdgeColors {
+                left: Some("red".to_string()),
+                top: Some("blue".to_string()),
+                ..EdgeColors::default()
+            },
+            ..ColorScheme::default()
+        };
+
+        assert_eq!(scheme.border_color_for(BorderPart::Left), "red");
+        assert_eq!(scheme.border_color_for(BorderPart::TopLeft), "red");
+        assert_eq!(scheme.border_color_for(BorderPart::TopRight), "blue");
+        assert_eq!(scheme.border_color_for(BorderPart::BottomRight), "grey");
+        assert_eq!(scheme.border_color_for(BorderPart::Divider), "grey");
+        assert_eq!(scheme.border_color_for(BorderPart::Inner), "grey");
+    }
+
+    #[test]
+    fn test_hyperlink_escapes_pass_through() {
+        let link = crate::hyperlink("https://x.io", "x.io");
+        let rendered = format!("│ {} │", link);
+        let scheme = ColorScheme {
+            border_color: "blue".to_string(),
+            ..ColorScheme::default()
+        };
+
+        let colored = apply_colors_to_rendered_box(&rendered, &scheme);
+        assert!(colored.contains("\x1b]8;;https://x.io\x1b\\"));
+        assert_eq!(crate::strip_ansi_codes(&colored), "│ x.io │");
+        // The closing border is still colored after the link
+        assert!(colored.ends_with(&format!("{}│{}", get_color_code("blue"), crate::RESET)));
+    }
 }
@@ -0,0 +1,679 @@
+//! Tree body mode - hierarchies drawn with `├──`/`└──`/`│` connectors
+//!
+//! Three input formats are understood (auto-detected unless forced):
+//!
+//! - indented text: each level indented further than its parent (tabs count as 4)
+//! - paths: one slash-separated path per line (`find` output); shared prefixes merge
+//! - JSON: object keys and array items become nodes, scalars `key: value` leaves
+//!
+//! Connectors use the box style's glyphs (`┣━━` in a heavy box); styles
+//! without box-drawing glyphs fall back to ASCII `|--` / `` `-- ``.
+//!
+//! # Example
+//! ```rust
+//! use boxy::api::layout::BoxBuilder;
+//! use boxy::api::tree::TreeStyle;
+//!
+//! let layout = BoxBuilder::new("")
+//!     .with_tree("src/main.rs\nsrc/api/tree.rs\nREADME.md", TreeStyle::new())
+//!     .build();
+//! assert!(layout.render().contains("└── tree.rs"));
+//! ```
+
+use crate::visual::{BOX_CHARS, BoxStyle};
+
+/// Input format of a tree body
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TreeFormat {
+    /// JSON when it parses as JSON, paths when every line is an unindented path, else indented
+    #[default]
+    Auto,
+    Indent,
+    Paths,
+    Json,
+}
+
+impl TreeFormat {
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name.trim().to_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "indent" => Ok(Self::Indent),
+            "paths" => Ok(Self::Paths),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "Invalid tree format '{}'. Valid: auto, indent, paths, json",
+                name
+            )),
+        }
+    }
+}
+
+/// Tree body options: input format and the depth beyond which nodes collapse
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TreeStyle {
+    format: TreeFormat,
+    max_depth: Option<usize>,
+}
+
+impl TreeStyle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_format(mut self, format: TreeFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Show nodes down to `depth` (roots are depth 0); deeper ones are counted as `(+N)`
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Flattened rows for `source`; JSON that fails to parse is read as indented text
+    pub fn rows(&self, source: &str) -> Vec<TreeRow> {
+        let roots = parse_tree(source, self.format)
+            .unwrap_or_else(|_| parse_tree(source, TreeFormat::Indent).unwrap_or_default());
+        flatten(&roots, self.max_depth)
+    }
+}
+
+/// A node and its children
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeNode {
+    pub label: String,
+    pub children: Vec<TreeNode>,
+}
+
+impl TreeNode {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Number of nodes below this one
+    pub fn descendants(&self) -> usize {
+        self.children.iter().map(|c| 1 + c.descendants()).sum()
+    }
+
+    fn child_mut(&mut self, label: &str) -> &mut TreeNode {
+        child_named(&mut self.children, label)
+    }
+}
+
+/// Existing child with `label`, or a new one appended
+fn child_named<'a>(nodes: &'a mut Vec<TreeNode>, label: &str) -> &'a mut TreeNode {
+    match nodes.iter().position(|n| n.label == label) {
+        Some(index) => &mut nodes[index],
+        None => {
+            nodes.push(TreeNode::new(label));
+            nodes.last_mut().unwrap()
+        }
+    }
+}
+
+/// Parse `source` into root nodes
+pub fn parse_tree(source: &str, format: TreeFormat) -> Result<Vec<TreeNode>, String> {
+    match format {
+        TreeFormat::Indent => Ok(parse_indented(source)),
+        TreeFormat::Paths => Ok(parse_paths(source)),
+        TreeFormat::Json => parse_json(source),
+        TreeFormat::Auto => {
+            let trimmed = source.trim_start();
+            if (trimmed.starts_with('{') || trimmed.starts_with('['))
+                && let Ok(roots) = parse_json(source)
+            {
+                return Ok(roots);
+            }
+            let unindented = source
+                .lines()
+                .filter(|l| !l.trim().is_empty())
+                .all(|l| !l.starts_with([' ', '\t']));
+            if unindented && source.contains('/') {
+                Ok(parse_paths(source))
+            } else {
+                Ok(parse_indented(source))
+            }
+        }
+    }
+}
+
+fn parse_indented(source: &str) -> Vec<TreeNode> {
+    let mut roots: Vec<TreeNode> = Vec::new();
+    // Indent of each open ancestor, outermost first
+    let mut open: Vec<usize> = Vec::new();
+    for line in source.lines() {
+        let label = line.trim();
+        if label.is_empty() {
+            continue;
+        }
+        let indent: usize = line
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .map(|c| if c == '\t' { 4 } else { 1 })
+            .sum();
+        while open.last().is_some_and(|&parent| parent >= indent) {
+            open.pop();
+        }
+        let mut siblings = &mut roots;
+        for _ in 0..open.len() {
+            siblings = &mut siblings.last_mut().unwrap().children;
+        }
+        siblings.push(TreeNode::new(label));
+        open.push(indent);
+    }
+    roots
+}
+
+fn parse_paths(source: &str) -> Vec<TreeNode> {
+    let mut roots: Vec<TreeNode> = Vec::new();
+    for line in source.lines() {
+        let mut parts = line
+            .trim()
+            .split('/')
+            .filter(|part| !part.is_empty() && *part != ".");
+        let Some(first) = parts.next() else {
+            continue;
+        };
+        let mut node = child_named(&mut roots, first);
+        for part in parts {
+            node = node.child_mut(part);
+        }
+    }
+    roots
+}
+
+fn parse_json(source: &str) -> Result<Vec<TreeNode>, String> {
+    let mut parser = JsonParser {
+        chars: source.chars().collect(),
+        pos: 0,
+        depth: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.pos < parser.chars.len() {
+        return Err(format!(
+            "Invalid JSON: unexpected '{}' at {}",
+            parser.chars[parser.pos], parser.pos
+        ));
+    }
+    Ok(match value {
+        Json::Scalar(text) => vec![TreeNode::new(&text)],
+        container => json_children(container),
+    })
+}
+
+/// Parsed JSON value; scalars keep their display text (strings unquoted)
+enum Json {
+    Scalar(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+fn json_children(value: Json) -> Vec<TreeNode> {
+    match value {
+        Json::Scalar(_) => Vec::new(),
+        Json::Array(items) => items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| match item {
+                Json::Scalar(text) => TreeNode::new(&text),
+                container => TreeNode {
+                    label: format!("[{}]", index),
+                    children: json_children(container),
+                },
+            })
+            .collect(),
+        Json::Object(entries) => entries
+            .into_iter()
+            .map(|(key, item)| match item {
+                Json::Scalar(text) => TreeNode::new(&format!("{}: {}", key, text)),
+                container => TreeNode {
+                    label: key,
+                    children: json_children(container),
+                },
+            })
+            .collect(),
+    }
+}
+
+/// Deepest object/array nesting accepted, so hostile input can't exhaust the stack
+const MAX_JSON_DEPTH: usize = 128;
+
+struct JsonParser {
+    chars: Vec<char>,
+    pos: usize,
+    /// Objects and arrays currently open
+    depth: usize,
+}
+
+impl JsonParser {
+    fn skip_whitespace(&mut self) {
+        while self.chars.get(self.pos).is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn error(&self, expected: &str) -> String {
+        match self.chars.get(self.pos) {
+            Some(c) => format!(
+                "Invalid JSON: expected {} but found '{}' at {}",
+                expected, c, self.pos
+            ),
+            None => format!("Invalid JSON: expected {} at end of input", expected),
+        }
+    }
+
+    fn expect(&mut self, ch: char) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.chars.get(self.pos) != Some(&ch) {
+            return Err(self.error(&format!("'{}'", ch)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn value(&mut self) -> Result<Json, String> {
+        self.skip_whitespace();
+        match self.chars.get(self.pos) {
+            Some('{') => self.nested(Self::object),
+            Some('[') => self.nested(Self::array),
+            Some('"') => Ok(Json::Scalar(self.string()?)),
+            Some(_) => self.literal(),
+            None => Err(self.error("a value")),
+        }
+    }
+
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Json, String>) -> Result<Json, String> {
+        if self.depth == MAX_JSON_DEPTH {
+            return Err(format!(
+                "Invalid JSON: nested deeper than {} levels at {}",
+                MAX_JSON_DEPTH, self.pos
+            ));
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn object(&mut self) -> Result<Json, String> {
+        self.expect('{')?;
+        let mut entries = Vec::new();
+        self.skip_whitespace();
+        if self.chars.get(self.pos) == Some(&'}') {
+            self.pos += 1;
+            return Ok(Json::Object(entries));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.chars.get(self.pos) != Some(&'"') {
+                return Err(self.error("a key"));
+            }
+            let key = self.string()?;
+            self.expect(':')?;
+            entries.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.chars.get(self.pos) {
+                Some(',') => self.pos += 1,
+                Some('}') => {
+                    self.pos += 1;
+                    return Ok(Json::Object(entries));
+                }
+                _ => return Err(self.error("',' or '}'")),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, String> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.chars.get(self.pos) == Some(&']') {
+            self.pos += 1;
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.chars.get(self.pos) {
+                Some(',') => self.pos += 1,
+                Some(']') => {
+                    self.pos += 1;
+                    return Ok(Json::Array(items));
+                }
+                _ => return Err(self.error("',' or ']'")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.pos += 1; // opening quote
+        let mut text = String::new();
+        loop {
+            let Some(&ch) = self.chars.get(self.pos) else {
+                return Err(self.error("'\"'"));
+            };
+            self.pos += 1;
+            match ch {
+                '"' => return Ok(text),
+                '\\' => {
+                    let Some(&escaped) = self.chars.get(self.pos) else {
+                        return Err(self.error("an escape"));
+                    };
+                    self.pos += 1;
+                    match escaped {
+                        'n' | 'r' | 't' => text.push(' '),
+                        'b' | 'f' => {}
+                        'u' => {
+                            let mut code = self.hex4()?;
+                            // A high surrogate combines with the low one escaped after it
+                            if (0xD800..0xDC00).contains(&code)
+                                && self.chars.get(self.pos..self.pos + 2) == Some(&['\\', 'u'])
+                            {
+                                let resume = self.pos;
+                                self.pos += 2;
+                                match self.hex4()? {
+                                    low @ 0xDC00..0xE000 => {
+                                        code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00)
+                                    }
+                                    _ => self.pos = resume,
+                                }
+                            }
+                            text.push(char::from_u32(code).unwrap_or('\u{FFFD}'));
+                        }
+                        other => text.push(other),
+                    }
+                }
+                other => text.push(other),
+            }
+        }
+    }
+
+    /// Exactly four hex digits after `\\u`
+    fn hex4(&mut self) -> Result<u32, String> {
+        let digits = self.chars.get(self.pos..self.pos + 4);
+        let code = digits
+            .filter(|digits| digits.iter().all(char::is_ascii_hexdigit))
+            .and_then(|digits| u32::from_str_radix(&digits.iter().collect::<String>(), 16).ok())
+            .ok_or_else(|| self.error("four hex digits"))?;
+        self.pos += 4;
+        Ok(code)
+    }
+
+    fn literal(&mut self) -> Result<Json, String> {
+        let start = self.pos;
+        while self
+            .chars
+            .get(self.pos)
+            .is_some_and(|c| c.is_alphanumeric() || matches!(c, '-' | '+' | '.'))
+        {
+            self.pos += 1;
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        let valid = matches!(text.as_str(), "true" | "false" | "null") || is_json_number(&text);
+        if !valid {
+            self.pos = start;
+            return Err(self.error("a value"));
+        }
+        Ok(Json::Scalar(text))
+    }
+}
+
+/// JSON number syntax: `-?(0|[1-9][0-9]*)(.[0-9]+)?([eE][+-]?[0-9]+)?`
+fn is_json_number(text: &str) -> bool {
+    fn digits(s: &str) -> (&str, &str) {
+        let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        s.split_at(end)
+    }
+
+    let rest = text.strip_prefix('-').unwrap_or(text);
+    let (int, mut rest) = digits(rest);
+    if int.is_empty() || (int.len() > 1 && int.starts_with('0')) {
+        return false;
+    }
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let (frac, after) = digits(fraction);
+        if frac.is_empty() {
+            return false;
+        }
+        rest = after;
+    }
+    if let Some(exponent) = rest.strip_prefix(['e', 'E']) {
+        let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        let (exp, after) = digits(exponent);
+        if exp.is_empty() {
+            return false;
+        }
+        rest = after;
+    }
+    rest.is_empty()
+}
+
+/// One connector cell left of a tree row
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Guide {
+    /// An ancestor has later siblings: `│   `
+    Pipe,
+    /// An ancestor was the last child: four blanks
+    Blank,
+    /// This node has later siblings: `├── `
+    Branch,
+    /// This node is the last child: `└── `
+    Last,
+}
+
+/// Columns each connector cell takes
+pub const GUIDE_WIDTH: usize = 4;
+
+/// A flattened tree row: connectors, then the label
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeRow {
+    pub guides: Vec<Guide>,
+    pub label: String,
+}
+
+/// Rows in display order; nodes at `max_depth` with children show `(+N)` instead
+pub fn flatten(roots: &[TreeNode], max_depth: Option<usize>) -> Vec<TreeRow> {
+    let mut rows = Vec::new();
+    for root in roots {
+        push_rows(root, &mut Vec::new(), 0, max_depth, &mut rows);
+    }
+    rows
+}
+
+fn push_rows(
+    node: &TreeNode,
+    guides: &mut Vec<Guide>,
+    depth: usize,
+    max_depth: Option<usize>,
+    rows: &mut Vec<TreeRow>,
+) {
+    let collapsed = max_depth.is_some_and(|max| depth >= max) && !node.children.is_empty();
+    let label = match collapsed {
+        true => format!("{} (+{})", node.label, node.descendants()),
+        false => node.label.clone(),
+    };
+    rows.push(TreeRow {
+        guides: guides.clone(),
+        label,
+    });
+    if collapsed {
+        return;
+    }
+
+    // Below this node, its own connector turns into a pipe or a gap
+    if let Some(own) = guides.last_mut() {
+        *own = match own {
+            Guide::Last => Guide::Blank,
+            _ => Guide::Pipe,
+        };
+    }
+    let last = node.children.len().saturating_sub(1);
+    for (index, child) in node.children.iter().enumerate() {
+        guides.push(if index == last {
+            Guide::Last
+        } else {
+            Guide::Branch
+        });
+        push_rows(child, guides, depth + 1, max_depth, rows);
+        guides.pop();
+    }
+}
+
+/// Connector strings for a box style
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeGlyphs {
+    pub pipe: String,
+    pub branch: String,
+    pub last: String,
+}
+
+impl TreeGlyphs {
+    /// Connectors in the style's glyph family; ASCII for styles without box-drawing glyphs
+    pub fn for_style(style: &BoxStyle) -> Self {
+        let drawn = [style.vertical, style.horizontal, style.tee_left]
+            .iter()
+            .all(|glyph| !glyph.is_ascii() && glyph.chars().all(|c| BOX_CHARS.contains(c)));
+        if !drawn {
+            return Self {
+                pipe: "|   ".to_string(),
+                branch: "|-- ".to_string(),
+                last: "`-- ".to_string(),
+            };
+        }
+        let rule = style.horizontal.repeat(2);
+        Self {
+            pipe: format!("{}   ", style.vertical),
+            branch: format!("{}{} ", style.tee_left, rule),
+            last: format!("{}{} ", style.bottom_left, rule),
+        }
+    }
+
+    pub fn prefix(&self, guides: &[Guide]) -> String {
+        guides
+            .iter()
+            .map(|guide| match guide {
+                Guide::Pipe => self.pipe.as_str(),
+                Guide::Blank => "    ",
+                Guide::Branch => self.branch.as_str(),
+                Guide::Last => self.last.as_str(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::visual::{ASCII, HEAVY, NORMAL};
+
+    fn draw(source: &str, format: TreeFormat, style: &BoxStyle) -> Vec<String> {
+        let glyphs = TreeGlyphs::for_style(style);
+        TreeStyle::new()
+            .with_format(format)
+            .rows(source)
+            .iter()
+            .map(|row| format!("{}{}", glyphs.prefix(&row.guides), row.label))
+            .collect()
+    }
+
+    #[test]
+    fn test_indented_text() {
+        let rows = draw(
+            "app\n  src\n    main.rs\n  docs\n\tguide.md",
+            TreeFormat::Indent,
+            &NORMAL,
+        );
+        assert_eq!(
+            rows,
+            vec![
+                "app",
+                "├── src",
+                "│   └── main.rs",
+                "└── docs",
+                "    └── guide.md"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_paths_merge_shared_prefixes() {
+        let source = ".\n./src\n./src/main.rs\n./src/api/tree.rs\n./README.md";
+        assert_eq!(
+            parse_tree(source, TreeFormat::Auto),
+            Ok(parse_paths(source))
+        );
+        let rows = draw(source, TreeFormat::Paths, &ASCII);
+        assert_eq!(
+            rows,
+            vec![
+                "src",
+                "|-- main.rs",
+                "`-- api",
+                "    `-- tree.rs",
+                "README.md"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_tree() {
+        let source = r#"{"name": "boxy", "deps": {"regex": "1.11", "serde": {"features": ["derive"]}}, "ok": true}"#;
+        let rows = draw(source, TreeFormat::Auto, &HEAVY);
+        assert_eq!(
+            rows,
+            vec![
+                "name: boxy",
+                "deps",
+                "┣━━ regex: 1.11",
+                "┗━━ serde",
+                "    ┗━━ features",
+                "        ┗━━ derive",
+                "ok: true"
+            ]
+        );
+        assert!(parse_tree("{\"a\": }", TreeFormat::Json).is_err());
+        // Auto falls back to indented text for input that only looks like JSON
+        assert_eq!(
+            parse_tree("[draft]", TreeFormat::Auto).unwrap()[0].label,
+            "[draft]"
+        );
+    }
+
+    #[test]
+    fn test_json_strings_and_numbers_are_strict() {
+        let label = |source: &str| parse_tree(source, TreeFormat::Json).map(|t| t[0].label.clone());
+        assert_eq!(label(r#"["\uD83D\uDE00"]"#), Ok("😀".to_string()));
+        assert_eq!(label(r#"["\u00e9"]"#), Ok("é".to_string()));
+        assert!(label(r#"["\u+0e9"]"#).is_err());
+        assert!(label(r#"["\u12"]"#).is_err());
+
+        for number in ["0", "-1", "2.5", "1e9", "-0.5E-3"] {
+            assert_eq!(label(&format!("[{}]", number)), Ok(number.to_string()));
+        }
+        for invalid in ["inf", "NaN", "+1", "01", "1.", ".5", "1e", "0x10"] {
+            assert!(label(&format!("[{}]", invalid)).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_json_nesting_is_limited() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse_tree(&nested(MAX_JSON_DEPTH), TreeFormat::Json).is_ok());
+        let error = parse_tree(&nested(100_000), TreeFormat::Json).unwrap_err();
+        assert!(error.contains("nested deeper"), "{}", error);
+    }
+
+    #[test]
+    fn test_collapse_beyond_depth() {
+        let rows = TreeStyle::new()
+            .with_max_depth(1)
+            .rows("a\n b\n  c\n  d\n e");
+        let labels: Vec<&str> = rows.iter().map(|r| r.label.as_str()).collect();
+        assert_eq!(labels, vec!["a", "b (+2)", "e"]);
+    }
+}
@@ -50,26 +50,54 @@ pub fn pad_cell(s: &str, width: usize) -> String {
 /// Strip ANSI escape sequences from text for accurate width calculation
 ///
 /// Internal implementation for removing color codes when calculating text width.
+/// OSC sequences (e.g. OSC 8 hyperlinks) are removed whole, keeping the link text.
 pub fn strip_ansi_codes(text: &str) -> String {
     let mut result = String::new();
-    let mut in_escape = false;
-    let mut chars = text.chars();
+    let mut chars = text.chars().peekable();
 
     while let Some(ch) = chars.next() {
         if ch == '\x1B' {
-            in_escape = true;
+            read_escape_sequence(&mut chars);
             continue;
         }
-
-        if in_escape {
-            if ch.is_alphabetic() {
-                in_escape = false;
-            }
-            continue;
-        }
-
         result.push(ch);
     }
 
     result
 }
+
+/// Consume the rest of an escape sequence whose ESC was just read; returns it with the ESC
+///
+/// CSI (`ESC [ ... final`) ends at its final byte, OSC (`ESC ] ...`) at BEL or
+/// `ESC \`; any other escape is ESC plus one character.
+pub fn read_escape_sequence(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut sequence = String::from('\x1B');
+    let Some(kind) = chars.next() else {
+        return sequence;
+    };
+    sequence.push(kind);
+    match kind {
+        '[' => {
+            for ch in chars.by_ref() {
+                sequence.push(ch);
+                if ('\x40'..='\x7E').contains(&ch) {
+                    break;
+                }
+            }
+        }
+        ']' => {
+            while let Some(ch) = chars.next() {
+                sequence.push(ch);
+                if ch == '\x07' {
+                    break;
+                }
+                if ch == '\x1B' && chars.peek() == Some(&'\\') {
+                    sequence.push(chars.next().unwrap());
+                    break;
+                }
+            }
+        }
+        _ => {}
+    }
+    sequence
+}
@@ -11,9 +11,11 @@ pub mod utils;
 // Re-export public API (curated, no wildcards per RSB MODULE_SPEC)
 // Allow unused lint because the CLI binary may not reference every helper directly,
 // but downstream library users depend on this surface.
-pub use helpers::strip_ansi_codes;
+pub use helpers::{read_escape_sequence, strip_ansi_codes};
 #[allow(unused_imports)]
-pub use utils::{RESET, generate_color_help, get_color_categories, get_color_code, validate_color};
+pub use utils::{
+    BOLD, ITALIC, RESET, generate_color_help, get_color_categories, get_color_code, validate_color,
+};
 
 // Test module access
 #[cfg(test)]
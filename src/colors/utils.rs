@@ -7,6 +7,10 @@ use super::helpers::*;
 
 pub const RESET: &str = "\x1B[0m";
 
+// Text style codes (combine with a color code; closed by RESET)
+pub const BOLD: &str = "\x1B[1m";
+pub const ITALIC: &str = "\x1B[3m";
+
 /// Core color mapping function - supports 90+ semantic colors
 ///
 /// This is the main public API for color code resolution.
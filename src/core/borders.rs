@@ -0,0 +1,161 @@
+//! Border sides - which edges of the frame are drawn
+//!
+//! Sides are given as letters in any order: `t` top, `r` right, `b` bottom,
+//! `l` left, plus the words `all` and `none`:
+//!
+//! - `tb` - horizontal rules above and below (like barmode)
+//! - `l` - a left rule only, quote style
+//! - `t` - a rule on top only
+//! - `none` - no frame, padding kept
+//!
+//! An edge that is turned off but carries a header or footer label keeps the
+//! label on its own row, without the rule glyphs.
+
+use std::fmt;
+
+/// Edges of the frame to draw; all four by default
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "cli",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
+pub struct BorderSides {
+    pub top: bool,
+    pub right: bool,
+    pub bottom: bool,
+    pub left: bool,
+}
+
+impl Default for BorderSides {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl BorderSides {
+    pub const ALL: Self = Self {
+        top: true,
+        right: true,
+        bottom: true,
+        left: true,
+    };
+    pub const NONE: Self = Self {
+        top: false,
+        right: false,
+        bottom: false,
+        left: false,
+    };
+
+    /// Parse `all`, `none` or side letters such as `tb` or `l`
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let spec = spec.trim().to_lowercase();
+        match spec.as_str() {
+            "all" => return Ok(Self::ALL),
+            "none" => return Ok(Self::NONE),
+            "" => {
+                return Err(
+                    "Empty border sides. Use letters t, r, b, l, 'all' or 'none'".to_string(),
+                );
+            }
+            _ => {}
+        }
+
+        let mut sides = Self::NONE;
+        for ch in spec.chars() {
+            match ch {
+                't' => sides.top = true,
+                'r' => sides.right = true,
+                'b' => sides.bottom = true,
+                'l' => sides.left = true,
+                _ => {
+                    return Err(format!(
+                        "Invalid border side '{}' in '{}'. Use letters t, r, b, l, 'all' or 'none'",
+                        ch, spec
+                    ));
+                }
+            }
+        }
+        Ok(sides)
+    }
+
+    pub fn is_all(&self) -> bool {
+        *self == Self::ALL
+    }
+
+    /// Columns taken by the left and right edges
+    pub fn columns(&self) -> usize {
+        self.left as usize + self.right as usize
+    }
+}
+
+impl fmt::Display for BorderSides {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::ALL => write!(f, "all"),
+            Self::NONE => write!(f, "none"),
+            sides => {
+                let letters = [
+                    (sides.top, 't'),
+                    (sides.right, 'r'),
+                    (sides.bottom, 'b'),
+                    (sides.left, 'l'),
+                ];
+                for (on, letter) in letters {
+                    if on {
+                        write!(f, "{}", letter)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for BorderSides {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(feature = "cli")]
+impl TryFrom<String> for BorderSides {
+    type Error = String;
+
+    fn try_from(spec: String) -> Result<Self, Self::Error> {
+        Self::parse(&spec)
+    }
+}
+
+#[cfg(feature = "cli")]
+impl From<BorderSides> for String {
+    fn from(sides: BorderSides) -> Self {
+        sides.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_side_letters() {
+        let tb = BorderSides::parse("tb").unwrap();
+        assert!(tb.top && tb.bottom && !tb.left && !tb.right);
+        assert_eq!(BorderSides::parse("LRTB"), Ok(BorderSides::ALL));
+        assert_eq!(BorderSides::parse(" none "), Ok(BorderSides::NONE));
+        assert_eq!(BorderSides::parse("l").unwrap().columns(), 1);
+        assert!(BorderSides::parse("tx").is_err());
+        assert!(BorderSides::parse("").is_err());
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for spec in ["all", "none", "tb", "l", "trl"] {
+            assert_eq!(BorderSides::parse(spec).unwrap().to_string(), spec);
+        }
+        assert_eq!(BorderSides::parse("bt").unwrap().to_string(), "tb");
+    }
+}
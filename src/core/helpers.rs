@@ -206,7 +206,13 @@ fn break_long_word(word: &str, max_width: usize) -> Vec<String> {
     let mut current = String::new();
     let mut current_width = 0;
 
-    for ch in word.chars() {
+    let mut chars = word.chars().peekable();
+    while let Some(ch) = chars.next() {
+        // Escape sequences stay whole and take no columns
+        if ch == '\x1B' {
+            current.push_str(&crate::colors::read_escape_sequence(&mut chars));
+            continue;
+        }
         let ch_width = get_display_width(&ch.to_string());
 
         if current_width + ch_width > max_width {
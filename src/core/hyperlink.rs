@@ -0,0 +1,217 @@
+//! OSC 8 hyperlinks - clickable URLs and file paths in box content
+//!
+//! Links are `ESC ] 8 ; params ; url ST text ESC ] 8 ; ; ST`. The escapes are
+//! zero-width for the width engine and `strip_ansi_codes`; this module creates
+//! them from `[text](url)` markup, keeps them balanced when a link's text is
+//! wrapped over several lines, and degrades them to `text (url)` for plain output.
+
+use crate::colors::read_escape_sequence;
+
+const OSC8_PREFIX: &str = "\x1b]8;";
+const ST: &str = "\x1b\\";
+
+/// Wrap `text` in an OSC 8 hyperlink to `url`
+pub fn hyperlink(url: &str, text: &str) -> String {
+    format!("{}{}{}{}{}{}", OSC8_PREFIX, ";", url, ST, text, close())
+}
+
+fn close() -> String {
+    format!("{};{}", OSC8_PREFIX, ST)
+}
+
+/// URL of an OSC 8 sequence: `Some("")` closes a link, `None` if not a hyperlink
+pub fn link_target(sequence: &str) -> Option<&str> {
+    let body = sequence.strip_prefix(OSC8_PREFIX)?;
+    let body = body
+        .strip_suffix(ST)
+        .or_else(|| body.strip_suffix('\x07'))
+        .unwrap_or(body);
+    body.split_once(';').map(|(_, url)| url)
+}
+
+/// Targets that `[text](target)` markup turns into links
+fn is_link_target(target: &str) -> bool {
+    !target.contains(char::is_whitespace)
+        && (target.contains("://")
+            || target.starts_with("mailto:")
+            || target.starts_with('/')
+            || target.starts_with("./")
+            || target.starts_with("~/"))
+}
+
+/// Replace `[text](url)` markup with OSC 8 hyperlinks
+///
+/// Only URL-like targets (scheme, `mailto:`, absolute or `./` paths) are linked,
+/// so ordinary bracketed text is left alone.
+pub fn expand_link_markup(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+
+    while let Some(open) = rest.find('[') {
+        let after = &rest[open + 1..];
+        let parsed = after.find("](").and_then(|mid| {
+            let label = &after[..mid];
+            let target_start = &after[mid + 2..];
+            let end = target_start.find(')')?;
+            let target = &target_start[..end];
+            let valid = !label.is_empty() && !label.contains('[') && is_link_target(target);
+            valid.then(|| (label, target, open + 1 + mid + 2 + end + 1))
+        });
+
+        match parsed {
+            Some((label, target, consumed)) => {
+                result.push_str(&rest[..open]);
+                result.push_str(&hyperlink(target, label));
+                rest = &rest[consumed..];
+            }
+            None => {
+                result.push_str(&rest[..=open]);
+                rest = &rest[open + 1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Replace hyperlinks with `text (url)`; other escape sequences are kept
+pub fn degrade_hyperlinks(text: &str) -> String {
+    let mut result = String::new();
+    // (url, visible text collected so far)
+    let mut open: Option<(String, String)> = None;
+    let mut chars = text.chars().peekable();
+
+    let finish = |url: String, label: String, result: &mut String| {
+        let visible = crate::colors::strip_ansi_codes(&label);
+        result.push_str(&label);
+        if visible != url {
+            result.push_str(&format!(" ({})", url));
+        }
+    };
+
+    while let Some(ch) = chars.next() {
+        if ch == '\x1b' {
+            let sequence = read_escape_sequence(&mut chars);
+            match link_target(&sequence) {
+                Some(url) => {
+                    if let Some((url, label)) = open.take() {
+                        finish(url, label, &mut result);
+                    }
+                    if !url.is_empty() {
+                        open = Some((url.to_string(), String::new()));
+                    }
+                }
+                None => match &mut open {
+                    Some((_, label)) => label.push_str(&sequence),
+                    None => result.push_str(&sequence),
+                },
+            }
+            continue;
+        }
+        match &mut open {
+            Some((_, label)) => label.push(ch),
+            None => result.push(ch),
+        }
+    }
+    if let Some((url, label)) = open {
+        finish(url, label, &mut result);
+    }
+    result
+}
+
+/// Close links left open at the end of a wrapped line and reopen them on the next
+pub fn balance_hyperlinks(lines: Vec<String>) -> Vec<String> {
+    if !lines.iter().any(|line| line.contains(OSC8_PREFIX)) {
+        return lines;
+    }
+
+    let mut open: Option<String> = None;
+    lines
+        .into_iter()
+        .map(|line| {
+            let mut balanced = open.clone().unwrap_or_default();
+            let mut chars = line.chars().peekable();
+            while let Some(ch) = chars.next() {
+                if ch == '\x1b' {
+                    let sequence = read_escape_sequence(&mut chars);
+                    match link_target(&sequence) {
+                        Some("") => open = None,
+                        Some(_) => open = Some(sequence.clone()),
+                        None => {}
+                    }
+                    balanced.push_str(&sequence);
+                } else {
+                    balanced.push(ch);
+                }
+            }
+            if open.is_some() {
+                balanced.push_str(&close());
+            }
+            balanced
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colors::strip_ansi_codes;
+    use crate::width_plugin::get_display_width;
+
+    #[test]
+    fn test_hyperlink_is_zero_width() {
+        let link = hyperlink("https://example.com/build/42", "build 42");
+        assert_eq!(strip_ansi_codes(&link), "build 42");
+        assert_eq!(get_display_width(&link), 8);
+
+        // BEL-terminated links from other tools measure the same
+        let bel = "\x1b]8;id=1;https://x.io\x07x.io\x1b]8;;\x07";
+        assert_eq!(strip_ansi_codes(bel), "x.io");
+        assert_eq!(get_display_width(bel), 4);
+    }
+
+    #[test]
+    fn test_expand_link_markup() {
+        let text = expand_link_markup("see [log](https://ci.io/1) and [x] (y) [a](b)");
+        assert_eq!(
+            text,
+            format!(
+                "see {} and [x] (y) [a](b)",
+                hyperlink("https://ci.io/1", "log")
+            )
+        );
+        assert_eq!(
+            expand_link_markup("[main.rs](file:///src/main.rs)"),
+            hyperlink("file:///src/main.rs", "main.rs")
+        );
+    }
+
+    #[test]
+    fn test_degrade_hyperlinks() {
+        let text = format!(
+            "{} and {}",
+            hyperlink("https://ci.io/1", "log"),
+            hyperlink("https://x.io", "https://x.io")
+        );
+        assert_eq!(
+            degrade_hyperlinks(&text),
+            "log (https://ci.io/1) and https://x.io"
+        );
+    }
+
+    #[test]
+    fn test_balance_wrapped_link() {
+        let link = hyperlink("https://x.io", "two words");
+        let (first, second) = link.split_once(' ').unwrap();
+        let lines = balance_hyperlinks(vec![first.to_string(), second.to_string()]);
+
+        assert_eq!(lines[0], format!("{}{}", first, close()));
+        assert_eq!(
+            lines[1],
+            format!("{};https://x.io{}{}", OSC8_PREFIX, ST, second)
+        );
+        for line in &lines {
+            assert!(!strip_ansi_codes(line).contains('\x1b'));
+        }
+    }
+}
@@ -12,17 +12,46 @@
 //!
 //! Version: boxy v0.16.0+ (RSB MODULE_SPEC reorganization)
 
+pub mod borders;
 pub mod helpers;
+pub mod hyperlink;
+pub mod overflow;
+pub mod sanitize;
+pub mod size;
+pub mod spacing;
 pub mod utils;
 
 #[allow(unused_imports)]
 pub use utils::{
-    AlignmentConfig, BodyAlignment, BoxColors, BoxyConfig, DESCRIPTION, DividerConfig, NAME,
-    PaddingConfig, ParsedContent, VERSION, WidthConfig, expand_variables, parse_content_stream,
-    render_title_or_footer, resolve_box_config, truncate_with_ellipsis, unescape_stream_value,
+    AlignedLine, AlignmentConfig, BodyAlignment, BodyMode, BorderLabelConfig, BoxColors,
+    BoxyConfig, CodeConfig, DESCRIPTION, DiffColors, DiffConfig, DividerConfig, EdgeColors, NAME,
+    PaddingConfig, ParsedContent, RunConfig, ShadowConfig, TreeConfig, VERSION, WidthConfig,
+    align_line, align_line_parts, expand_tabs, expand_variables, leading_icon,
+    parse_content_stream, render_title_or_footer, resolve_box_config, split_after_icon,
+    split_aligned_lines, truncate_with_ellipsis, unescape_stream_value,
     wrap_text_at_word_boundaries,
 };
 
+#[allow(unused_imports)]
+pub use borders::BorderSides;
+
+#[allow(unused_imports)]
+pub use hyperlink::{balance_hyperlinks, degrade_hyperlinks, expand_link_markup, hyperlink};
+
+#[allow(unused_imports)]
+pub use overflow::{Overflow, OverflowPolicy};
+
+#[allow(unused_imports)]
+pub use size::{
+    MAX_HEIGHT, MAX_WIDTH, MIN_HEIGHT, MIN_WIDTH, SizeSpec, resolve_height, resolve_width,
+};
+
+#[allow(unused_imports)]
+pub use spacing::Spacing;
+
+#[allow(unused_imports)]
+pub use sanitize::{ControlStyle, SanitizeOptions, SanitizeReport, sanitize, sanitize_text};
+
 #[cfg(feature = "cli")]
 #[allow(unused_imports)]
 pub use utils::{show_comprehensive_help, show_comprehensive_help_with_header, show_logo, show_usage_examples};
@@ -96,9 +125,58 @@ mod tests {
         assert_eq!(BodyAlignment::from("center"), BodyAlignment::Center);
         assert_eq!(BodyAlignment::from("right"), BodyAlignment::Right);
         assert_eq!(BodyAlignment::from("left"), BodyAlignment::Left);
+        assert_eq!(BodyAlignment::from("justify"), BodyAlignment::Justify);
         assert_eq!(BodyAlignment::from("invalid"), BodyAlignment::Left);
     }
 
+    #[test]
+    fn test_inline_alignment_markers() {
+        assert_eq!(
+            BodyAlignment::split_marker("#C# Centered"),
+            (Some(BodyAlignment::Center), "Centered")
+        );
+        assert_eq!(
+            BodyAlignment::split_marker("#J#text"),
+            (Some(BodyAlignment::Justify), "text")
+        );
+        assert_eq!(BodyAlignment::split_marker("plain"), (None, "plain"));
+
+        // Wrapped fragments inherit the marker; only the last ends the paragraph
+        let lines = split_aligned_lines("#R#one two three#NL#four", &BodyAlignment::Left, Some(8));
+        let (last, wrapped) = lines.split_last().unwrap();
+        assert!(wrapped.len() >= 2);
+        assert!(wrapped.iter().all(|l| l.align == BodyAlignment::Right));
+        assert!(wrapped.iter().rev().skip(1).all(|l| !l.paragraph_end));
+        assert!(wrapped.last().unwrap().paragraph_end);
+        assert_eq!(last.text, "four");
+        assert_eq!(last.align, BodyAlignment::Left);
+    }
+
+    #[test]
+    fn test_align_line() {
+        crate::width_calibration::install_uncalibrated();
+        assert_eq!(align_line("ab", 6, &BodyAlignment::Left, true), "ab    ");
+        assert_eq!(align_line("ab", 6, &BodyAlignment::Center, true), "  ab  ");
+        assert_eq!(align_line("ab", 6, &BodyAlignment::Right, true), "    ab");
+        assert_eq!(
+            align_line("a b c", 9, &BodyAlignment::Justify, false),
+            "a   b   c"
+        );
+        assert_eq!(
+            align_line("a bb c", 9, &BodyAlignment::Justify, false),
+            "a   bb  c"
+        );
+        // Paragraph-ending and single-word lines stay left aligned
+        let justify = BodyAlignment::Justify;
+        assert_eq!(align_line("a b", 6, &justify, true), "a b   ");
+        assert_eq!(align_line("word", 6, &justify, false), "word  ");
+        // Wide glyphs count as two columns
+        assert_eq!(
+            align_line("日本", 6, &BodyAlignment::Center, true),
+            " 日本 "
+        );
+    }
+
     #[test]
     fn test_variable_expansion() {
         unsafe {
@@ -130,6 +208,7 @@ mod tests {
 
     #[test]
     fn test_critical_icon_detection_logic() {
+        crate::width_calibration::install_uncalibrated();
         // Test the PROTECTED icon detection logic in render_title_or_footer
         let result = render_title_or_footer("📦 Package Status", 20, "─", "left");
 
@@ -149,6 +228,14 @@ mod tests {
         assert_eq!(result, vec!["hello", "world"]);
     }
 
+    #[test]
+    fn test_wrap_text_sanitizes_first() {
+        let result = wrap_text_at_word_boundaries("a\x07b\tc", 20);
+        assert_eq!(result.len(), 1);
+        assert!(!result[0].contains(['\x07', '\t']), "{:?}", result);
+        assert!(result[0].starts_with("ab"), "{:?}", result);
+    }
+
     #[test]
     fn test_truncate_with_ellipsis() {
         let result = truncate_with_ellipsis("very long text", 8);
@@ -157,6 +244,41 @@ mod tests {
         assert!(result.chars().count() <= 8);
     }
 
+    #[test]
+    fn test_hyperlinks_survive_truncation_and_wrapping() {
+        let link = hyperlink("https://example.com/a/very/long/path", "linked words here");
+        let text = format!("see {} now", link);
+
+        let truncated = truncate_with_ellipsis(&text, 10);
+        assert_eq!(crate::strip_ansi_codes(&truncated), "see linke…");
+        assert!(truncated.ends_with("\x1b]8;;\x1b\\"));
+
+        let lines = split_aligned_lines(&text, &BodyAlignment::Left, Some(8));
+        let plain: Vec<String> = lines
+            .iter()
+            .map(|line| crate::strip_ansi_codes(&line.text))
+            .collect();
+        assert_eq!(plain, vec!["see", "linked", "words", "here now"]);
+        // Every line opens and closes its own piece of the link
+        for line in &lines[1..] {
+            assert!(
+                line.text
+                    .starts_with("\x1b]8;;https://example.com/a/very/long/path")
+            );
+            assert!(line.text.contains("\x1b]8;;\x1b\\"));
+        }
+    }
+
+    #[test]
+    fn test_expand_tabs_to_tab_stops() {
+        crate::width_calibration::install_uncalibrated();
+        assert_eq!(expand_tabs("\tx", 4), "    x");
+        assert_eq!(expand_tabs("ab\tc", 4), "ab  c");
+        assert_eq!(expand_tabs("abcd\te", 4), "abcd    e");
+        assert_eq!(expand_tabs("中\tx", 4), "中  x");
+        assert_eq!(expand_tabs("no tabs", 8), "no tabs");
+    }
+
     #[test]
     fn test_constants_accessible() {
         // Verify constants are properly exported
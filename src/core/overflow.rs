@@ -0,0 +1,89 @@
+//! Overflow policy - what happens when a box is wider than the terminal
+//!
+//! A box wider than the screen has its right border wrapped onto the next
+//! line by the terminal, which destroys the frame. When a limit is set the
+//! layout checks the computed width against it and applies a policy:
+//!
+//! - `wrap` (default): shrink the box to the limit and re-wrap the body
+//! - `truncate`: shrink the box and cut long lines with an ellipsis
+//! - `shrink`: give up horizontal padding first, then re-wrap what still does not fit
+//! - `error`: refuse to render
+//!
+//! Header, footer and status labels are truncated with an ellipsis in every case.
+
+/// How a box that exceeds the available width is brought back in bounds
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum OverflowPolicy {
+    #[default]
+    Wrap,
+    Truncate,
+    Shrink,
+    Error,
+}
+
+impl OverflowPolicy {
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name.trim().to_lowercase().as_str() {
+            "wrap" => Ok(Self::Wrap),
+            "truncate" => Ok(Self::Truncate),
+            "shrink" => Ok(Self::Shrink),
+            "error" => Ok(Self::Error),
+            _ => Err(format!(
+                "Invalid overflow policy '{}'. Valid: wrap, truncate, shrink, error",
+                name
+            )),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Wrap => "wrap",
+            Self::Truncate => "truncate",
+            Self::Shrink => "shrink",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// A box that did not fit: its natural width, the limit and the policy applied
+#[derive(Debug, Clone, PartialEq)]
+pub struct Overflow {
+    pub policy: OverflowPolicy,
+    /// Width the box would have had without the limit
+    pub width: usize,
+    /// Columns available
+    pub limit: usize,
+}
+
+impl Overflow {
+    /// One-line description for stderr
+    pub fn message(&self) -> String {
+        let outcome = match self.policy {
+            OverflowPolicy::Wrap => "wrapped to fit",
+            OverflowPolicy::Truncate => "truncated to fit",
+            OverflowPolicy::Shrink => "shrunk to fit",
+            OverflowPolicy::Error => "refusing to render (--overflow error)",
+        };
+        format!(
+            "box is {} columns wide but only {} are available; {}",
+            self.width, self.limit, outcome
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_names_round_trip() {
+        for name in ["wrap", "truncate", "shrink", "error"] {
+            assert_eq!(OverflowPolicy::from_name(name).unwrap().name(), name);
+        }
+        assert_eq!(
+            OverflowPolicy::from_name(" Shrink "),
+            Ok(OverflowPolicy::Shrink)
+        );
+        assert!(OverflowPolicy::from_name("scroll").is_err());
+    }
+}
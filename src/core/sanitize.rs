@@ -0,0 +1,256 @@
+//! Input sanitization - make raw text safe for width calculation
+//!
+//! Piped input may carry tabs, carriage returns (progress spinners),
+//! backspaces and other C0 control characters. None of them occupy a
+//! predictable number of columns, so they are resolved here before any
+//! wrapping or measuring happens:
+//!
+//! - tabs expand to the next tab stop
+//! - `\r` keeps the last overwrite of a line (`50%\r100%` -> `100%`)
+//! - other controls are stripped or made visible (`␛` or `^[`)
+//!
+//! ANSI color (CSI) and OSC sequences pass through untouched.
+
+use std::collections::BTreeMap;
+
+use crate::colors::read_escape_sequence;
+use crate::width_plugin::get_display_width;
+
+/// What happens to control characters other than tab, `\r` and `\n`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ControlStyle {
+    /// Drop them
+    #[default]
+    Strip,
+    /// Unicode control pictures: `␛`, `␈`
+    Picture,
+    /// Caret notation: `^[`, `^H`
+    Caret,
+}
+
+impl From<&str> for ControlStyle {
+    fn from(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "show" | "picture" => Self::Picture,
+            "caret" => Self::Caret,
+            _ => Self::Strip,
+        }
+    }
+}
+
+/// Sanitization settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct SanitizeOptions {
+    /// Columns per tab stop
+    pub tab_width: usize,
+    pub controls: ControlStyle,
+}
+
+impl Default for SanitizeOptions {
+    fn default() -> Self {
+        Self {
+            tab_width: 4,
+            controls: ControlStyle::Strip,
+        }
+    }
+}
+
+/// What a sanitization pass changed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SanitizeReport {
+    pub tabs_expanded: usize,
+    /// Carriage returns resolved by keeping the last overwrite
+    pub carriage_returns: usize,
+    /// Other control characters by count
+    pub controls: BTreeMap<char, usize>,
+}
+
+impl SanitizeReport {
+    pub fn is_clean(&self) -> bool {
+        self.tabs_expanded == 0 && self.carriage_returns == 0 && self.controls.is_empty()
+    }
+
+    /// Fold another report into this one
+    pub fn merge(&mut self, other: SanitizeReport) {
+        self.tabs_expanded += other.tabs_expanded;
+        self.carriage_returns += other.carriage_returns;
+        for (ch, count) in other.controls {
+            *self.controls.entry(ch).or_default() += count;
+        }
+    }
+
+    /// Human readable lines for debug output
+    pub fn summary(&self, style: ControlStyle) -> Vec<String> {
+        let mut lines = Vec::new();
+        if self.tabs_expanded > 0 {
+            lines.push(format!("tabs expanded: {}", self.tabs_expanded));
+        }
+        if self.carriage_returns > 0 {
+            lines.push(format!(
+                "carriage returns resolved: {}",
+                self.carriage_returns
+            ));
+        }
+        let action = match style {
+            ControlStyle::Strip => "stripped",
+            _ => "shown",
+        };
+        for (&ch, count) in &self.controls {
+            lines.push(format!(
+                "{} (U+{:04X}) {}: {}",
+                caret(ch),
+                ch as u32,
+                action,
+                count
+            ));
+        }
+        lines
+    }
+}
+
+/// Sanitize with default options, discarding the report
+pub fn sanitize(text: &str) -> String {
+    sanitize_text(text, &SanitizeOptions::default()).0
+}
+
+/// Sanitize text line by line, returning the cleaned text and what changed
+pub fn sanitize_text(text: &str, options: &SanitizeOptions) -> (String, SanitizeReport) {
+    let mut report = SanitizeReport::default();
+    if !text.chars().any(|c| c.is_control() && c != '\n') {
+        return (text.to_string(), report);
+    }
+
+    let lines: Vec<String> = text
+        .split('\n')
+        .map(|line| sanitize_line(line, options, &mut report))
+        .collect();
+    (lines.join("\n"), report)
+}
+
+fn sanitize_line(line: &str, options: &SanitizeOptions, report: &mut SanitizeReport) -> String {
+    // CRLF line endings are not overwrites
+    let line = line.strip_suffix('\r').unwrap_or(line);
+    let line = match line.rsplit('\r').find(|segment| !segment.is_empty()) {
+        Some(last) if line.contains('\r') => {
+            report.carriage_returns += line.matches('\r').count();
+            last
+        }
+        _ => line.trim_start_matches('\r'),
+    };
+
+    let tab_width = options.tab_width.max(1);
+    let mut result = String::new();
+    let mut column = 0;
+    let mut chars = line.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch == '\x1b' && matches!(chars.peek(), Some('[') | Some(']')) {
+            result.push_str(&read_escape_sequence(&mut chars));
+            continue;
+        }
+        if ch == '\t' {
+            let fill = tab_width - column % tab_width;
+            result.push_str(&" ".repeat(fill));
+            column += fill;
+            report.tabs_expanded += 1;
+            continue;
+        }
+        if ch.is_control() {
+            *report.controls.entry(ch).or_default() += 1;
+            let shown = match options.controls {
+                ControlStyle::Strip => String::new(),
+                ControlStyle::Picture => picture(ch).to_string(),
+                ControlStyle::Caret => caret(ch),
+            };
+            column += get_display_width(&shown);
+            result.push_str(&shown);
+            continue;
+        }
+        result.push(ch);
+        column += get_display_width(ch.encode_utf8(&mut [0; 4]));
+    }
+    result
+}
+
+/// Unicode control picture for a C0 control or DEL
+fn picture(ch: char) -> char {
+    match ch as u32 {
+        code @ 0x00..=0x1f => char::from_u32(0x2400 + code).unwrap_or('?'),
+        0x7f => '␡',
+        _ => '�',
+    }
+}
+
+/// Caret notation for a C0 control or DEL (`^H`, `^[`, `^?`)
+fn caret(ch: char) -> String {
+    match ch as u32 {
+        code @ 0x00..=0x1f => format!("^{}", char::from_u32(code + 0x40).unwrap_or('?')),
+        0x7f => "^?".to_string(),
+        code => format!("<U+{:04X}>", code),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with(controls: ControlStyle) -> SanitizeOptions {
+        SanitizeOptions {
+            controls,
+            ..SanitizeOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_tabs_expand_to_stops() {
+        let (text, report) = sanitize_text("a\tb\n\tc", &SanitizeOptions::default());
+        assert_eq!(text, "a   b\n    c");
+        assert_eq!(report.tabs_expanded, 2);
+    }
+
+    #[test]
+    fn test_tab_stops_ignore_color_codes() {
+        let (text, _) = sanitize_text("\x1b[31mab\x1b[0m\tc", &SanitizeOptions::default());
+        assert_eq!(text, "\x1b[31mab\x1b[0m  c");
+    }
+
+    #[test]
+    fn test_carriage_return_keeps_last_overwrite() {
+        let (text, report) = sanitize_text("10%\r50%\r100%\nnext\r\n", &SanitizeOptions::default());
+        assert_eq!(text, "100%\nnext\n");
+        assert_eq!(report.carriage_returns, 2);
+
+        // A trailing CR (spinner cleared the line) keeps the last visible text
+        assert_eq!(sanitize("working\r"), "working");
+    }
+
+    #[test]
+    fn test_controls_strip_picture_or_caret() {
+        let input = "a\x08b\x1bc\x07";
+        assert_eq!(sanitize_text(input, &with(ControlStyle::Strip)).0, "abc");
+        assert_eq!(
+            sanitize_text(input, &with(ControlStyle::Picture)).0,
+            "a␈b␛c␇"
+        );
+        assert_eq!(
+            sanitize_text(input, &with(ControlStyle::Caret)).0,
+            "a^Hb^[c^G"
+        );
+    }
+
+    #[test]
+    fn test_ansi_and_osc_sequences_survive() {
+        let input = "\x1b[1;31mred\x1b[0m \x1b]8;;https://x.io\x1b\\link\x1b]8;;\x07";
+        let (text, report) = sanitize_text(input, &with(ControlStyle::Picture));
+        assert_eq!(text, input);
+        assert!(report.is_clean());
+    }
+
+    #[test]
+    fn test_report_summary() {
+        let (_, report) = sanitize_text("\ta\x08\x08", &SanitizeOptions::default());
+        assert_eq!(
+            report.summary(ControlStyle::Strip),
+            vec!["tabs expanded: 1", "^H (U+0008) stripped: 2"]
+        );
+    }
+}
@@ -0,0 +1,359 @@
+//! Size specs - widths and heights relative to the terminal
+//!
+//! A spec is either an absolute cell count or an expression resolved against
+//! the space available (terminal columns, or the safe terminal height):
+//!
+//! - `40` - exactly 40 cells
+//! - `50%` - half of the available space
+//! - `max` - all of it
+//! - `-4` - all of it minus 4
+//! - `auto` - size to content
+//! - `min(80,100%)` / `max(30,50%)` - smallest / largest of several specs
+//!
+//! Relative results are clamped to the available space, so a box sized with
+//! a percentage or offset never spills past the terminal edge. Absolute sizes
+//! may exceed the terminal (e.g. when writing to a file) up to `MAX_WIDTH` /
+//! `MAX_HEIGHT`.
+
+use std::fmt;
+
+use crate::height_plugin::get_max_safe_height;
+use crate::width_plugin::get_terminal_width;
+
+/// Smallest box that still has borders and one cell of content
+pub const MIN_WIDTH: usize = 4;
+/// Smallest box that fits borders around header, body and footer lines
+pub const MIN_HEIGHT: usize = 5;
+/// Widest box an absolute width may ask for
+pub const MAX_WIDTH: usize = 1000;
+/// Tallest box an absolute height may ask for
+pub const MAX_HEIGHT: usize = 1000;
+
+/// A width or height, absolute or relative to the available space
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "cli",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawSize", into = "RawSize")
+)]
+pub enum SizeSpec {
+    /// Size to content
+    Auto,
+    /// Exact number of cells
+    Cells(usize),
+    /// Percentage of the available space (1-100)
+    Percent(usize),
+    /// All of the available space
+    Full,
+    /// Available space minus N cells
+    Minus(usize),
+    /// Smallest of several specs
+    MinOf(Vec<SizeSpec>),
+    /// Largest of several specs
+    MaxOf(Vec<SizeSpec>),
+}
+
+impl SizeSpec {
+    /// Parse `40`, `50%`, `max`, `auto`, `-4`, `min(..)` or `max(..)`
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let spec = spec.trim();
+        let lower = spec.to_lowercase();
+
+        for name in ["min", "max"] {
+            let Some(inner) = lower
+                .strip_prefix(name)
+                .and_then(|s| s.strip_prefix('('))
+                .and_then(|s| s.strip_suffix(')'))
+            else {
+                continue;
+            };
+            let parts = split_arguments(inner)
+                .into_iter()
+                .map(Self::parse)
+                .collect::<Result<Vec<_>, _>>()?;
+            if parts.len() < 2 {
+                return Err(format!("'{}' needs at least two sizes", spec));
+            }
+            return Ok(match name {
+                "min" => Self::MinOf(parts),
+                _ => Self::MaxOf(parts),
+            });
+        }
+
+        match lower.as_str() {
+            "auto" => return Ok(Self::Auto),
+            "max" | "full" => return Ok(Self::Full),
+            _ => {}
+        }
+
+        let invalid = || {
+            format!(
+                "Invalid size '{}'. Use N, N%, -N, max, auto, min(a,b) or max(a,b)",
+                spec
+            )
+        };
+        if let Some(percent) = lower.strip_suffix('%') {
+            return match percent.trim().parse::<usize>() {
+                Ok(p) if (1..=100).contains(&p) => Ok(Self::Percent(p)),
+                Ok(p) => Err(format!("Percentage {}% out of range (1-100%)", p)),
+                Err(_) => Err(invalid()),
+            };
+        }
+        if let Some(offset) = lower.strip_prefix('-') {
+            return offset
+                .trim()
+                .parse()
+                .map(Self::Minus)
+                .map_err(|_| invalid());
+        }
+        lower.parse().map(Self::Cells).map_err(|_| invalid())
+    }
+
+    /// Resolve against the available space; `None` means size to content
+    pub fn resolve(&self, available: usize) -> Option<usize> {
+        match self {
+            Self::Auto => None,
+            Self::Cells(n) => Some(*n),
+            Self::Percent(p) => Some(available * p / 100),
+            Self::Full => Some(available),
+            Self::Minus(n) => Some(available.saturating_sub(*n)),
+            Self::MinOf(specs) => specs.iter().filter_map(|s| s.resolve(available)).min(),
+            Self::MaxOf(specs) => specs.iter().filter_map(|s| s.resolve(available)).max(),
+        }
+    }
+
+    /// Resolve, clamping relative results to `minimum..=available` and every
+    /// result to `maximum`
+    ///
+    /// Absolute sizes are otherwise returned as given: an explicit `--width 200`
+    /// may deliberately be wider than the terminal (e.g. when writing to a file).
+    pub fn resolve_within(
+        &self,
+        available: usize,
+        minimum: usize,
+        maximum: usize,
+    ) -> Option<usize> {
+        let size = self.resolve(available)?;
+        let size = match self.is_relative() {
+            true => size.clamp(minimum, available.max(minimum)),
+            false => size,
+        };
+        Some(size.min(maximum))
+    }
+
+    /// Whether the result depends on the available space
+    pub fn is_relative(&self) -> bool {
+        match self {
+            Self::Auto | Self::Cells(_) => false,
+            Self::Percent(_) | Self::Full | Self::Minus(_) => true,
+            Self::MinOf(specs) | Self::MaxOf(specs) => specs.iter().any(Self::is_relative),
+        }
+    }
+}
+
+impl From<usize> for SizeSpec {
+    fn from(cells: usize) -> Self {
+        Self::Cells(cells)
+    }
+}
+
+impl std::str::FromStr for SizeSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for SizeSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |specs: &[SizeSpec]| {
+            specs
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::Cells(n) => write!(f, "{}", n),
+            Self::Percent(p) => write!(f, "{}%", p),
+            Self::Full => write!(f, "max"),
+            Self::Minus(n) => write!(f, "-{}", n),
+            Self::MinOf(specs) => write!(f, "min({})", list(specs)),
+            Self::MaxOf(specs) => write!(f, "max({})", list(specs)),
+        }
+    }
+}
+
+/// Split `a,b,min(c,d)` on top-level commas
+fn split_arguments(inner: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, ch) in inner.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&inner[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&inner[start..]);
+    parts
+}
+
+/// Resolve a width spec against the terminal width
+pub fn resolve_width(spec: &SizeSpec) -> Result<Option<usize>, String> {
+    resolve_checked(spec, get_terminal_width(), (MIN_WIDTH, MAX_WIDTH), "Width")
+}
+
+/// Resolve a height spec against the safe terminal height (room for the prompt)
+pub fn resolve_height(spec: &SizeSpec) -> Result<Option<usize>, String> {
+    resolve_checked(
+        spec,
+        get_max_safe_height(),
+        (MIN_HEIGHT, MAX_HEIGHT),
+        "Height",
+    )
+}
+
+fn resolve_checked(
+    spec: &SizeSpec,
+    available: usize,
+    (minimum, maximum): (usize, usize),
+    what: &str,
+) -> Result<Option<usize>, String> {
+    match spec.resolve_within(available, minimum, usize::MAX) {
+        Some(size) if size < minimum => Err(format!(
+            "{} {} is below the minimum of {}",
+            what, size, minimum
+        )),
+        Some(size) if size > maximum => Err(format!(
+            "{} {} is above the maximum of {}",
+            what, size, maximum
+        )),
+        resolved => Ok(resolved),
+    }
+}
+
+/// YAML form of a spec: a plain number or a string expression
+#[cfg(feature = "cli")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum RawSize {
+    Number(i64),
+    Text(String),
+}
+
+#[cfg(feature = "cli")]
+impl TryFrom<RawSize> for SizeSpec {
+    type Error = String;
+
+    fn try_from(raw: RawSize) -> Result<Self, Self::Error> {
+        match raw {
+            RawSize::Number(n) if n < 0 => Ok(Self::Minus(n.unsigned_abs() as usize)),
+            RawSize::Number(n) => Ok(Self::Cells(n as usize)),
+            RawSize::Text(text) => Self::parse(&text),
+        }
+    }
+}
+
+#[cfg(feature = "cli")]
+impl From<SizeSpec> for RawSize {
+    fn from(spec: SizeSpec) -> Self {
+        match spec {
+            SizeSpec::Cells(n) => RawSize::Number(n as i64),
+            other => RawSize::Text(other.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_forms() {
+        assert_eq!(SizeSpec::parse("40"), Ok(SizeSpec::Cells(40)));
+        assert_eq!(SizeSpec::parse("50%"), Ok(SizeSpec::Percent(50)));
+        assert_eq!(SizeSpec::parse("MAX"), Ok(SizeSpec::Full));
+        assert_eq!(SizeSpec::parse("auto"), Ok(SizeSpec::Auto));
+        assert_eq!(SizeSpec::parse("-4"), Ok(SizeSpec::Minus(4)));
+        assert_eq!(
+            SizeSpec::parse("min(80, 100%)"),
+            Ok(SizeSpec::MinOf(vec![
+                SizeSpec::Cells(80),
+                SizeSpec::Percent(100)
+            ]))
+        );
+        assert!(SizeSpec::parse("150%").is_err());
+        assert!(SizeSpec::parse("wide").is_err());
+        assert!(SizeSpec::parse("min(80)").is_err());
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for spec in ["40", "50%", "max", "auto", "-4", "max(30,min(80,-2))"] {
+            assert_eq!(SizeSpec::parse(spec).unwrap().to_string(), spec);
+        }
+    }
+
+    #[test]
+    fn test_resolve_against_available() {
+        let resolve = |spec: &str, available| SizeSpec::parse(spec).unwrap().resolve(available);
+        assert_eq!(resolve("50%", 120), Some(60));
+        assert_eq!(resolve("max", 120), Some(120));
+        assert_eq!(resolve("-4", 120), Some(116));
+        assert_eq!(resolve("min(80,100%)", 120), Some(80));
+        assert_eq!(resolve("min(80,100%)", 60), Some(60));
+        assert_eq!(resolve("max(30,50%)", 40), Some(30));
+        assert_eq!(resolve("auto", 120), None);
+    }
+
+    #[test]
+    fn test_relative_sizes_clamp_to_available() {
+        let spec = SizeSpec::parse("-100").unwrap();
+        assert_eq!(
+            spec.resolve_within(80, MIN_WIDTH, MAX_WIDTH),
+            Some(MIN_WIDTH)
+        );
+        let spec = SizeSpec::parse("max(200,10%)").unwrap();
+        assert_eq!(spec.resolve_within(80, MIN_WIDTH, MAX_WIDTH), Some(80));
+        // Absolute sizes are taken as given, up to the maximum
+        assert_eq!(
+            SizeSpec::Cells(200).resolve_within(80, MIN_WIDTH, MAX_WIDTH),
+            Some(200)
+        );
+        assert_eq!(
+            SizeSpec::Cells(5000).resolve_within(80, MIN_WIDTH, MAX_WIDTH),
+            Some(MAX_WIDTH)
+        );
+        let bounds = (MIN_WIDTH, MAX_WIDTH);
+        assert!(resolve_checked(&SizeSpec::Cells(2), 80, bounds, "Width").is_err());
+    }
+
+    #[test]
+    fn test_absolute_sizes_past_the_maximum_are_rejected() {
+        let width = (MIN_WIDTH, MAX_WIDTH);
+        let height = (MIN_HEIGHT, MAX_HEIGHT);
+        assert_eq!(
+            resolve_checked(&SizeSpec::Cells(MAX_WIDTH), 80, width, "Width"),
+            Ok(Some(MAX_WIDTH))
+        );
+        assert_eq!(
+            resolve_checked(&SizeSpec::Cells(MAX_WIDTH + 1), 80, width, "Width"),
+            Err(format!(
+                "Width {} is above the maximum of {}",
+                MAX_WIDTH + 1,
+                MAX_WIDTH
+            ))
+        );
+        assert!(resolve_checked(&SizeSpec::Cells(usize::MAX), 40, height, "Height").is_err());
+        let spec = SizeSpec::parse("min(5000,100%)").unwrap();
+        assert_eq!(resolve_checked(&spec, 80, width, "Width"), Ok(Some(80)));
+    }
+}
@@ -0,0 +1,167 @@
+//! Spacing - per-side padding and margin
+//!
+//! Values follow CSS shorthand order, separated by spaces or commas:
+//!
+//! - `2` - all sides
+//! - `1 2` - top/bottom, left/right
+//! - `1 2 0` - top, left/right, bottom
+//! - `1 2 0 4` - top, right, bottom, left
+//!
+//! In theme YAML a bare number keeps its historical meaning of horizontal
+//! padding (`padding: 2` is `0 2`); quote a shorthand for anything else.
+
+use std::fmt;
+
+/// Cells on each side of a box (padding inside the border, margin outside)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "cli",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawSpacing", into = "RawSpacing")
+)]
+pub struct Spacing {
+    pub top: usize,
+    pub right: usize,
+    pub bottom: usize,
+    pub left: usize,
+}
+
+impl Spacing {
+    pub fn new(top: usize, right: usize, bottom: usize, left: usize) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// Same value on all four sides
+    pub fn uniform(cells: usize) -> Self {
+        Self::new(cells, cells, cells, cells)
+    }
+
+    /// Rows above/below and columns left/right
+    pub fn symmetric(vertical: usize, horizontal: usize) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+
+    /// Columns left and right only
+    pub fn horizontal(cells: usize) -> Self {
+        Self::symmetric(0, cells)
+    }
+
+    /// Parse CSS shorthand: `2`, `1 2`, `1 2 0` or `1 2 0 4`
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let values = spec
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                part.parse::<usize>()
+                    .map_err(|_| format!("Invalid spacing value '{}' in '{}'", part, spec))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match values[..] {
+            [all] => Ok(Self::uniform(all)),
+            [vertical, horizontal] => Ok(Self::symmetric(vertical, horizontal)),
+            [top, horizontal, bottom] => Ok(Self::new(top, horizontal, bottom, horizontal)),
+            [top, right, bottom, left] => Ok(Self::new(top, right, bottom, left)),
+            _ => Err(format!(
+                "Invalid spacing '{}'. Use 1-4 values: top right bottom left",
+                spec
+            )),
+        }
+    }
+
+    /// Columns taken up (left + right)
+    pub fn width(&self) -> usize {
+        self.left + self.right
+    }
+
+    /// Rows taken up (top + bottom)
+    pub fn height(&self) -> usize {
+        self.top + self.bottom
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.width() == 0 && self.height() == 0
+    }
+}
+
+impl fmt::Display for Spacing {
+    /// Shortest CSS shorthand for the four values
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            top,
+            right,
+            bottom,
+            left,
+        } = *self;
+        if left != right {
+            write!(f, "{} {} {} {}", top, right, bottom, left)
+        } else if top != bottom {
+            write!(f, "{} {} {}", top, right, bottom)
+        } else if top != right {
+            write!(f, "{} {}", top, right)
+        } else {
+            write!(f, "{}", top)
+        }
+    }
+}
+
+/// YAML form: a number (horizontal) or a shorthand string
+#[cfg(feature = "cli")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+enum RawSpacing {
+    Horizontal(usize),
+    Shorthand(String),
+}
+
+#[cfg(feature = "cli")]
+impl TryFrom<RawSpacing> for Spacing {
+    type Error = String;
+
+    fn try_from(raw: RawSpacing) -> Result<Self, Self::Error> {
+        match raw {
+            RawSpacing::Horizontal(cells) => Ok(Self::horizontal(cells)),
+            RawSpacing::Shorthand(spec) => Self::parse(&spec),
+        }
+    }
+}
+
+#[cfg(feature = "cli")]
+impl From<Spacing> for RawSpacing {
+    fn from(spacing: Spacing) -> Self {
+        match spacing.height() {
+            0 if spacing.left == spacing.right => RawSpacing::Horizontal(spacing.left),
+            _ => RawSpacing::Shorthand(spacing.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_css_shorthand() {
+        assert_eq!(Spacing::parse("2"), Ok(Spacing::uniform(2)));
+        assert_eq!(Spacing::parse("1 3"), Ok(Spacing::symmetric(1, 3)));
+        assert_eq!(Spacing::parse("1,2,0"), Ok(Spacing::new(1, 2, 0, 2)));
+        assert_eq!(Spacing::parse(" 1 2 3 4 "), Ok(Spacing::new(1, 2, 3, 4)));
+        assert!(Spacing::parse("1 2 3 4 5").is_err());
+        assert!(Spacing::parse("").is_err());
+        assert!(Spacing::parse("1 wide").is_err());
+    }
+
+    #[test]
+    fn test_display_is_shortest_shorthand() {
+        for spec in ["2", "1 3", "1 2 0", "1 2 3 4", "0"] {
+            assert_eq!(Spacing::parse(spec).unwrap().to_string(), spec);
+        }
+        let spacing = Spacing::new(1, 2, 3, 4);
+        assert_eq!((spacing.width(), spacing.height()), (6, 4));
+    }
+}
@@ -33,6 +33,8 @@ pub enum BodyAlignment {
     Left,
     Center,
     Right,
+    /// Stretch inter-word gaps so the line fills the full width
+    Justify,
 }
 
 impl Default for BodyAlignment {
@@ -46,11 +48,58 @@ impl From<&str> for BodyAlignment {
         match s.to_lowercase().as_str() {
             "center" => Self::Center,
             "right" => Self::Right,
+            "justify" => Self::Justify,
             _ => Self::Left,
         }
     }
 }
 
+/// How the body text is interpreted before layout
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum BodyMode {
+    /// Plain text with inline markers (`#C#`, `#NL#`, ...)
+    #[default]
+    Text,
+    /// Pre-rendered box: rows are kept whole and never wrapped
+    Nested,
+    /// Markdown: headings, lists, emphasis and fenced code blocks
+    Markdown,
+    /// Source code: indentation kept, tabs expanded, no word wrapping
+    Code(CodeConfig),
+    /// Tree: indented text, paths or JSON drawn with connectors
+    Tree(TreeConfig),
+    /// Side-by-side diff: old and new documents split by a `#DIFF#` line
+    Diff(DiffConfig),
+    /// Command output: kept literal like code, stderr in its own section
+    Run(RunConfig),
+}
+
+impl BodyAlignment {
+    /// Split a leading inline alignment marker (`#L#`, `#C#`, `#R#`, `#J#`) off a body line
+    ///
+    /// Markers work like the `#W#`/`#NL#` hints: they are consumed during rendering
+    /// and override the box-wide body alignment for that source line only.
+    pub fn split_marker(line: &str) -> (Option<Self>, &str) {
+        let align = match line.get(..3) {
+            Some("#L#") => Self::Left,
+            Some("#C#") => Self::Center,
+            Some("#R#") => Self::Right,
+            Some("#J#") => Self::Justify,
+            _ => return (None, line),
+        };
+        (Some(align), line[3..].trim_start())
+    }
+}
+
+/// A body line tagged with the alignment it should be rendered with
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlignedLine {
+    pub text: String,
+    pub align: BodyAlignment,
+    /// Last fragment of its source line; justified paragraphs leave it ragged
+    pub paragraph_end: bool,
+}
+
 /// Width configuration for box sizing
 #[derive(Debug, Clone)]
 pub struct WidthConfig {
@@ -59,6 +108,14 @@ pub struct WidthConfig {
     #[allow(dead_code)] // Future feature: vertical padding support
     pub v_padding: usize,
     pub enable_wrapping: bool,
+    /// Lower bound on an auto-sized box (theme `settings.min_width`)
+    pub min_width: Option<usize>,
+    /// Upper bound on an auto-sized box (theme `settings.max_width`)
+    pub max_width: Option<usize>,
+    /// Fit the box to the terminal width with this policy (`None`: no limit)
+    pub overflow: Option<super::OverflowPolicy>,
+    /// Per-side body padding; overrides `h_padding`/`v_padding` when set
+    pub body_padding: Option<super::Spacing>,
 }
 
 impl Default for WidthConfig {
@@ -68,6 +125,10 @@ impl Default for WidthConfig {
             h_padding: 1,
             v_padding: 1,
             enable_wrapping: false,
+            min_width: None,
+            max_width: None,
+            overflow: None,
+            body_padding: None,
         }
     }
 }
@@ -83,6 +144,10 @@ pub struct BoxColors {
     pub header_color: Option<String>,
     #[allow(dead_code)] // Future feature: footer color customization
     pub footer_color: Option<String>,
+    /// Per-side and divider overrides of `box_color`
+    pub edges: EdgeColors,
+    /// Changed lines in a side-by-side diff body
+    pub diff: DiffColors,
 }
 
 impl Default for BoxColors {
@@ -94,6 +159,94 @@ impl Default for BoxColors {
             status_color: None,
             header_color: None,
             footer_color: None,
+            edges: EdgeColors::default(),
+            diff: DiffColors::default(),
+        }
+    }
+}
+
+/// Border colors for individual frame edges; unset edges use the border color
+///
+/// Corners take the color of their left/right edge when one is set, so an
+/// accent stripe runs the full height of the box.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "cli",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct EdgeColors {
+    pub top: Option<String>,
+    pub right: Option<String>,
+    pub bottom: Option<String>,
+    pub left: Option<String>,
+    /// Divider rules inside the box (title and status dividers, body rules)
+    pub divider: Option<String>,
+}
+
+impl EdgeColors {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Fill edges left unset here from `fallback`
+    pub fn or(self, fallback: Self) -> Self {
+        Self {
+            top: self.top.or(fallback.top),
+            right: self.right.or(fallback.right),
+            bottom: self.bottom.or(fallback.bottom),
+            left: self.left.or(fallback.left),
+            divider: self.divider.or(fallback.divider),
+        }
+    }
+
+    /// Colors that are set, for validation
+    pub fn names(&self) -> impl Iterator<Item = &String> {
+        [
+            &self.top,
+            &self.right,
+            &self.bottom,
+            &self.left,
+            &self.divider,
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+/// Text colors of the lines that differ in a side-by-side diff
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "cli",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct DiffColors {
+    /// Lines only in the new document (right pane)
+    pub added: String,
+    /// Lines only in the old document (left pane)
+    pub removed: String,
+    /// Lines replaced by another, colored in both panes
+    pub changed: String,
+}
+
+impl Default for DiffColors {
+    fn default() -> Self {
+        Self {
+            added: "green".to_string(),
+            removed: "red".to_string(),
+            changed: "yellow".to_string(),
+        }
+    }
+}
+
+impl DiffColors {
+    /// No colors: changes are shown by their markers only
+    pub fn none() -> Self {
+        Self {
+            added: "none".to_string(),
+            removed: "none".to_string(),
+            changed: "none".to_string(),
         }
     }
 }
@@ -105,6 +258,12 @@ pub struct DividerConfig {
     pub divider_before_status: bool,
     pub pad_after_title_divider: bool,
     pub pad_before_status_divider: bool,
+    /// Label alignment of `#DIV:Label#` section dividers: left, center, right
+    pub section_align: String,
+    /// Decoration around section labels: plain, spaced, bracket
+    pub section_decoration: String,
+    /// Weight or pattern of dividers inside the box: frame, light, heavy, double, dashed, dotted
+    pub rule_style: String,
 }
 
 impl Default for DividerConfig {
@@ -114,6 +273,9 @@ impl Default for DividerConfig {
             divider_before_status: false,
             pad_after_title_divider: false,
             pad_before_status_divider: false,
+            section_align: "left".to_string(),
+            section_decoration: "spaced".to_string(),
+            rule_style: "frame".to_string(),
         }
     }
 }
@@ -150,6 +312,8 @@ pub struct AlignmentConfig {
     pub header_align: String,
     pub footer_align: String,
     pub status_align_override: Option<String>,
+    /// Vertical placement of body content in fixed-height boxes: top, middle, bottom
+    pub vertical_align: String,
 }
 
 impl Default for AlignmentConfig {
@@ -158,10 +322,108 @@ impl Default for AlignmentConfig {
             header_align: "left".to_string(),
             footer_align: "left".to_string(),
             status_align_override: None,
+            vertical_align: "top".to_string(),
         }
     }
 }
 
+/// Embedded border label configuration (header/footer text drawn inside border lines)
+#[derive(Debug, Clone)]
+pub struct BorderLabelConfig {
+    /// Decoration around the label: plain, spaced, bracket
+    pub decoration: String,
+    /// Border glyphs kept between the corner and a left/right placed label
+    pub inset: usize,
+    /// Render the title in the top border instead of as the first body line
+    pub embed_title: bool,
+}
+
+impl Default for BorderLabelConfig {
+    fn default() -> Self {
+        Self {
+            decoration: "plain".to_string(),
+            inset: 0,
+            embed_title: false,
+        }
+    }
+}
+
+/// Drop shadow configuration (offset one column right, one row down)
+#[derive(Debug, Clone)]
+pub struct ShadowConfig {
+    /// Single-column glyph the shadow is drawn with
+    pub glyph: String,
+    /// Optional shadow color name
+    pub color: Option<String>,
+}
+
+impl Default for ShadowConfig {
+    fn default() -> Self {
+        Self {
+            glyph: "░".to_string(),
+            color: None,
+        }
+    }
+}
+
+/// Code body configuration (`--code [lang]`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeConfig {
+    /// Language label, shown in the header when no header is set
+    pub lang: Option<String>,
+    /// Columns per tab stop
+    pub tab_width: usize,
+    /// Number lines in a gutter left of the code
+    pub line_numbers: bool,
+}
+
+impl Default for CodeConfig {
+    fn default() -> Self {
+        Self {
+            lang: None,
+            tab_width: 4,
+            line_numbers: false,
+        }
+    }
+}
+
+/// Tree body configuration (`--tree [format]`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeConfig {
+    /// Input format: auto, indent, paths, json
+    pub format: String,
+    /// Collapse nodes deeper than this (roots are depth 0)
+    pub max_depth: Option<usize>,
+}
+
+impl Default for TreeConfig {
+    fn default() -> Self {
+        Self {
+            format: "auto".to_string(),
+            max_depth: None,
+        }
+    }
+}
+
+/// Diff body configuration (`boxy diff OLD NEW`, `--diff`)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DiffConfig {
+    /// Pane titles, e.g. the file names
+    pub titles: Option<(String, String)>,
+    /// Show each line's number in its pane
+    pub line_numbers: bool,
+    /// Old and new documents; unset, they come from the body text split at a `#DIFF#` line
+    pub documents: Option<(String, String)>,
+}
+
+/// Command output body (`boxy run`)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunConfig {
+    pub stdout: String,
+    /// Shown below a `stderr` divider, or alone when there is no stdout
+    pub stderr: String,
+}
+
 /// Main configuration struct that replaces the 28-parameter draw_box function
 #[derive(Debug, Clone)]
 pub struct BoxyConfig {
@@ -174,10 +436,10 @@ pub struct BoxyConfig {
     pub icon: Option<String>,
 
     // Layout
-    #[allow(dead_code)] // Future feature: body text alignment
     pub body_align: BodyAlignment,
-    #[allow(dead_code)] // Future feature: emoji padding in body text
+    /// Indent body lines after the icon line so they align with the title text
     pub body_pad_emoji: bool,
+    pub body_mode: BodyMode,
 
     // Styling
     pub style: BoxStyle,
@@ -191,6 +453,12 @@ pub struct BoxyConfig {
     // Advanced layout
     pub dividers: DividerConfig,
     pub alignment: AlignmentConfig,
+    pub border_labels: BorderLabelConfig,
+    pub shadow: Option<ShadowConfig>,
+    /// Blank space around the box
+    pub margin: super::Spacing,
+    /// Frame edges to draw
+    pub borders: super::BorderSides,
 }
 
 impl Default for BoxyConfig {
@@ -204,6 +472,7 @@ impl Default for BoxyConfig {
             icon: None,
             body_align: BodyAlignment::default(),
             body_pad_emoji: false,
+            body_mode: BodyMode::default(),
             style: BoxStyle::default(),
             colors: BoxColors::default(),
             width: WidthConfig::default(),
@@ -211,6 +480,10 @@ impl Default for BoxyConfig {
             padding: PaddingConfig::default(),
             dividers: DividerConfig::default(),
             alignment: AlignmentConfig::default(),
+            border_labels: BorderLabelConfig::default(),
+            shadow: None,
+            margin: super::Spacing::default(),
+            borders: super::BorderSides::ALL,
         }
     }
 }
@@ -281,6 +554,7 @@ pub fn resolve_box_config(
         icon: icon.map(|s| s.to_string()),
         body_align: BodyAlignment::from(body_align),
         body_pad_emoji,
+        body_mode: BodyMode::default(),
         style: *style,
         colors: BoxColors {
             box_color: color.to_string(),
@@ -289,12 +563,18 @@ pub fn resolve_box_config(
             status_color: status_color_name.map(|s| s.to_string()),
             header_color: header_color.map(|s| s.to_string()),
             footer_color: footer_color.map(|s| s.to_string()),
+            edges: EdgeColors::default(),
+            diff: DiffColors::default(),
         },
         width: WidthConfig {
             fixed_width,
             h_padding,
             v_padding,
             enable_wrapping,
+            min_width: None,
+            max_width: None,
+            overflow: None,
+            body_padding: None,
         },
         fixed_height,
         padding: PaddingConfig {
@@ -310,12 +590,18 @@ pub fn resolve_box_config(
             divider_before_status,
             pad_after_title_divider,
             pad_before_status_divider,
+            ..DividerConfig::default()
         },
         alignment: AlignmentConfig {
             header_align: header_align.to_string(),
             footer_align: footer_align.to_string(),
             status_align_override: status_align_override.map(|s| s.to_string()),
+            vertical_align: "top".to_string(),
         },
+        border_labels: BorderLabelConfig::default(),
+        shadow: None,
+        margin: super::Spacing::default(),
+        borders: super::BorderSides::ALL,
     }
 }
 
@@ -447,13 +733,15 @@ pub fn parse_content_stream(input: &str) -> Option<ParsedContent> {
 /// - #W# : Ideal wrap point if needed
 /// - #T# : Truncate everything before this point, wrap content after
 /// - #NL# : Explicit newline
+#[allow(dead_code)] // Library API; renderers wrap through split_aligned_lines
 pub fn wrap_text_at_word_boundaries(text: &str, max_width: usize) -> Vec<String> {
     if max_width == 0 {
         return vec![String::new()];
     }
 
-    // First, convert #NL# markers to actual newlines
-    let text_with_newlines = text.replace("#NL#", "\n");
+    // Control characters and tabs are resolved before anything is measured,
+    // then #NL# markers become actual newlines
+    let text_with_newlines = super::sanitize::sanitize(text).replace("#NL#", "\n");
 
     let mut lines = Vec::new();
 
@@ -471,6 +759,28 @@ pub fn wrap_text_at_word_boundaries(text: &str, max_width: usize) -> Vec<String>
     lines
 }
 
+/// Replace tabs with spaces up to the next multiple of `tab_width` display columns
+pub fn expand_tabs(text: &str, tab_width: usize) -> String {
+    if !text.contains('\t') {
+        return text.to_string();
+    }
+
+    let tab_width = tab_width.max(1);
+    let mut expanded = String::new();
+    let mut column = 0;
+    for ch in text.chars() {
+        if ch == '\t' {
+            let fill = tab_width - column % tab_width;
+            expanded.push_str(&" ".repeat(fill));
+            column += fill;
+        } else {
+            expanded.push(ch);
+            column += get_display_width(ch.encode_utf8(&mut [0; 4]));
+        }
+    }
+    expanded
+}
+
 pub fn truncate_with_ellipsis(text: &str, max_width: usize) -> String {
     if max_width == 0 {
         return String::new();
@@ -496,6 +806,12 @@ pub fn truncate_with_ellipsis(text: &str, max_width: usize) -> String {
     // Process character by character, but handle multi-codepoint sequences properly
     let mut chars = text.chars().peekable();
     while let Some(ch) = chars.next() {
+        // Escape sequences (colors, hyperlinks) are zero-width and never split
+        if ch == '\x1B' {
+            result.push_str(&read_escape_sequence(&mut chars));
+            continue;
+        }
+
         // Build the complete grapheme cluster (handle variation selectors, etc.)
         let mut grapheme = String::new();
         grapheme.push(ch);
@@ -518,7 +834,137 @@ pub fn truncate_with_ellipsis(text: &str, max_width: usize) -> String {
     }
 
     result.push_str(ELLIPSIS);
-    result
+    // A link cut short still has to be closed
+    super::hyperlink::balance_hyperlinks(vec![result]).remove(0)
+}
+
+/// Split body text into lines tagged with their alignment
+///
+/// Inline markers (see [`BodyAlignment::split_marker`]) are stripped and resolved
+/// against `default_align`. With `wrap_width` set, each source line is wrapped
+/// (honoring `#NL#`/`#W#`/`#T#` hints) and every fragment inherits its marker.
+pub fn split_aligned_lines(
+    text: &str,
+    default_align: &BodyAlignment,
+    wrap_width: Option<usize>,
+) -> Vec<AlignedLine> {
+    // Control characters and tabs are resolved before anything is measured
+    let text = super::sanitize::sanitize(text);
+    let source = match wrap_width {
+        Some(_) => text.replace("#NL#", "\n"),
+        None => text,
+    };
+
+    let mut lines = Vec::new();
+    for line in source.lines() {
+        let (marker, rest) = BodyAlignment::split_marker(line);
+        let align = marker.unwrap_or_else(|| default_align.clone());
+
+        let fragments = match wrap_width {
+            Some(0) => vec![String::new()],
+            Some(width) => super::hyperlink::balance_hyperlinks(
+                crate::core::helpers::wrap_single_line(rest, width),
+            ),
+            None => vec![rest.to_string()],
+        };
+
+        let last = fragments.len().saturating_sub(1);
+        for (i, fragment) in fragments.into_iter().enumerate() {
+            lines.push(AlignedLine {
+                text: fragment,
+                align: align.clone(),
+                paragraph_end: i == last,
+            });
+        }
+    }
+
+    if lines.is_empty() {
+        lines.push(AlignedLine {
+            text: String::new(),
+            align: default_align.clone(),
+            paragraph_end: true,
+        });
+    }
+
+    lines
+}
+
+/// Icon leading a line (`✅ Done` → `✅`): a first word of symbols, no letters or digits
+pub fn leading_icon(line: &str) -> Option<&str> {
+    let (head, _) = line.split_once(' ')?;
+    let is_icon = !head.is_ascii() && !head.chars().any(char::is_alphanumeric);
+    is_icon.then_some(head)
+}
+
+/// Like [`split_aligned_lines`], for a body whose first line leads with `icon`
+///
+/// Emoji padding indents every line after the first past the icon, so the text
+/// after the icon is wrapped to `wrap_width` (the width left beside the indent)
+/// and the icon is put back in front of the first line.
+pub fn split_after_icon(
+    text: &str,
+    icon: &str,
+    default_align: &BodyAlignment,
+    wrap_width: Option<usize>,
+) -> Vec<AlignedLine> {
+    let rest = text
+        .strip_prefix(icon)
+        .unwrap_or(text)
+        .trim_start_matches(' ');
+    let mut lines = split_aligned_lines(rest, default_align, wrap_width);
+    lines[0].text = format!("{} {}", icon, lines[0].text);
+    lines
+}
+
+/// Compute the (leading, content, trailing) parts that fill `width` columns
+///
+/// Text wider than `width` is returned untouched; callers truncate first.
+/// Justified lines fall back to left alignment when they end a paragraph or
+/// contain a single word.
+pub fn align_line_parts(
+    text: &str,
+    width: usize,
+    align: &BodyAlignment,
+    paragraph_end: bool,
+) -> (String, String, String) {
+    let text_width = get_display_width(text);
+    let slack = width.saturating_sub(text_width);
+
+    match align {
+        BodyAlignment::Left => (String::new(), text.to_string(), " ".repeat(slack)),
+        BodyAlignment::Center => {
+            let left = slack / 2;
+            (" ".repeat(left), text.to_string(), " ".repeat(slack - left))
+        }
+        BodyAlignment::Right => (" ".repeat(slack), text.to_string(), String::new()),
+        BodyAlignment::Justify => {
+            let words: Vec<&str> = text.split_whitespace().collect();
+            if paragraph_end || words.len() < 2 {
+                return align_line_parts(text, width, &BodyAlignment::Left, true);
+            }
+
+            let words_width: usize = words.iter().map(|w| get_display_width(w)).sum();
+            let gaps = words.len() - 1;
+            let space = width.saturating_sub(words_width).max(gaps);
+            let (base, extra) = (space / gaps, space % gaps);
+
+            let mut justified = String::new();
+            for (i, word) in words.iter().enumerate() {
+                if i > 0 {
+                    justified.push_str(&" ".repeat(base + usize::from(i <= extra)));
+                }
+                justified.push_str(word);
+            }
+            let trailing = " ".repeat(width.saturating_sub(get_display_width(&justified)));
+            (String::new(), justified, trailing)
+        }
+    }
+}
+
+/// Pad `text` to exactly `width` columns using the given alignment
+pub fn align_line(text: &str, width: usize, align: &BodyAlignment, paragraph_end: bool) -> String {
+    let (leading, content, trailing) = align_line_parts(text, width, align, paragraph_end);
+    format!("{}{}{}", leading, content, trailing)
 }
 
 /// CRITICAL: Enhanced title/footer rendering with auto-detection and formatting of icons
@@ -635,6 +1081,14 @@ pub fn show_comprehensive_help_with_header(jynx: &JynxPlugin, show_header: bool)
     println!("  echo \"content\" | {} [OPTIONS]", NAME);
     println!("  command | {} --theme <theme>", NAME);
     println!("  {} theme <action> [args]        # Theme management", NAME);
+    println!(
+        "  {} diff <old> <new> [OPTIONS]   # Side-by-side diff box",
+        NAME
+    );
+    println!(
+        "  {} run [OPTIONS] -- <cmd>       # Box a command's output; exits with its code",
+        NAME
+    );
     println!();
 
     // =============== CORE OPTIONS ===============
@@ -643,7 +1097,18 @@ pub fn show_comprehensive_help_with_header(jynx: &JynxPlugin, show_header: bool)
     println!("    -s, --style <STYLE>        Border style: normal, rounded, double, heavy, ascii");
     println!("    -c, --color <COLOR>        Border color from 90+ palette (see --colors)");
     println!("    --text <COLOR>             Text color: 'auto' matches border, 'none' default");
-    println!("    -w, --width <WIDTH|max|auto>  Set width: number, 'max' (terminal), or 'auto'");
+    println!(
+        "    -w, --width <SIZE>         Width: N, N%, max, -N (terminal minus N), auto, min(a,b)"
+    );
+    println!("    --height <SIZE>            Height: same forms, relative to the terminal height");
+    println!(
+        "    --padding <T R B L>        Body padding, CSS shorthand: 2 | \"1 2\" | \"1 2 0 4\""
+    );
+    println!("    --margin <T R B L>         Blank space around the box, same shorthand");
+    println!("    --borders <SIDES>          Edges to draw: t r b l letters (tb, l), all, none");
+    println!(
+        "    --overflow <POLICY>        Wider than the terminal: wrap (default), truncate, shrink, error"
+    );
     println!();
 
     println!("  {}Content & Layout:{}", get_color_code("cyan"), RESET);
@@ -651,11 +1116,55 @@ pub fn show_comprehensive_help_with_header(jynx: &JynxPlugin, show_header: bool)
     println!("    --title <TEXT>             Title line (first in-box line; emoji-aware icon)");
     println!("    --footer <TEXT>            Footer text (inside bottom border)");
     println!("    --icon <ICON>              Add icon to content (deprecated - use --title)"); //??
-    println!("    --status <TEXT>            Status line inside box (use sl:|sc:|sr: prefixes)");
     println!(
-        "    --layout <spec>            Align/divide/pad: hl|hc|hr, fl|fc|fr, sl|sc|sr, dt|dtn, ds|dsn, stn|ptn|psn|ssn, bl|bc|br, bp"
+        "    --status <TEXT>            Status line inside box (sl:|sc:|sr: prefixes, | joins zones)"
+    );
+    println!(
+        "    --layout <spec>            Align/divide/pad: hl|hc|hr, fl|fc|fr, sl|sc|sr, dt|dtn, ds|dsn, dl|dc|dr, stn|ptn|psn|ssn, bl|bc|br|bj, bp, vt|vm|vb, te"
     );
     println!("    --pad <a|b>               Blank line above (a) and/or below (b) the body");
+    println!(
+        "    --label-style <STYLE>      Border/section label decoration: plain, spaced, bracket"
+    );
+    println!("    --label-inset <N>          Border glyphs between corner and left/right label");
+    println!(
+        "    --divider-style <STYLE>    Inner dividers: frame, light, heavy, double, dashed, dotted"
+    );
+    println!(
+        "    --nested                   Treat stdin as a rendered box and wrap another box around it"
+    );
+    println!(
+        "    --code [LANG|=LANG]        Render the body as code: indentation kept, no word wrap"
+    );
+    println!("    --line-numbers             Line-number gutter for --code and --diff");
+    println!("    --tab-width <N>            Tab stop for input tabs and --code (default 4)");
+    println!(
+        "    --controls <MODE>          Control characters in input: strip (default), show (␛), caret (^[)"
+    );
+    println!(
+        "    --debug-input              Report tab, \\r and control-character fixes on stderr"
+    );
+    println!(
+        "    --tree [FORMAT]            Render the body as a tree: auto, indent, paths or json"
+    );
+    println!("    --tree-depth <N>           Collapse tree nodes deeper than N into a (+count)");
+    println!(
+        "    --diff                     Side-by-side diff of two stdin documents split by #DIFF#"
+    );
+    println!("    --interleave               boxy run: stdout and stderr in the order written");
+    println!(
+        "    --markdown                 Render the body as markdown (headings, lists, code blocks)"
+    );
+    println!(
+        "    --reflow                   Re-render a boxy box from stdin (new width, style or theme)"
+    );
+    println!("    --shadow                   Drop shadow right of and below the box");
+    println!("    --shadow-glyph <CHAR>      Shadow glyph (default ░, implies --shadow)");
+    println!("    --shadow-color <COLOR>     Shadow color (implies --shadow)");
+    println!(
+        "    --border-<SIDE>-color <C>  Color one edge: top, right, bottom or left (accent stripe)"
+    );
+    println!("    --divider-color <COLOR>    Color for divider rules inside the box");
     println!("    --title-color <COLOR>      Color for title line (overrides --text)");
     println!("    --status-color <COLOR>     Color for status line (overrides --text)");
     println!("    --header-color <COLOR>     Color for header line");
@@ -670,7 +1179,20 @@ pub fn show_comprehensive_help_with_header(jynx: &JynxPlugin, show_header: bool)
     println!("  {}Utility:{}", get_color_code("cyan"), RESET);
     println!("    --no-boxy[=strict]         Strip box decoration (strict removes all formatting)");
     println!("    --no-color                 Disable jynx integration and color output");
+    println!("    --no-color=urls            Same, and spell hyperlinks out as 'text (url)'");
+    println!(
+        "    --profile <NAME>           Paste-safe output: markdown (fenced, plain) or ascii-strict"
+    );
+    println!("    --output=<html|svg>        Export the colored box as HTML or SVG markup");
+    println!("    --font <FAMILY>            Font family for --output (default: system monospace)");
+    println!(
+        "    --font-size <PX>           Font size for --output (default 14; cells scale with it)"
+    );
+    println!("    --cell-size <WxH>          Cell size in px for --output=svg, e.g. 9x18");
     println!("    width                      Show terminal width diagnostics");
+    println!(
+        "    width calibrate            Measure glyph widths in this terminal (--dry-run, --reset)"
+    );
     println!(
         "    --params <stream>          Param stream: k='v'; pairs (hd, tl, st, ft, ic). Body comes from stdin"
     );
@@ -739,6 +1261,9 @@ pub fn show_comprehensive_help_with_header(jynx: &JynxPlugin, show_header: bool)
         NAME
     );
     println!("    Env: BOXY_THEME=<name>      Set default theme (overridden by --theme)");
+    println!(
+        "    Env: BOXY_OVERFLOW=<policy> Default overflow policy (overridden by theme and --overflow)"
+    );
     println!();
 
     // =============== NEW IN V0.6 =============== //TODO:CLEANUP
@@ -765,6 +1290,10 @@ pub fn show_comprehensive_help_with_header(jynx: &JynxPlugin, show_header: bool)
     println!("    --status \"sl:Left\"        Left-aligned status");
     println!("    --status \"sc:Center\"      Center-aligned status");
     println!("    --status \"sr:Right\"       Right-aligned status");
+    println!("    --status \"sl:main|sc,red:3 errors|sr:12:04\"");
+    println!(
+        "                            Zones on one line; ,color and ,priority (0-255) per segment"
+    );
     println!();
 
     println!("  {}Rich Color Palette:{}", get_color_code("cyan"), RESET);
@@ -8,25 +8,24 @@ use crate::{Command, File, Stdio};
 /// Validate height input string
 ///
 /// # Arguments
-/// * `height_str` - String representation of desired height
+/// * `height_str` - Line count or spec (`50%`, `max`, `-4`, `min(20,100%)`)
 ///
 /// # Returns
-/// * `Ok(())` if height is valid (5-50 lines range)
+/// * `Ok(())` if height resolves to at least `MIN_HEIGHT` lines; relative
+///   specs are bounded by the safe terminal height
 /// * `Err(String)` with descriptive error message if invalid
 ///
 /// # Examples
 /// ```
 /// use boxy::height_plugin::validate_height;
 /// assert!(validate_height("20").is_ok());
+/// assert!(validate_height("50%").is_ok());
 /// assert!(validate_height("3").is_err());  // Too small
-/// assert!(validate_height("100").is_err()); // Too large
+/// assert!(validate_height("tall").is_err());
 /// ```
 pub fn validate_height(height_str: &str) -> Result<(), String> {
-    match height_str.parse::<usize>() {
-        Ok(h) if h >= 5 && h <= 50 => Ok(()),
-        Ok(h) => Err(format!("Height {} out of range (5-50)", h)),
-        Err(_) => Err("Height must be a number".to_string()),
-    }
+    let spec = crate::core::SizeSpec::parse(height_str)?;
+    crate::core::resolve_height(&spec).map(|_| ())
 }
 
 /// Handle the height diagnostics subcommand
@@ -16,7 +16,7 @@
 //! - Core API: geometry, layout, theming, room_runtime
 //! - Colors: 112-color naming system
 //! - Visual: Box styles and rendering
-//! - Plugins: width_plugin, height_plugin (needed by API)
+//! - Plugins: width_plugin, width_calibration, height_plugin (needed by API)
 //!
 //! Excludes CLI bloat:
 //! - Argument parsing, help text, stream parsing
@@ -28,6 +28,7 @@ pub mod colors;
 pub mod core;
 pub mod height_plugin;
 pub mod visual;
+pub mod width_calibration;
 pub mod width_plugin;
 
 #[cfg(feature = "cli")]
@@ -35,17 +36,26 @@ pub mod plugins;
 
 // Import colors module public API
 pub use colors::{
-    RESET, generate_color_help, get_color_categories, get_color_code, strip_ansi_codes,
-    validate_color,
+    BOLD, ITALIC, RESET, generate_color_help, get_color_categories, get_color_code,
+    read_escape_sequence, strip_ansi_codes, validate_color,
 };
 
 pub use core::{
-    AlignmentConfig, BodyAlignment, BoxColors, BoxyConfig, DESCRIPTION, DividerConfig, NAME,
-    PaddingConfig, ParsedContent, VERSION, WidthConfig, expand_variables, parse_content_stream,
-    render_title_or_footer, resolve_box_config, truncate_with_ellipsis, unescape_stream_value,
-    wrap_text_at_word_boundaries,
+    AlignedLine, AlignmentConfig, BodyAlignment, BodyMode, BorderLabelConfig, BoxColors,
+    BoxyConfig, CodeConfig, DESCRIPTION, DiffColors, DiffConfig, DividerConfig, EdgeColors, NAME,
+    PaddingConfig, ParsedContent, RunConfig, ShadowConfig, TreeConfig, VERSION, WidthConfig,
+    align_line, align_line_parts, expand_tabs, expand_variables, parse_content_stream,
+    render_title_or_footer, resolve_box_config, split_aligned_lines, truncate_with_ellipsis,
+    unescape_stream_value, wrap_text_at_word_boundaries,
 };
 
+pub use core::{BorderSides, Overflow, OverflowPolicy, Spacing};
+pub use core::{ControlStyle, SanitizeOptions, SanitizeReport, sanitize, sanitize_text};
+pub use core::{
+    MAX_HEIGHT, MAX_WIDTH, MIN_HEIGHT, MIN_WIDTH, SizeSpec, resolve_height, resolve_width,
+};
+pub use core::{balance_hyperlinks, degrade_hyperlinks, expand_link_markup, hyperlink};
+
 #[cfg(feature = "cli")]
 pub use core::{show_comprehensive_help, show_comprehensive_help_with_header, show_logo, show_usage_examples};
 
@@ -60,6 +70,7 @@ pub use visual::{
     DASHED,
     DOT,
     DOUBLE,
+    DividerStyle,
     Footer,
     HEAVY,
     // Component system
@@ -47,6 +47,7 @@ fn config_colors_to_color_scheme(config: &BoxyConfig) -> ColorScheme {
         header_color: config.colors.header_color.clone(),
         footer_color: config.colors.footer_color.clone(),
         status_color: config.colors.status_color.clone(),
+        edges: config.colors.edges.clone(),
     }
 }
 
@@ -85,6 +86,7 @@ fn apply_proper_colors(layout: &BoxLayout, config: &BoxyConfig, is_themed: bool)
         header_color: config.colors.header_color.clone(),
         footer_color: config.colors.footer_color.clone(),
         status_color: config.colors.status_color.clone(),
+        edges: config.colors.edges.clone(),
     };
 
     // Get the plain rendered output
@@ -230,11 +232,12 @@ mod core;
 mod height_plugin;
 mod plugins;
 mod visual;
+mod width_calibration;
 mod width_plugin;
 
 use std::env;
 use std::fs::File;
-use std::io::{self, Read};
+use std::io::{self, IsTerminal, Read};
 use std::process::{Command, Stdio};
 
 use regex::Regex;
@@ -262,17 +265,23 @@ type AppError = String;
 
 fn main() {
     // RSB bootstrap pattern - delegate to application logic
-    match run_boxy_application() {
-        Ok(_) => {}
-        Err(e) => {
-            eprintln!("Application error: {}", e);
-            std::process::exit(1);
-        }
+    // `boxy run` exits with the command's code, even when boxing its output failed
+    let mut run_exit: Option<i32> = None;
+    let result = run_boxy_application(&mut run_exit);
+    if let Err(e) = &result {
+        eprintln!("Application error: {}", e);
+    }
+    match (run_exit, result) {
+        (Some(code), _) => std::process::exit(code),
+        (None, Err(_)) => std::process::exit(1),
+        (None, Ok(_)) => {}
     }
 }
 
-fn run_boxy_application() -> Result<(), AppError> {
-    let args: Vec<String> = env::args().collect();
+fn run_boxy_application(run_exit: &mut Option<i32>) -> Result<(), AppError> {
+    let mut args: Vec<String> = env::args().collect();
+    // Glyph widths measured by `boxy width calibrate` for this terminal
+    width_calibration::install_terminal_table();
 
     let mut style = &NORMAL;
     let mut style_from_cli = false;
@@ -289,6 +298,30 @@ fn run_boxy_application() -> Result<(), AppError> {
     let mut theme_name: Option<String> = None;
     let mut theme_from_env = false;
     let mut enable_wrapping = false;
+    let mut reflow = false;
+    let mut body_mode = BodyMode::Text;
+    let mut code_config: Option<CodeConfig> = None;
+    let mut tab_width: Option<usize> = None;
+    let mut line_numbers = false;
+    let mut tree_config: Option<TreeConfig> = None;
+    // --diff / `boxy diff OLD NEW`: the files (with their names as pane titles) stand in for stdin
+    let mut diff_config: Option<DiffConfig> = None;
+    // Body text produced by the `diff` and `run` subcommands instead of stdin
+    let mut subcommand_input: Option<String> = None;
+    // `boxy run`: the command's exit code, propagated once the box is printed
+    // `boxy run`: the command and --interleave, run once the options have been parsed
+    let mut pending_run: Option<(Vec<String>, bool)> = None;
+    let mut control_style = ControlStyle::Strip;
+    let mut debug_input = false;
+    let mut overflow_policy: Option<OverflowPolicy> = None;
+    // `--width N` is taken as given; relative specs already fit the terminal
+    let mut absolute_width = false;
+    // --padding/--margin: CSS shorthand, explicit flags win over the theme
+    let mut body_padding: Option<Spacing> = None;
+    let mut margin: Option<Spacing> = None;
+    let mut borders: Option<BorderSides> = None;
+    // --border-<side>-color/--divider-color: per-edge overrides of the border color
+    let mut edge_colors = EdgeColors::default();
 
     // Check for BOXY_THEME environment variable as default (overridden by --theme)
     if let Ok(env_theme) = env::var("BOXY_THEME") {
@@ -305,14 +338,29 @@ fn run_boxy_application() -> Result<(), AppError> {
     let mut header_align: &str = "center";
     let mut footer_align: &str = "center";
     let mut status_align_override: Option<String> = None;
-    let mut body_align: &str = "left"; //todo: missing implementation?
-    let mut body_pad_emoji = false; //todo: missing implementation?
+    let mut body_align: &str = "left";
+    let mut body_pad_emoji = false;
+    let mut vertical_align: Option<&str> = None;
+    let mut embed_title = false;
+    let mut label_style: Option<String> = None;
+    let mut label_inset: usize = 0;
+    let mut shadow: Option<ShadowConfig> = None;
+    // --output=html|svg: export the rendered box as markup
+    let mut output_format: Option<api::export::ExportFormat> = None;
+    let mut export_options = api::export::ExportOptions::new();
+    let mut cell_size: Option<(f32, f32)> = None;
+    // --profile markdown|ascii-strict: safe output for chat and issue trackers
+    let mut output_profile = api::profile::OutputProfile::default();
     let mut pad_body_above = false;
     let mut pad_body_below = false;
     let mut divider_after_title = false;
     let mut divider_before_status = false;
     let mut pad_after_title_divider = false;
     let mut pad_before_status_divider = false;
+    // Label alignment of #DIV:Label# section dividers (dl/dc/dr layout tokens)
+    let mut section_align: Option<&str> = None;
+    // --divider-style: weight/pattern of inner dividers, independent of --style
+    let mut divider_style: Option<String> = None;
     let mut pad_before_title = false;
     let mut pad_after_status = false;
     let mut pad_after_title = false;
@@ -321,6 +369,8 @@ fn run_boxy_application() -> Result<(), AppError> {
     let mut params_flag: Option<String> = None;
     // Deprecated suggestions removed in v0.6.x -> simplified migration help view only
     let mut no_color_requested = false;
+    // --no-color=urls: also spell hyperlinks out as `text (url)`
+    let mut plain_links = false;
 
     // PRIORITY 1: Handle subcommands first - these take absolute precedence over stdin
     // Subcommands should always execute regardless of piped input
@@ -329,6 +379,9 @@ fn run_boxy_application() -> Result<(), AppError> {
     for arg in args.iter().skip(1) {
         if arg == "--no-color" || arg == "--no-colour" {
             no_color_requested = true;
+        } else if arg == "--no-color=urls" || arg == "--no-colour=urls" {
+            no_color_requested = true;
+            plain_links = true;
         } else if arg.starts_with("--dev-level=") {
             let level_str = &arg[12..]; // Skip "--dev-level="
             match level_str.parse::<u8>() {
@@ -348,7 +401,58 @@ fn run_boxy_application() -> Result<(), AppError> {
         }
     }
 
+    // `boxy diff OLD NEW [options]` is `--diff` with both documents read from files
+    if args.len() >= 2 && args[1] == "diff" {
+        let paths: Vec<String> = args[2..]
+            .iter()
+            .take(2)
+            .take_while(|a| !a.starts_with('-'))
+            .cloned()
+            .collect();
+        let [old_path, new_path] = paths.as_slice() else {
+            eprintln!("Error: Usage: {} diff OLD NEW [options]", NAME);
+            return Err("diff needs two files".to_string());
+        };
+        let read = |path: &String| {
+            std::fs::read_to_string(path).map_err(|e| {
+                eprintln!("Error: Cannot read '{}': {}", path, e);
+                format!("Cannot read '{}': {}", path, e)
+            })
+        };
+        let (old, new) = (read(old_path)?, read(new_path)?);
+        // The files are the body; stdin is not read
+        subcommand_input = Some(String::new());
+        diff_config = Some(DiffConfig {
+            titles: Some((old_path.clone(), new_path.clone())),
+            documents: Some((
+                old.trim_end_matches('\n').to_string(),
+                new.trim_end_matches('\n').to_string(),
+            )),
+            ..DiffConfig::default()
+        });
+        args.splice(1..4, ["--diff".to_string()]);
+    }
+
+    // `boxy run [options] -- CMD ARGS...`: box the command's output, themed by its exit code
+    if args.len() >= 2 && args[1] == "run" {
+        let split = args.iter().position(|a| a == "--");
+        let Some(split) = split.filter(|&split| split + 1 < args.len()) else {
+            eprintln!("Error: Usage: {} run [options] -- COMMAND [ARGS...]", NAME);
+            return Err("run needs a command after --".to_string());
+        };
+        let command = args[split + 1..].to_vec();
+        let mut options: Vec<String> = args[2..split].to_vec();
+        let interleave = options.iter().any(|a| a == "--interleave");
+        options.retain(|a| a != "--interleave");
+
+        pending_run = Some((command, interleave));
+        args = std::iter::once(args[0].clone()).chain(options).collect();
+    }
+
     if args.len() >= 2 && args[1] == "width" {
+        if args.len() >= 3 && args[2] == "calibrate" {
+            return handle_width_calibrate(&args[3..]);
+        }
         handle_width_command();
         return Ok(());
     }
@@ -486,46 +590,31 @@ fn run_boxy_application() -> Result<(), AppError> {
             }
             "--width" | "-w" => {
                 if i + 1 < args.len() {
-                    let warg = &args[i + 1];
-                    if warg.eq_ignore_ascii_case("max") {
-                        fixed_width = Some(get_terminal_width());
-                        skip_next = true;
-                    } else if warg.eq_ignore_ascii_case("auto") {
-                        fixed_width = None; // let auto-sizing decide
-                        skip_next = true;
-                    } else {
-                        match warg.parse::<usize>() {
-                            Ok(w) if w >= 4 => {
-                                fixed_width = Some(w);
-                                skip_next = true;
-                            }
-                            _ => {
-                                eprintln!("Error: Width must be <number>=4, or 'max'/'auto'");
-                                return Err("Invalid width specification".to_string());
-                            }
+                    // N, N%, max, auto, -N (terminal minus N), min(..)/max(..)
+                    let spec = SizeSpec::parse(&args[i + 1]);
+                    absolute_width = matches!(spec, Ok(SizeSpec::Cells(_)));
+                    match spec.and_then(|spec| resolve_width(&spec)) {
+                        Ok(width) => {
+                            fixed_width = width;
+                            skip_next = true;
+                        }
+                        Err(error_msg) => {
+                            eprintln!("Error: {}", error_msg);
+                            return Err("Invalid width specification".to_string());
                         }
                     }
                 }
             }
             "--height" => {
                 if i + 1 < args.len() {
-                    let harg = &args[i + 1];
-                    if harg.eq_ignore_ascii_case("max") {
-                        fixed_height = Some(get_max_safe_height());
-                        skip_next = true;
-                    } else if harg.eq_ignore_ascii_case("auto") {
-                        fixed_height = None; // let auto-sizing decide
-                        skip_next = true;
-                    } else {
-                        match validate_height(harg) {
-                            Ok(_) => {
-                                fixed_height = Some(harg.parse::<usize>().unwrap());
-                                skip_next = true;
-                            }
-                            Err(error_msg) => {
-                                eprintln!("Error: {}", error_msg);
-                                return Err("Invalid height specification".to_string());
-                            }
+                    match SizeSpec::parse(&args[i + 1]).and_then(|spec| resolve_height(&spec)) {
+                        Ok(height) => {
+                            fixed_height = height;
+                            skip_next = true;
+                        }
+                        Err(error_msg) => {
+                            eprintln!("Error: {}", error_msg);
+                            return Err("Invalid height specification".to_string());
                         }
                     }
                 }
@@ -543,6 +632,206 @@ fn run_boxy_application() -> Result<(), AppError> {
             "--wrap" => {
                 enable_wrapping = true;
             }
+            "--reflow" => {
+                reflow = true;
+            }
+            "--nested" => {
+                body_mode = BodyMode::Nested;
+            }
+            "--markdown" => {
+                body_mode = BodyMode::Markdown;
+            }
+            arg if arg == "--code" || arg.starts_with("--code=") => {
+                // Optional language: `--code=LANG`, or the next argument unless
+                // it is another flag
+                let lang = match arg.strip_prefix("--code=") {
+                    Some(lang) => Some(lang.to_string()),
+                    None => {
+                        let next = args.get(i + 1).filter(|a| !a.starts_with('-'));
+                        skip_next = next.is_some();
+                        next.cloned()
+                    }
+                };
+                let code = code_config.get_or_insert_with(CodeConfig::default);
+                code.lang = lang.filter(|l| !l.is_empty());
+            }
+            "--tree" => {
+                // Optional format: the next argument when it names one
+                let format = args
+                    .get(i + 1)
+                    .filter(|a| api::tree::TreeFormat::from_name(a).is_ok());
+                if format.is_some() {
+                    skip_next = true;
+                }
+                let tree = tree_config.get_or_insert_with(TreeConfig::default);
+                tree.format = format.map_or("auto".to_string(), |f| f.to_lowercase());
+            }
+            "--diff" => {
+                diff_config.get_or_insert_with(DiffConfig::default);
+            }
+            "--tree-depth" => {
+                if i + 1 < args.len() {
+                    match args[i + 1].parse::<usize>() {
+                        Ok(n) => {
+                            tree_config
+                                .get_or_insert_with(TreeConfig::default)
+                                .max_depth = Some(n);
+                            skip_next = true;
+                        }
+                        Err(_) => {
+                            eprintln!("Error: --tree-depth must be a non-negative number");
+                            return Err(format!("Invalid tree depth: {}", args[i + 1]));
+                        }
+                    }
+                }
+            }
+            "--tab-width" => {
+                if i + 1 < args.len() {
+                    match args[i + 1].parse::<usize>() {
+                        Ok(n) if n >= 1 => {
+                            tab_width = Some(n);
+                            skip_next = true;
+                        }
+                        _ => {
+                            eprintln!("Error: --tab-width must be a number >= 1");
+                            return Err("Invalid tab width".to_string());
+                        }
+                    }
+                }
+            }
+            "--line-numbers" => {
+                line_numbers = true;
+            }
+            arg if arg.starts_with("--output") => {
+                let format = match arg.strip_prefix("--output=") {
+                    Some(format) => format,
+                    None if arg == "--output" && i + 1 < args.len() => {
+                        skip_next = true;
+                        args[i + 1].as_str()
+                    }
+                    None => {
+                        eprintln!("Unknown argument: {}", arg);
+                        return Err(format!("Unknown argument: {}", arg));
+                    }
+                };
+                match api::export::ExportFormat::from_name(format) {
+                    Ok(format) => output_format = Some(format),
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        return Err(e);
+                    }
+                }
+            }
+            "--profile" => {
+                if i + 1 < args.len() {
+                    match api::profile::OutputProfile::from_name(&args[i + 1]) {
+                        Ok(profile) => {
+                            output_profile = profile;
+                            skip_next = true;
+                        }
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            return Err(e);
+                        }
+                    }
+                }
+            }
+            "--overflow" => {
+                if i + 1 < args.len() {
+                    match OverflowPolicy::from_name(&args[i + 1]) {
+                        Ok(policy) => {
+                            overflow_policy = Some(policy);
+                            skip_next = true;
+                        }
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            return Err(e);
+                        }
+                    }
+                }
+            }
+            "--padding" | "--margin" => {
+                if i + 1 < args.len() {
+                    match Spacing::parse(&args[i + 1]) {
+                        Ok(spacing) if arg == "--padding" => body_padding = Some(spacing),
+                        Ok(spacing) => margin = Some(spacing),
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            return Err(e);
+                        }
+                    }
+                    skip_next = true;
+                }
+            }
+            "--borders" => {
+                if i + 1 < args.len() {
+                    match BorderSides::parse(&args[i + 1]) {
+                        Ok(sides) => borders = Some(sides),
+                        Err(e) => {
+                            eprintln!("Error: {}", e);
+                            return Err(e);
+                        }
+                    }
+                    skip_next = true;
+                }
+            }
+            "--font" => {
+                if i + 1 < args.len() {
+                    export_options = export_options.with_font(&args[i + 1]);
+                    skip_next = true;
+                }
+            }
+            "--font-size" => {
+                if i + 1 < args.len() {
+                    match args[i + 1].parse::<f32>() {
+                        Ok(size) if size > 0.0 => {
+                            export_options = export_options.with_font_size(size);
+                            skip_next = true;
+                        }
+                        _ => {
+                            eprintln!("Error: --font-size must be a positive number");
+                            return Err("Invalid font size".to_string());
+                        }
+                    }
+                }
+            }
+            "--cell-size" => {
+                if i + 1 < args.len() {
+                    let size = args[i + 1]
+                        .split_once('x')
+                        .and_then(|(w, h)| Some((w.parse::<f32>().ok()?, h.parse::<f32>().ok()?)));
+                    match size {
+                        Some((w, h)) if w > 0.0 && h > 0.0 => {
+                            cell_size = Some((w, h));
+                            skip_next = true;
+                        }
+                        _ => {
+                            eprintln!("Error: --cell-size must be WIDTHxHEIGHT in px, e.g. 9x18");
+                            return Err("Invalid cell size".to_string());
+                        }
+                    }
+                }
+            }
+            "--controls" => {
+                if i + 1 < args.len() {
+                    match args[i + 1].as_str() {
+                        "strip" | "show" | "caret" => {
+                            control_style = ControlStyle::from(args[i + 1].as_str());
+                            skip_next = true;
+                        }
+                        other => {
+                            eprintln!(
+                                "Error: --controls must be strip, show or caret (got '{}')",
+                                other
+                            );
+                            return Err("Invalid control character mode".to_string());
+                        }
+                    }
+                }
+            }
+            "--debug-input" => {
+                debug_input = true;
+            }
 
             "--title" => {
                 if i + 1 < args.len() {
@@ -586,6 +875,29 @@ fn run_boxy_application() -> Result<(), AppError> {
                     skip_next = true;
                 }
             }
+            "--border-top-color"
+            | "--border-right-color"
+            | "--border-bottom-color"
+            | "--border-left-color"
+            | "--divider-color" => {
+                if i + 1 < args.len() {
+                    let requested_color = &args[i + 1];
+                    if let Err(error_msg) = validate_color(requested_color) {
+                        eprintln!("Color Error: {}", error_msg);
+                        eprintln!("Use '{} --colors' to see all available colors", NAME);
+                        return Err(format!("Invalid color: {}", error_msg));
+                    }
+                    let edge = match arg.as_str() {
+                        "--border-top-color" => &mut edge_colors.top,
+                        "--border-right-color" => &mut edge_colors.right,
+                        "--border-bottom-color" => &mut edge_colors.bottom,
+                        "--border-left-color" => &mut edge_colors.left,
+                        _ => &mut edge_colors.divider,
+                    };
+                    *edge = Some(requested_color.clone());
+                    skip_next = true;
+                }
+            }
             "--title-color" => {
                 if i + 1 < args.len() {
                     let c = &args[i + 1];
@@ -639,9 +951,17 @@ fn run_boxy_application() -> Result<(), AppError> {
                             "bl" => body_align = "left",
                             "bc" => body_align = "center",
                             "br" => body_align = "right",
+                            "bj" => body_align = "justify",
                             "bp" => body_pad_emoji = true,
+                            "vt" => vertical_align = Some("top"),
+                            "vm" => vertical_align = Some("middle"),
+                            "vb" => vertical_align = Some("bottom"),
+                            "te" => embed_title = true,
                             "dt" => divider_after_title = true,
                             "ds" => divider_before_status = true,
+                            "dl" => section_align = Some("left"),
+                            "dc" => section_align = Some("center"),
+                            "dr" => section_align = Some("right"),
                             "dtn" => {
                                 divider_after_title = true;
                                 pad_after_title_divider = true;
@@ -668,6 +988,68 @@ fn run_boxy_application() -> Result<(), AppError> {
                     skip_next = true;
                 }
             }
+            "--shadow" => {
+                shadow.get_or_insert_with(ShadowConfig::default);
+            }
+            "--shadow-glyph" => {
+                if i + 1 < args.len() {
+                    let glyph = &args[i + 1];
+                    if get_display_width(glyph) != 1 {
+                        eprintln!("Error: Shadow glyph must be a single-column character");
+                        return Err(format!("Invalid shadow glyph: {}", glyph));
+                    }
+                    shadow.get_or_insert_with(ShadowConfig::default).glyph = glyph.clone();
+                    skip_next = true;
+                }
+            }
+            "--shadow-color" => {
+                if i + 1 < args.len() {
+                    let requested_color = &args[i + 1];
+                    if let Err(error_msg) = validate_color(requested_color) {
+                        eprintln!("Color Error: {}", error_msg);
+                        eprintln!("Use '{} --colors' to see all available colors", NAME);
+                        return Err(format!("Invalid color: {}", error_msg));
+                    }
+                    shadow.get_or_insert_with(ShadowConfig::default).color =
+                        Some(requested_color.clone());
+                    skip_next = true;
+                }
+            }
+            "--label-style" => {
+                if i + 1 < args.len() {
+                    let lstyle = args[i + 1].to_lowercase();
+                    if !matches!(lstyle.as_str(), "plain" | "spaced" | "bracket") {
+                        eprintln!("Error: Label style must be 'plain', 'spaced' or 'bracket'");
+                        return Err(format!("Invalid label style: {}", args[i + 1]));
+                    }
+                    label_style = Some(lstyle);
+                    skip_next = true;
+                }
+            }
+            "--divider-style" => {
+                if i + 1 < args.len() {
+                    if let Err(e) = DividerStyle::from_name(&args[i + 1]) {
+                        eprintln!("Error: {}", e);
+                        return Err(e);
+                    }
+                    divider_style = Some(args[i + 1].to_lowercase());
+                    skip_next = true;
+                }
+            }
+            "--label-inset" => {
+                if i + 1 < args.len() {
+                    match args[i + 1].parse::<usize>() {
+                        Ok(n) => {
+                            label_inset = n;
+                            skip_next = true;
+                        }
+                        Err(_) => {
+                            eprintln!("Error: Label inset must be a non-negative number");
+                            return Err(format!("Invalid label inset: {}", args[i + 1]));
+                        }
+                    }
+                }
+            }
             "--pad" => {
                 if i + 1 < args.len() {
                     for t in args[i + 1].split(',') {
@@ -686,7 +1068,7 @@ fn run_boxy_application() -> Result<(), AppError> {
                     strict_mode = true;
                 }
             }
-            "--no-color" | "--no-colour" => {
+            "--no-color" | "--no-colour" | "--no-color=urls" | "--no-colour=urls" => {
                 // Color handling already processed in pre-scan
             }
             _ => {
@@ -697,16 +1079,143 @@ fn run_boxy_application() -> Result<(), AppError> {
         }
     }
 
+    // Pasted profiles carry no escapes; links are spelled out so their URLs survive
+    if output_profile.strips_colors() {
+        no_color_requested = true;
+        plain_links = true;
+    }
+
     // PRIORITY 3: Read from stdin only if no subcommands were processed
     // At this point, all subcommands and utility flags (--help, --version, etc.) have been handled
     // This ensures clear precedence: subcommands > utility flags > stdin processing
+    let mut run_body: Option<RunConfig> = None;
+    if let Some((command, interleave)) = pending_run {
+        let run = plugins::run::run_command(&command, interleave);
+        // Explicit --theme, --status and --header/--title win over the run defaults
+        if theme_name.is_none() || theme_from_env {
+            let theme = if run.success() { "success" } else { "error" };
+            theme_name = Some(theme.to_string());
+            theme_from_env = false;
+        }
+        if status_bar.is_none() {
+            status_bar = Some(run.status());
+        }
+        if header.is_none() && title.is_none() {
+            header = Some(format!("$ {}", command.join(" ")));
+        }
+        // The output is the body; stdin went to the command
+        subcommand_input = Some(String::new());
+        *run_exit = Some(run.code);
+        run_body = Some(run.body());
+    }
+
     let mut input = String::new();
-    io::stdin()
-        .read_to_string(&mut input)
-        .expect("Failed to read input");
+    match subcommand_input {
+        Some(text) => input = text,
+        None => {
+            io::stdin()
+                .read_to_string(&mut input)
+                .expect("Failed to read input");
+        }
+    }
 
     let mut text = input.trim_end_matches('\n').to_string();
 
+    // Sanitize before anything measures the text: tabs, \r overwrites, control chars
+    let sanitize_options = SanitizeOptions {
+        tab_width: tab_width.unwrap_or(SanitizeOptions::default().tab_width),
+        controls: control_style,
+    };
+    let (clean, mut sanitize_report) = sanitize_text(&text, &sanitize_options);
+    text = clean;
+    for label in [&mut title, &mut header, &mut footer, &mut status_bar]
+        .into_iter()
+        .flatten()
+    {
+        *label = sanitize_text(label, &sanitize_options).0;
+    }
+
+    // Reflow: stdin is a previously rendered box; recovered parts fill in unset options
+    let mut reflowed: Option<BoxyConfig> = None;
+    if reflow {
+        let parsed = api::reverse::config_from_rendered(&text).map_err(|e| {
+            eprintln!("Reflow Error: {}", e);
+            format!("Cannot reflow input: {}", e)
+        })?;
+        text = parsed.text.clone();
+        // Re-rendering at another width should wrap rather than truncate
+        enable_wrapping = true;
+        if header.is_none() {
+            header = parsed.header.clone();
+        }
+        if footer.is_none() {
+            footer = parsed.footer.clone();
+        }
+        if status_bar.is_none() {
+            status_bar = parsed.status_bar.clone();
+        }
+        if title.is_none() {
+            title = parsed.title.clone();
+        }
+        if header_align == "center" {
+            header_align = Box::leak(parsed.alignment.header_align.clone().into_boxed_str());
+        }
+        if footer_align == "center" {
+            footer_align = Box::leak(parsed.alignment.footer_align.clone().into_boxed_str());
+        }
+        if body_align == "left" {
+            body_align = match parsed.body_align {
+                BodyAlignment::Left => "left",
+                BodyAlignment::Center => "center",
+                BodyAlignment::Right => "right",
+                BodyAlignment::Justify => "justify",
+            };
+        }
+        if label_style.is_none() && parsed.border_labels.decoration != "plain" {
+            label_style = Some(parsed.border_labels.decoration.clone());
+        }
+        if label_inset == 0 {
+            label_inset = parsed.border_labels.inset;
+        }
+        reflowed = Some(parsed);
+    }
+
+    // Code mode: --tab-width and --line-numbers refine the --code settings
+    if let Some(mut code) = code_config {
+        code.tab_width = tab_width.unwrap_or(code.tab_width);
+        code.line_numbers = line_numbers;
+        body_mode = BodyMode::Code(code);
+    }
+    if let Some(tree) = tree_config {
+        body_mode = BodyMode::Tree(tree);
+    }
+    if let Some(mut diff) = diff_config {
+        diff.line_numbers = line_numbers;
+        // Documents read from files get the same sanitizing as stdin
+        if let Some((old, new)) = diff.documents.take() {
+            let (old, old_report) = sanitize_text(&old, &sanitize_options);
+            let (new, new_report) = sanitize_text(&new, &sanitize_options);
+            sanitize_report.merge(old_report);
+            sanitize_report.merge(new_report);
+            diff.documents = Some((old, new));
+        }
+        body_mode = BodyMode::Diff(diff);
+    }
+    // Command output from `boxy run`, sanitized like stdin
+    if let Some(mut run) = run_body {
+        let (stdout, stdout_report) = sanitize_text(&run.stdout, &sanitize_options);
+        let (stderr, stderr_report) = sanitize_text(&run.stderr, &sanitize_options);
+        sanitize_report.merge(stdout_report);
+        sanitize_report.merge(stderr_report);
+        (run.stdout, run.stderr) = (stdout, stderr);
+        body_mode = BodyMode::Run(run);
+    }
+
+    // Markdown paragraphs and list items are meant to flow with the box width
+    if body_mode == BodyMode::Markdown {
+        enable_wrapping = true;
+    }
+
     // Params stream parsing: ONLY via --params flag. Piped stdin remains the body.
     if let Some(ref blob) = params_flag {
         if let Some(pc) = parse_content_stream(blob) {
@@ -753,9 +1262,17 @@ fn run_boxy_application() -> Result<(), AppError> {
                         "bl" => body_align = "left",
                         "bc" => body_align = "center",
                         "br" => body_align = "right",
+                        "bj" => body_align = "justify",
                         "bp" => body_pad_emoji = true,
+                        "vt" => vertical_align = Some("top"),
+                        "vm" => vertical_align = Some("middle"),
+                        "vb" => vertical_align = Some("bottom"),
+                        "te" => embed_title = true,
                         "dt" => divider_after_title = true,
                         "ds" => divider_before_status = true,
+                        "dl" => section_align = Some("left"),
+                        "dc" => section_align = Some("center"),
+                        "dr" => section_align = Some("right"),
                         "dtn" => {
                             divider_after_title = true;
                             pad_after_title_divider = true;
@@ -783,14 +1300,14 @@ fn run_boxy_application() -> Result<(), AppError> {
             // Handle width and height params: w=N, h=N
             if fixed_width.is_none() && pc.width.is_some() {
                 if let Some(w) = pc.width {
-                    if w >= 4 {
+                    if (MIN_WIDTH..=MAX_WIDTH).contains(&w) {
                         fixed_width = Some(w);
                     }
                 }
             }
             if fixed_height.is_none() && pc.height.is_some() {
                 if let Some(h) = pc.height {
-                    if validate_height(&h.to_string()).is_ok() {
+                    if (MIN_HEIGHT..=MAX_HEIGHT).contains(&h) {
                         fixed_height = Some(h);
                     }
                 }
@@ -799,9 +1316,49 @@ fn run_boxy_application() -> Result<(), AppError> {
         }
     }
 
+    // Labels from flags and params get the same treatment as the body
+    for label in [&mut title, &mut header, &mut footer, &mut status_bar]
+        .into_iter()
+        .flatten()
+    {
+        let (clean, report) = sanitize_text(label, &sanitize_options);
+        *label = clean;
+        sanitize_report.merge(report);
+    }
+
+    // [text](url) markup becomes OSC 8 hyperlinks; code and nested boxes stay literal
+    for label in [&mut title, &mut header, &mut footer, &mut status_bar]
+        .into_iter()
+        .flatten()
+    {
+        *label = expand_link_markup(label);
+        if plain_links {
+            *label = degrade_hyperlinks(label);
+        }
+    }
+    if matches!(body_mode, BodyMode::Text | BodyMode::Markdown) {
+        text = expand_link_markup(&text);
+    }
+    if plain_links {
+        text = degrade_hyperlinks(&text);
+    }
+    if debug_input {
+        if sanitize_report.is_clean() {
+            eprintln!("boxy: input needed no sanitizing");
+        } else {
+            eprintln!("boxy: input sanitized");
+            for line in sanitize_report.summary(control_style) {
+                eprintln!("  {}", line);
+            }
+        }
+    }
+
     //TODO: refactor to themes.rs => handle_theme_enigne(&theme_name)
     //      needs to return the right values for icon, fixed_width etc.
     // Apply theme if specified - using new theme engine
+    // Theme file min/max width settings bound the box unless --width was given
+    let mut theme_width_bounds: (Option<usize>, Option<usize>) = (None, None);
+    let width_from_cli = fixed_width.is_some();
     if let Some(theme_name_str) = &theme_name {
         match ThemeEngine::new_with_override(opt_dev_level) {
             Ok(theme_engine) => {
@@ -823,7 +1380,8 @@ fn run_boxy_application() -> Result<(), AppError> {
                         }
                     }
                     // Apply theme icon directly to text using safe pattern (no icon variable)
-                    if icon.is_none() {
+                    // Only plain text has a first line to carry the icon
+                    if icon.is_none() && body_mode == BodyMode::Text {
                         if let Some(icon_str) = &boxy_theme.icon {
                             apply_theme_icon_to_text(&mut text, icon_str);
                         } else if let Some(title_str) = &boxy_theme.title {
@@ -835,7 +1393,40 @@ fn run_boxy_application() -> Result<(), AppError> {
                         }
                     }
                     if fixed_width.is_none() {
-                        fixed_width = boxy_theme.width;
+                        fixed_width = boxy_theme
+                            .width
+                            .as_ref()
+                            .and_then(|spec| resolve_width(spec).ok().flatten());
+                    }
+                    if overflow_policy.is_none() {
+                        overflow_policy = boxy_theme
+                            .overflow
+                            .as_deref()
+                            .and_then(|name| OverflowPolicy::from_name(name).ok());
+                    }
+                    if body_padding.is_none() {
+                        body_padding = Some(boxy_theme.padding);
+                    }
+                    if margin.is_none() {
+                        margin = boxy_theme.margin;
+                    }
+                    if borders.is_none() {
+                        borders = boxy_theme.borders;
+                    }
+                    if divider_style.is_none() {
+                        divider_style = boxy_theme.divider_style.clone();
+                    }
+                    edge_colors = edge_colors.or(boxy_theme.border_colors.clone());
+                    if !width_from_cli {
+                        if let Some(settings) = theme_engine.theme_settings(theme_name_str) {
+                            theme_width_bounds = (settings.min_width, settings.max_width);
+                        }
+                    }
+                    if vertical_align.is_none() {
+                        vertical_align = boxy_theme
+                            .vertical_align
+                            .clone()
+                            .map(|va| &*Box::leak(va.into_boxed_str()));
                     }
                     // Apply theme style if not overridden by CLI
                     if !style_from_cli {
@@ -912,10 +1503,29 @@ fn run_boxy_application() -> Result<(), AppError> {
         }
     }
 
+    // Reflowed boxes keep their width and style unless the CLI or a theme sets them
+    if let Some(parsed) = &reflowed {
+        if fixed_width.is_none() {
+            fixed_width = parsed.width.fixed_width;
+        }
+        if !style_from_cli && theme_name.is_none() {
+            style = Box::leak(Box::new(parsed.style));
+        }
+    }
+    if let Some(forced) = output_profile.forced_style() {
+        style = Box::leak(Box::new(forced));
+    }
+
     let _status_color_str = status_color.as_deref().unwrap_or("");
     // DEBUG: Status color selection (commented for clean output)
     // eprintln!("Status Color: {}", status_color_str);
 
+    // Nested, markdown, code, tree and diff bodies have no text line to carry
+    // the icon; without a title to put it on, it is dropped
+    if title.is_none() && body_mode != BodyMode::Text && icon.take().is_some() {
+        eprintln!("Warning: --icon needs --title with this body; icon dropped");
+    }
+
     // ⚠️  CRITICAL: DO NOT CHANGE THIS ICON LOGIC! ⚠️
     //
     // 🚨 WARNING: The icon positioning was a NIGHTMARE to get right! 🚨
@@ -942,11 +1552,32 @@ fn run_boxy_application() -> Result<(), AppError> {
         apply_icon_to_text!(text, icon);
     }
 
+    // Checked on the final body text, once icons have been placed
+    // A forced JSON tree must parse; auto-detected JSON falls back to indented text
+    if let BodyMode::Tree(tree) = &body_mode
+        && tree.format == "json"
+        && let Err(e) = api::tree::parse_tree(&text, api::tree::TreeFormat::Json)
+    {
+        eprintln!("Error: {}", e);
+        return Err(e);
+    }
+    if let BodyMode::Diff(diff) = &body_mode
+        && diff.documents.is_none()
+        && api::diff::split_documents(&text).is_none()
+    {
+        let e = format!(
+            "--diff needs two documents on stdin separated by a {} line",
+            api::diff::SEPARATOR
+        );
+        eprintln!("Error: {}", e);
+        return Err(e);
+    }
+
     if no_boxy {
         let stripped = strip_box(&text, strict_mode);
         println!("{}", stripped);
     } else {
-        let config = resolve_box_config(
+        let mut config = resolve_box_config(
             &text,
             1,
             1,
@@ -981,17 +1612,77 @@ fn run_boxy_application() -> Result<(), AppError> {
             footer_color.as_deref(),
             enable_wrapping,
         );
+        if let Some(va) = vertical_align {
+            config.alignment.vertical_align = va.to_string();
+        }
+        // Only bounds the theme file sets itself apply
+        (config.width.min_width, config.width.max_width) = theme_width_bounds;
+        // Exported files are not bound by the terminal width. Piped output and an
+        // absolute --width are only fitted when a flag, theme or BOXY_OVERFLOW asks for it.
+        if output_format.is_none() {
+            let env_policy = env::var("BOXY_OVERFLOW").ok().and_then(|name| {
+                OverflowPolicy::from_name(&name)
+                    .map_err(|e| eprintln!("Warning: BOXY_OVERFLOW ignored: {}", e))
+                    .ok()
+            });
+            let requested = overflow_policy.or(env_policy);
+            if requested.is_some() || (io::stdout().is_terminal() && !absolute_width) {
+                config.width.overflow = Some(requested.unwrap_or_default());
+            }
+        }
+        config.width.body_padding = body_padding;
+        config.margin = margin.unwrap_or_default();
+        config.borders = borders.unwrap_or_default();
+        config.colors.edges = edge_colors;
+        config.border_labels.embed_title = embed_title;
+        config.shadow = shadow;
+        config.body_mode = body_mode;
+        config.border_labels.inset = label_inset;
+        if let Some(align) = section_align {
+            config.dividers.section_align = align.to_string();
+        }
+        if let Some(rule_style) = divider_style {
+            config.dividers.rule_style = rule_style;
+        }
+        if let Some(ls) = label_style {
+            config.dividers.section_decoration = ls.clone();
+            config.border_labels.decoration = ls;
+        } else if embed_title {
+            // Embedded titles read better with breathing room: ╭─ Build ───╮
+            config.border_labels.decoration = "spaced".to_string();
+        }
         // CHINA-05A Phase 3: CLI switchover to new API
         let layout = BoxLayout::from(&config);
+        if let Some(overflow) = &layout.overflow {
+            if overflow.policy == OverflowPolicy::Error {
+                eprintln!("Error: {}", overflow.message());
+                return Err("Box exceeds terminal width".to_string());
+            }
+            eprintln!("Warning: {}", overflow.message());
+        }
 
         // Apply colors using the new API, unless --no-color was specified
         let output = if no_color_requested {
-            layout.render()
+            // Status segment colors are embedded in the layout, drop them too
+            strip_ansi_codes(&layout.render())
         } else {
             use api::theming::ColorScheme;
             let color_scheme = ColorScheme::from_config(&config);
             layout.render_with_colors(&color_scheme)
         };
+        // Applied last so an explicit cell size wins over the one --font-size derives
+        if let Some((w, h)) = cell_size {
+            export_options = export_options.with_cell_size(w, h);
+        }
+        let output = match output_profile {
+            api::profile::OutputProfile::Terminal => output,
+            profile => profile.apply(&[output]).join("\n"),
+        };
+        let output = match output_format {
+            Some(api::export::ExportFormat::Html) => api::export::to_html(&output, &export_options),
+            Some(api::export::ExportFormat::Svg) => api::export::to_svg(&output, &export_options),
+            None => output,
+        };
         println!("{}", output);
     }
 
@@ -273,6 +273,7 @@ mod tests {
 
     #[test]
     fn test_width_calculations() {
+        crate::width_calibration::install_uncalibrated();
         // Simple ASCII
         assert_eq!(get_unicode_width("X"), 1);
         assert_eq!(get_estimated_terminal_width("X"), 1);
@@ -6,9 +6,11 @@
 //! - theme_engine: YAML theme loading and management
 //! - themes_builtin: Built-in theme registry
 //! - themes: Theme commands and utilities
+//! - run: Run a command and box its output (`boxy run`)
 
 pub mod emoji_debug;
 pub mod jynx;
+pub mod run;
 pub mod theme_engine;
 pub mod themes;
 pub mod themes_builtin;
@@ -0,0 +1,171 @@
+//! Run-and-box - execute a command and box what it printed (`boxy run -- CMD ARGS`)
+//!
+//! stdout and stderr are captured separately, stderr going into a `stderr`
+//! section, or through one pipe in the order they were written (`--interleave`).
+//! Output is boxed literally: markers and link markup in it are not
+//! interpreted. The exit code picks the `success` or `error` theme and, with
+//! the elapsed time, fills the status bar; boxy then exits with the same code.
+
+use std::io::{self, Read};
+use std::process::{Command, ExitStatus, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::core::RunConfig;
+
+/// What a finished command left behind
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunOutput {
+    pub stdout: String,
+    /// Empty when interleaved into `stdout`
+    pub stderr: String,
+    pub code: i32,
+    pub elapsed: Duration,
+}
+
+impl RunOutput {
+    pub fn success(&self) -> bool {
+        self.code == 0
+    }
+
+    /// Body for `BodyMode::Run`: both streams without trailing newlines, `(no output)` if empty
+    pub fn body(&self) -> RunConfig {
+        let stdout = self.stdout.trim_end_matches('\n');
+        let stderr = self.stderr.trim_end_matches('\n');
+        RunConfig {
+            stdout: match stdout.is_empty() && stderr.is_empty() {
+                true => "(no output)".to_string(),
+                false => stdout.to_string(),
+            },
+            stderr: stderr.to_string(),
+        }
+    }
+
+    /// Status bar spec: exit code on the left, elapsed time on the right
+    pub fn status(&self) -> String {
+        format!("sl:exit {}|sr:{}", self.code, format_elapsed(self.elapsed))
+    }
+}
+
+/// Run `command` (program first) to completion; stdin is passed through
+///
+/// A command that cannot be started is reported like a shell would: its
+/// error on stderr and code 127 (not found) or 126 (not executable).
+pub fn run_command(command: &[String], interleave: bool) -> RunOutput {
+    let started = Instant::now();
+    let captured = match interleave {
+        true => capture_interleaved(command),
+        false => capture_separate(command),
+    };
+    let elapsed = started.elapsed();
+
+    match captured {
+        Ok((stdout, stderr, status)) => RunOutput {
+            stdout,
+            stderr,
+            code: exit_code(status),
+            elapsed,
+        },
+        Err(e) => RunOutput {
+            stdout: String::new(),
+            stderr: format!("cannot run '{}': {}", command[0], e),
+            code: match e.kind() {
+                io::ErrorKind::NotFound => 127,
+                _ => 126,
+            },
+            elapsed,
+        },
+    }
+}
+
+fn capture_separate(command: &[String]) -> io::Result<(String, String, ExitStatus)> {
+    let output = Command::new(&command[0])
+        .args(&command[1..])
+        .stdin(Stdio::inherit())
+        .output()?;
+    Ok((
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+        output.status,
+    ))
+}
+
+fn capture_interleaved(command: &[String]) -> io::Result<(String, String, ExitStatus)> {
+    let (mut reader, writer) = io::pipe()?;
+    // The Command is dropped at the end of this statement, so the child holds
+    // the only write ends and reading stops once it exits
+    let mut child = Command::new(&command[0])
+        .args(&command[1..])
+        .stdin(Stdio::inherit())
+        .stdout(writer.try_clone()?)
+        .stderr(writer)
+        .spawn()?;
+
+    let mut output = Vec::new();
+    reader.read_to_end(&mut output)?;
+    let status = child.wait()?;
+    Ok((
+        String::from_utf8_lossy(&output).into_owned(),
+        String::new(),
+        status,
+    ))
+}
+
+/// Exit code to propagate; a command killed by a signal gets 128 + signal, like in a shell
+fn exit_code(status: ExitStatus) -> i32 {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return 128 + signal;
+        }
+    }
+    status.code().unwrap_or(1)
+}
+
+/// Short elapsed time: `250ms`, `1.25s`, `2m05s`
+pub fn format_elapsed(elapsed: Duration) -> String {
+    let millis = elapsed.as_millis();
+    let secs = elapsed.as_secs();
+    match millis {
+        0..1_000 => format!("{}ms", millis),
+        1_000..60_000 => format!("{:.2}s", elapsed.as_secs_f64()),
+        _ => format!("{}m{:02}s", secs / 60, secs % 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sh(script: &str) -> Vec<String> {
+        ["sh", "-c", script].map(String::from).to_vec()
+    }
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(Duration::from_millis(250)), "250ms");
+        assert_eq!(format_elapsed(Duration::from_millis(1250)), "1.25s");
+        assert_eq!(format_elapsed(Duration::from_secs(125)), "2m05s");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_captures_streams_and_exit_code() {
+        let run = run_command(&sh("echo out; echo err >&2; exit 3"), false);
+        assert_eq!(run.code, 3);
+        assert_eq!(
+            (run.body().stdout, run.body().stderr),
+            ("out".into(), "err".into())
+        );
+        assert!(run.status().starts_with("sl:exit 3|sr:"));
+
+        let run = run_command(&sh("echo one; echo two >&2; echo three"), true);
+        assert!(run.success());
+        assert_eq!(run.body().stdout, "one\ntwo\nthree");
+        assert!(run.body().stderr.is_empty());
+
+        let run = run_command(&["no-such-command-for-boxy".to_string()], false);
+        assert_eq!(run.code, 127);
+        assert!(run.stderr.contains("no-such-command-for-boxy"));
+    }
+}
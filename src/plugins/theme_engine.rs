@@ -2,6 +2,7 @@
 // Inherits complete jynx architecture patterns with XDG+ directory support
 
 use crate::colors::*;
+use crate::core::{BorderSides, EdgeColors, SizeSpec, Spacing};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fs;
@@ -27,6 +28,8 @@ pub struct ThemeEngine {
     theme_files: Vec<PathBuf>,
     theme_hierarchy: Vec<String>, // Track loading hierarchy for debug
     file_trail: Vec<String>,      // Track each individual file found
+    theme_sources: HashMap<String, String>, // Theme name -> file that defined it ("builtin" if compiled in)
+    theme_settings: HashMap<String, ThemeSettings>, // Theme name -> settings of the file that defined it
     xdg_base_dir: PathBuf,
 }
 
@@ -59,11 +62,19 @@ pub struct BoxyTheme {
     pub header_color: Option<String>,
     #[serde(default)]
     pub footer_color: Option<String>,
+    #[serde(default)]
+    pub border_colors: EdgeColors, // Per-edge overrides: { left: "crimson", divider: "grey" }
 
     // === LAYOUT PROPERTIES ===
-    pub width: Option<usize>, // Fixed width in characters
+    pub width: Option<SizeSpec>, // Fixed width: 40, 50%, max, -4, min(80,100%)
     #[serde(default = "default_padding")]
-    pub padding: usize, // Internal padding (default: 1)
+    pub padding: Spacing, // Internal padding: 2 (left/right) or "1 2 0 2" (default: 1)
+    #[serde(default)]
+    pub margin: Option<Spacing>, // Space around the box: "1 0" or "0 0 0 4"
+    #[serde(default)]
+    pub borders: Option<BorderSides>, // Frame edges: "all", "tb", "l", "none"
+    #[serde(default)]
+    pub divider_style: Option<String>, // Inner dividers: frame, light, heavy, double, dashed, dotted
 
     // === LAYOUT ALIGNMENT ===
     #[serde(default = "default_align")]
@@ -72,6 +83,10 @@ pub struct BoxyTheme {
     pub header_align: String, // Header alignment: left, center, right
     #[serde(default = "default_align")]
     pub footer_align: String, // Footer alignment: left, center, right
+    #[serde(default)]
+    pub vertical_align: Option<String>, // Body placement in fixed-height boxes: top, middle, bottom
+    #[serde(default)]
+    pub overflow: Option<String>, // Wider than the terminal: wrap, truncate, shrink, error
 
     // === ADVANCED FEATURES ===
     pub status_bar: Option<String>, // Status bar below box
@@ -126,10 +141,12 @@ pub struct ThemeSettings {
     pub default_theme: String,
     #[serde(default = "default_fallback_color")]
     pub fallback_color: String,
-    #[serde(default = "default_max_width")]
-    pub max_width: usize,
-    #[serde(default = "default_min_width")]
-    pub min_width: usize,
+    /// Upper bound on auto-sized boxes of this file's themes, when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_width: Option<usize>,
+    /// Lower bound on auto-sized boxes of this file's themes, when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_width: Option<usize>,
     #[serde(default = "default_true")]
     pub cache_themes: bool,
     #[serde(default = "default_true")]
@@ -141,8 +158,8 @@ impl Default for ThemeSettings {
         ThemeSettings {
             default_theme: default_theme_name(),
             fallback_color: default_fallback_color(),
-            max_width: default_max_width(),
-            min_width: default_min_width(),
+            max_width: None,
+            min_width: None,
             cache_themes: default_true(),
             validate_colors: default_true(),
         }
@@ -162,8 +179,8 @@ fn default_style() -> String {
 fn default_text_style() -> String {
     "normal".to_string()
 }
-fn default_padding() -> usize {
-    1
+fn default_padding() -> Spacing {
+    Spacing::horizontal(1)
 }
 fn default_align() -> String {
     "center".to_string()
@@ -177,12 +194,6 @@ fn default_theme_name() -> String {
 fn default_fallback_color() -> String {
     "slate".to_string()
 }
-fn default_max_width() -> usize {
-    120
-}
-fn default_min_width() -> usize {
-    10
-}
 fn default_true() -> bool {
     true
 }
@@ -202,6 +213,8 @@ impl ThemeEngine {
             theme_files: Vec::new(),
             theme_hierarchy: Vec::new(),
             file_trail: Vec::new(),
+            theme_sources: HashMap::new(),
+            theme_settings: HashMap::new(),
             xdg_base_dir,
         };
 
@@ -250,6 +263,9 @@ impl ThemeEngine {
         self.file_trail.extend(trail);
 
         for (name, theme) in builtin_themes {
+            self.theme_sources
+                .insert(name.clone(), "builtin".to_string());
+            self.theme_settings.remove(&name);
             self.themes.insert(name, theme);
         }
     }
@@ -453,6 +469,10 @@ impl ThemeEngine {
             theme.metadata = Some(theme_file.metadata.clone());
 
             // Always insert - later loads have higher priority
+            self.theme_sources
+                .insert(name.clone(), path.display().to_string());
+            self.theme_settings
+                .insert(name.clone(), theme_file.settings.clone());
             self.themes.insert(name, theme);
         }
 
@@ -481,6 +501,9 @@ impl ThemeEngine {
         if let Some(c) = &theme.header_color {
             validate_color(c)?;
         }
+        for c in theme.border_colors.names() {
+            validate_color(c)?;
+        }
         if let Some(c) = &theme.footer_color {
             validate_color(c)?;
         }
@@ -494,10 +517,28 @@ impl ThemeEngine {
             ));
         }
 
-        // Validate width constraints
-        if let Some(width) = theme.width {
-            if width < 10 || width > 200 {
-                return Err(format!("Width {} out of range (10-200)", width));
+        if let Some(policy) = &theme.overflow {
+            crate::core::OverflowPolicy::from_name(policy)?;
+        }
+        if let Some(divider) = &theme.divider_style {
+            crate::visual::DividerStyle::from_name(divider)?;
+        }
+
+        // Validate width constraints; relative widths are bounded by the terminal
+        if let Some(SizeSpec::Cells(width)) = theme.width {
+            if width < crate::core::MIN_WIDTH {
+                return Err(format!(
+                    "Width {} is below the minimum of {}",
+                    width,
+                    crate::core::MIN_WIDTH
+                ));
+            }
+            if width > crate::core::MAX_WIDTH {
+                return Err(format!(
+                    "Width {} is above the maximum of {}",
+                    width,
+                    crate::core::MAX_WIDTH
+                ));
             }
         }
 
@@ -626,13 +667,17 @@ impl ThemeEngine {
             status_color: child.status_color.or(parent.status_color),
             header_color: child.header_color.or(parent.header_color),
             footer_color: child.footer_color.or(parent.footer_color),
+            border_colors: child.border_colors.or(parent.border_colors),
             icon: child.icon.or(parent.icon),
             width: child.width.or(parent.width),
-            padding: if child.padding == 1 && parent.padding != 1 {
+            padding: if child.padding == default_padding() && parent.padding != default_padding() {
                 parent.padding
             } else {
                 child.padding
             },
+            margin: child.margin.or(parent.margin),
+            borders: child.borders.or(parent.borders),
+            divider_style: child.divider_style.or(parent.divider_style),
             title_align: if child.title_align == "center" && parent.title_align != "center" {
                 parent.title_align
             } else {
@@ -648,6 +693,8 @@ impl ThemeEngine {
             } else {
                 child.footer_align
             },
+            vertical_align: child.vertical_align.or(parent.vertical_align),
+            overflow: child.overflow.or(parent.overflow),
             status_bar: child.status_bar.or(parent.status_bar),
             status_align: if child.status_align == "left" && parent.status_align != "left" {
                 parent.status_align
@@ -695,6 +742,16 @@ impl ThemeEngine {
         self.file_trail.clone()
     }
 
+    /// File that defined a theme ("builtin" for compiled-in themes)
+    pub fn theme_source(&self, name: &str) -> Option<&str> {
+        self.theme_sources.get(name).map(String::as_str)
+    }
+
+    /// Settings of the file that defined a theme (`None` for compiled-in themes)
+    pub fn theme_settings(&self, name: &str) -> Option<&ThemeSettings> {
+        self.theme_settings.get(name)
+    }
+
     /// Print comprehensive theme hierarchy for debugging (ENGINE-010)
     pub fn print_theme_hierarchy(&self) {
         use std::fs;
@@ -828,12 +885,18 @@ impl Default for BoxyTheme {
             status_color: None,
             header_color: None,
             footer_color: None,
+            border_colors: EdgeColors::default(),
             icon: None,
             width: None,
-            padding: 1,
+            padding: default_padding(),
+            margin: None,
+            borders: None,
+            divider_style: None,
             title_align: "center".to_string(),
             header_align: "center".to_string(),
             footer_align: "center".to_string(),
+            vertical_align: None,
+            overflow: None,
             status_bar: None,
             status_align: "left".to_string(),
             layout: None,
@@ -942,17 +1005,19 @@ mod tests {
         // Test invalid width constraints
         let invalid_width_theme = BoxyTheme {
             color: "azure".to_string(),
-            width: Some(5), // Too small
+            width: Some(3.into()), // Too small for borders and content
             ..Default::default()
         };
         assert!(engine.validate_theme(&invalid_width_theme).is_err());
 
-        let invalid_width_large_theme = BoxyTheme {
+        // Divider styles are checked by name
+        let divider_theme = |name: &str| BoxyTheme {
             color: "azure".to_string(),
-            width: Some(300), // Too large
+            divider_style: Some(name.to_string()),
             ..Default::default()
         };
-        assert!(engine.validate_theme(&invalid_width_large_theme).is_err());
+        assert!(engine.validate_theme(&divider_theme("dashed")).is_ok());
+        assert!(engine.validate_theme(&divider_theme("wavy")).is_err());
     }
 
     #[test]
@@ -980,7 +1045,8 @@ mod tests {
             color: "azure".to_string(),
             text_color: "auto".to_string(),
             style: "normal".to_string(),
-            padding: 2,
+            padding: Spacing::horizontal(2),
+            margin: Some(Spacing::symmetric(1, 0)),
             ..Default::default()
         };
 
@@ -998,7 +1064,8 @@ mod tests {
         assert_eq!(merged.text_style, "bold");
         // Parent properties should be preserved where child doesn't override
         assert_eq!(merged.text_color, "auto");
-        assert_eq!(merged.padding, 2);
+        assert_eq!(merged.padding, Spacing::horizontal(2));
+        assert_eq!(merged.margin, Some(Spacing::symmetric(1, 0)));
     }
 
     #[test]
@@ -1040,7 +1107,101 @@ settings:
         let theme = &theme_file.themes["test_theme"];
         assert_eq!(theme.color, "azure");
         assert_eq!(theme.style, "rounded");
-        assert_eq!(theme.width, Some(50));
+        assert_eq!(theme.width, Some(50.into()));
+    }
+
+    #[test]
+    fn test_yaml_theme_width_specs() {
+        let test_yaml = r#"
+metadata:
+  name: "widths"
+  version: "1.0.0"
+  description: "Relative widths"
+themes:
+  half: { color: "azure", width: "50%" }
+  inset: { color: "azure", width: -4 }
+  capped: { color: "azure", width: "min(80,100%)" }
+settings:
+  max_width: 100
+  min_width: 20
+"#;
+        let theme_file: ThemeFile = serde_yaml::from_str(test_yaml).unwrap();
+        assert_eq!(theme_file.themes["half"].width, Some(SizeSpec::Percent(50)));
+        assert_eq!(theme_file.themes["inset"].width, Some(SizeSpec::Minus(4)));
+        assert_eq!(
+            theme_file.themes["capped"].width,
+            Some(SizeSpec::parse("min(80,100%)").unwrap())
+        );
+        assert_eq!(theme_file.settings.max_width, Some(100));
+        let unset = test_yaml.replace("  min_width: 20\n", "");
+        let theme_file: ThemeFile = serde_yaml::from_str(&unset).unwrap();
+        assert_eq!(theme_file.settings.min_width, None);
+
+        let bad = test_yaml.replace("\"50%\"", "\"150%\"");
+        assert!(serde_yaml::from_str::<ThemeFile>(&bad).is_err());
+    }
+
+    #[test]
+    fn test_yaml_theme_border_colors() {
+        let test_yaml = r#"
+metadata:
+  name: "edges"
+  version: "1.0.0"
+  description: "Accent stripes"
+themes:
+  stripe:
+    color: "grey"
+    border_colors: { left: "crimson", divider: "azure" }
+"#;
+        let theme_file: ThemeFile = serde_yaml::from_str(test_yaml).unwrap();
+        let edges = &theme_file.themes["stripe"].border_colors;
+        assert_eq!(edges.left.as_deref(), Some("crimson"));
+        assert_eq!(edges.divider.as_deref(), Some("azure"));
+        assert!(edges.top.is_none());
+
+        let engine = ThemeEngine::new().unwrap();
+        let child = BoxyTheme {
+            border_colors: EdgeColors {
+                top: Some("red".to_string()),
+                ..EdgeColors::default()
+            },
+            ..Default::default()
+        };
+        let merged = engine.merge_themes(theme_file.themes["stripe"].clone(), child);
+        assert_eq!(merged.border_colors.top.as_deref(), Some("red"));
+        assert_eq!(merged.border_colors.left.as_deref(), Some("crimson"));
+
+        let bad = test_yaml.replace("left:", "middle:");
+        assert!(serde_yaml::from_str::<ThemeFile>(&bad).is_err());
+    }
+
+    #[test]
+    fn test_yaml_theme_padding_and_margin() {
+        let test_yaml = r#"
+metadata:
+  name: "spacing"
+  version: "1.0.0"
+  description: "Per-side spacing"
+themes:
+  legacy: { color: "azure", padding: 2 }
+  card: { color: "azure", padding: "1 3", margin: "1 0 1 4", borders: "l" }
+"#;
+        let theme_file: ThemeFile = serde_yaml::from_str(test_yaml).unwrap();
+        assert_eq!(theme_file.themes["legacy"].padding, Spacing::horizontal(2));
+        assert!(theme_file.themes["legacy"].margin.is_none());
+        assert_eq!(theme_file.themes["card"].padding, Spacing::symmetric(1, 3));
+        assert_eq!(
+            theme_file.themes["card"].margin,
+            Some(Spacing::new(1, 0, 1, 4))
+        );
+        assert_eq!(
+            theme_file.themes["card"].borders,
+            Some(BorderSides::parse("l").unwrap())
+        );
+        assert!(theme_file.themes["legacy"].borders.is_none());
+
+        let bad = test_yaml.replace("\"1 3\"", "\"1 2 3 4 5\"");
+        assert!(serde_yaml::from_str::<ThemeFile>(&bad).is_err());
     }
 
     #[test]
@@ -1052,7 +1213,8 @@ settings:
         assert_eq!(default_theme.text_color, "auto");
         assert_eq!(default_theme.style, "normal");
         assert_eq!(default_theme.text_style, "normal");
-        assert_eq!(default_theme.padding, 1);
+        assert_eq!(default_theme.padding, Spacing::horizontal(1));
+        assert!(default_theme.margin.is_none());
         assert_eq!(default_theme.title_align, "center");
         assert_eq!(default_theme.status_align, "left");
         assert!(default_theme.width.is_none());
@@ -1081,7 +1243,7 @@ settings:
             color: "emerald".to_string(),
             text_color: "none".to_string(),
             style: "rounded".to_string(),
-            width: Some(60),
+            width: Some(60.into()),
             ..Default::default()
         };
         assert!(engine.validate_theme(&valid_theme).is_ok());
@@ -1089,14 +1251,14 @@ settings:
         // Test edge case width values
         let min_width_theme = BoxyTheme {
             color: "azure".to_string(),
-            width: Some(10), // Minimum valid
+            width: Some(crate::core::MIN_WIDTH.into()), // Minimum valid
             ..Default::default()
         };
         assert!(engine.validate_theme(&min_width_theme).is_ok());
 
         let max_width_theme = BoxyTheme {
             color: "azure".to_string(),
-            width: Some(200), // Maximum valid
+            width: Some(300.into()), // Wider than most terminals is still valid
             ..Default::default()
         };
         assert!(engine.validate_theme(&max_width_theme).is_ok());
@@ -1136,8 +1298,8 @@ settings:
 
         assert_eq!(settings.default_theme, "info");
         assert_eq!(settings.fallback_color, "slate");
-        assert_eq!(settings.max_width, 120);
-        assert_eq!(settings.min_width, 10);
+        assert_eq!(settings.max_width, None);
+        assert_eq!(settings.min_width, None);
         assert!(settings.cache_themes);
         assert!(settings.validate_colors);
     }
@@ -0,0 +1,357 @@
+//! Theme gallery - every loaded theme as a sample box, side by side
+//!
+//! `boxy theme gallery` renders one sample card per theme and lays the cards
+//! out in as many columns as the terminal fits. Themes can be narrowed by name
+//! pattern, catalog category or the file that defined them, and the whole
+//! gallery can be written as a self-contained HTML page instead.
+
+use crate::api::export::ansi_to_html;
+use crate::api::geometry::validate_box_style_name;
+use crate::api::layout::{BoxBuilder, HeaderBuilder, StatusBuilder};
+use crate::api::theming::{ColorScheme, apply_colors_to_rendered_box};
+use crate::plugins::theme_engine::{BoxyTheme, ThemeEngine};
+use crate::width_plugin::{get_display_width, get_terminal_width};
+use crate::{NAME, NORMAL, VERSION};
+
+const CATEGORIES: [&str; 4] = ["semantic", "utility", "builtin", "custom"];
+
+/// Catalog category of a theme: semantic, utility, builtin or custom
+pub fn theme_category(name: &str, description: &str) -> &'static str {
+    if matches!(
+        name,
+        "error" | "success" | "warning" | "info" | "critical" | "fatal" | "debug"
+    ) {
+        "semantic"
+    } else if name.starts_with("base") || matches!(name, "blueprint" | "default" | "trace") {
+        "utility"
+    } else if description.contains("Compiled default") {
+        "builtin"
+    } else {
+        "custom"
+    }
+}
+
+/// Gallery settings parsed from `theme gallery` arguments
+#[derive(Debug, Clone, PartialEq)]
+pub struct GalleryOptions {
+    /// Name pattern; `*` and `?` are wildcards, otherwise a substring match
+    pub pattern: Option<String>,
+    pub category: Option<String>,
+    /// Substring of the defining file (`builtin` for compiled-in themes)
+    pub source: Option<String>,
+    pub card_width: usize,
+    pub html: bool,
+}
+
+impl Default for GalleryOptions {
+    fn default() -> Self {
+        Self {
+            pattern: None,
+            category: None,
+            source: None,
+            card_width: 28,
+            html: false,
+        }
+    }
+}
+
+impl GalleryOptions {
+    /// Parse `[PATTERN] [--category C] [--source FILE] [--card-width N] [--html]`
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = |flag: &str| {
+                args.next()
+                    .cloned()
+                    .ok_or_else(|| format!("{} requires a value", flag))
+            };
+            match arg.as_str() {
+                "--category" => {
+                    let category = value(arg)?.to_lowercase();
+                    if !CATEGORIES.contains(&category.as_str()) {
+                        return Err(format!(
+                            "Unknown category '{}'. Valid: {}",
+                            category,
+                            CATEGORIES.join(", ")
+                        ));
+                    }
+                    options.category = Some(category);
+                }
+                "--source" => options.source = Some(value(arg)?),
+                "--card-width" => {
+                    let width = value(arg)?;
+                    options.card_width = match width.parse::<usize>() {
+                        Ok(width) if width >= 10 => width,
+                        _ => return Err(format!("Invalid card width '{}' (minimum 10)", width)),
+                    };
+                }
+                "--html" => options.html = true,
+                flag if flag.starts_with('-') => {
+                    return Err(format!("Unknown gallery option: {}", flag));
+                }
+                pattern => options.pattern = Some(pattern.to_string()),
+            }
+        }
+        Ok(options)
+    }
+
+    /// Whether a theme passes every filter
+    pub fn matches(&self, name: &str, category: &str, source: &str) -> bool {
+        self.pattern
+            .as_deref()
+            .is_none_or(|pattern| matches_pattern(pattern, name))
+            && self.category.as_deref().is_none_or(|c| c == category)
+            && self.source.as_deref().is_none_or(|s| source.contains(s))
+    }
+}
+
+/// Glob match with `*` and `?`; patterns without wildcards match substrings
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    if !pattern.contains(['*', '?']) {
+        return name.contains(pattern);
+    }
+
+    fn glob(pattern: &[char], name: &[char]) -> bool {
+        match (pattern.first(), name.first()) {
+            (None, _) => name.is_empty(),
+            (Some('*'), _) => {
+                glob(&pattern[1..], name) || (!name.is_empty() && glob(pattern, &name[1..]))
+            }
+            (Some('?'), Some(_)) => glob(&pattern[1..], &name[1..]),
+            (Some(p), Some(n)) => p == n && glob(&pattern[1..], &name[1..]),
+            (Some(_), None) => false,
+        }
+    }
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    glob(&pattern, &name)
+}
+
+/// A theme selected for the gallery
+#[derive(Debug, Clone)]
+pub struct GalleryEntry {
+    pub name: String,
+    pub category: &'static str,
+    pub source: String,
+    pub theme: BoxyTheme,
+}
+
+/// Resolved themes that pass the filters, sorted by name
+pub fn collect_entries(engine: &ThemeEngine, options: &GalleryOptions) -> Vec<GalleryEntry> {
+    engine
+        .list_themes()
+        .into_iter()
+        .filter_map(|(name, description)| {
+            let category = theme_category(&name, &description);
+            let source = engine.theme_source(&name).unwrap_or("builtin").to_string();
+            if !options.matches(&name, category, &source) {
+                return None;
+            }
+            let theme = engine.get_theme(&name)?;
+            Some(GalleryEntry {
+                name,
+                category,
+                source,
+                theme,
+            })
+        })
+        .collect()
+}
+
+/// Sample box for one theme, colored with its border and text colors
+pub fn render_card(name: &str, theme: &BoxyTheme, width: usize) -> String {
+    let style = validate_box_style_name(&theme.style).unwrap_or(NORMAL);
+    let mut builder = BoxBuilder::new("Sample text")
+        .with_style(style)
+        .with_header(HeaderBuilder::new(name))
+        .with_status(StatusBuilder::new(&format!(
+            "{} · {}",
+            theme.color, theme.style
+        )))
+        .with_fixed_width(width);
+    if let Some(title) = &theme.title {
+        builder = builder.with_title(title);
+    }
+    if let Some(icon) = &theme.icon {
+        builder = builder.with_icon(icon);
+    }
+
+    let scheme = ColorScheme {
+        border_color: theme.color.clone(),
+        text_color: theme.text_color.clone(),
+        header_color: theme.header_color.clone(),
+        status_color: theme.status_color.clone(),
+        ..ColorScheme::default()
+    };
+    apply_colors_to_rendered_box(&builder.build().render(), &scheme)
+}
+
+/// Lay rendered cards out left to right, wrapping to fit `width` columns
+pub fn layout_grid(cards: &[String], width: usize, gap: usize) -> Vec<String> {
+    let cards: Vec<Vec<&str>> = cards.iter().map(|card| card.lines().collect()).collect();
+    let cell = cards
+        .iter()
+        .flatten()
+        .map(|line| get_display_width(line))
+        .max()
+        .unwrap_or(0);
+    let columns = ((width + gap) / (cell + gap)).max(1);
+
+    let mut lines = Vec::new();
+    for (row_index, row) in cards.chunks(columns).enumerate() {
+        if row_index > 0 {
+            lines.push(String::new());
+        }
+        let height = row.iter().map(Vec::len).max().unwrap_or(0);
+        for i in 0..height {
+            let mut line = String::new();
+            for (column, card) in row.iter().enumerate() {
+                let part = card.get(i).copied().unwrap_or("");
+                line.push_str(part);
+                if column + 1 < row.len() {
+                    let fill = cell.saturating_sub(get_display_width(part)) + gap;
+                    line.push_str(&" ".repeat(fill));
+                }
+            }
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+/// Self-contained HTML page with one figure per theme
+pub fn gallery_html(entries: &[GalleryEntry], card_width: usize) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str(&format!("<title>{} theme gallery</title>\n", NAME));
+    html.push_str("<style>\n");
+    html.push_str(
+        "body { background: #1e1e1e; color: #d4d4d4; font-family: monospace; margin: 2em; }\n",
+    );
+    html.push_str(&format!(
+        ".gallery {{ display: grid; grid-template-columns: repeat(auto-fill, minmax({}ch, 1fr)); gap: 2em 1.5em; }}\n",
+        card_width + 2
+    ));
+    html.push_str("figure { margin: 0; }\n");
+    html.push_str("pre { margin: 0; line-height: 1.2; }\n");
+    html.push_str("figcaption { color: #8a8a8a; font-size: 0.85em; margin-top: 0.4em; }\n");
+    html.push_str("a { color: inherit; }\n");
+    html.push_str("</style>\n</head>\n<body>\n");
+    html.push_str(&format!(
+        "<h1>{} {} theme gallery</h1>\n<p>{} themes</p>\n<div class=\"gallery\">\n",
+        NAME,
+        VERSION,
+        entries.len()
+    ));
+    for entry in entries {
+        let card = render_card(&entry.name, &entry.theme, card_width);
+        html.push_str(&format!(
+            "<figure id=\"{0}\">\n<pre>{1}</pre>\n<figcaption>{0} · {2} · {3}</figcaption>\n</figure>\n",
+            crate::api::export::escape_markup(&entry.name),
+            ansi_to_html(&card),
+            entry.category,
+            crate::api::export::escape_markup(&entry.source),
+        ));
+    }
+    html.push_str("</div>\n</body>\n</html>\n");
+    html
+}
+
+/// `boxy theme gallery` entry point
+pub fn handle_theme_gallery(args: &[String], opt_dev_level: Option<u8>) {
+    let options = match GalleryOptions::from_args(args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            eprintln!(
+                "Usage: {} theme gallery [PATTERN] [--category C] [--source FILE] [--card-width N] [--html]",
+                NAME
+            );
+            std::process::exit(1);
+        }
+    };
+    let engine = match ThemeEngine::new_with_override(opt_dev_level) {
+        Ok(engine) => engine,
+        Err(e) => {
+            eprintln!("Error: Failed to load theme engine: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let entries = collect_entries(&engine, &options);
+    if entries.is_empty() {
+        eprintln!("No themes match the gallery filters.");
+        std::process::exit(1);
+    }
+
+    if options.html {
+        print!("{}", gallery_html(&entries, options.card_width));
+        return;
+    }
+
+    let cards: Vec<String> = entries
+        .iter()
+        .map(|entry| render_card(&entry.name, &entry.theme, options.card_width))
+        .collect();
+    println!("🎨 Theme Gallery ({} themes)", entries.len());
+    println!();
+    for line in layout_grid(&cards, get_terminal_width(), 2) {
+        println!("{}", line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_pattern_matching() {
+        assert!(matches_pattern("err", "error"));
+        assert!(matches_pattern("base_*", "base_rounded"));
+        assert!(!matches_pattern("base_*", "rebase_x"));
+        assert!(matches_pattern("?nfo", "info"));
+    }
+
+    #[test]
+    fn test_gallery_options() {
+        let options =
+            GalleryOptions::from_args(&args(&["warn*", "--category", "Semantic", "--html"]))
+                .unwrap();
+        assert_eq!(options.pattern.as_deref(), Some("warn*"));
+        assert_eq!(options.category.as_deref(), Some("semantic"));
+        assert!(options.html);
+        assert!(options.matches("warning", "semantic", "builtin"));
+        assert!(!options.matches("warning", "custom", "builtin"));
+
+        assert!(GalleryOptions::from_args(&args(&["--category", "nope"])).is_err());
+        assert!(GalleryOptions::from_args(&args(&["--card-width", "4"])).is_err());
+    }
+
+    #[test]
+    fn test_grid_fits_width() {
+        let card = "┌──┐\n│ab│\n└──┘".to_string();
+        let cards = vec![card.clone(), card.clone(), card];
+
+        let wide = layout_grid(&cards, 20, 2);
+        assert_eq!(wide[1], "│ab│  │ab│  │ab│");
+
+        // Two cards fit in 10 columns; the third starts a new row
+        let narrow = layout_grid(&cards, 10, 2);
+        assert_eq!(narrow.len(), 7);
+        assert_eq!(narrow[0], "┌──┐  ┌──┐");
+        assert_eq!(narrow[5], "│ab│");
+    }
+
+    #[test]
+    fn test_categories() {
+        assert_eq!(theme_category("error", ""), "semantic");
+        assert_eq!(theme_category("base_rounded", ""), "utility");
+        assert_eq!(theme_category("ocean", "Compiled default theme"), "builtin");
+        assert_eq!(theme_category("mine", "My theme"), "custom");
+    }
+}
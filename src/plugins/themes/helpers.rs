@@ -411,8 +411,13 @@ themes:
     text_style: "normal"                 # Text styling (normal|bold|italic|underline|dim)
 
     # === LAYOUT AND SPACING ===
-    padding: 2                           # Inner horizontal padding (int)
-    width: 80                            # Fixed width override (optional int)
+    padding: 2                           # Inner padding: 2 (left/right) or "1 2 0 2" (top right bottom left)
+    # margin: "1 0"                      # Blank space around the box (same shorthand)
+    # borders: "tb"                      # Edges to draw: all, none or letters t r b l
+    # border_colors: { left: "crimson" } # Per-edge colors: top, right, bottom, left, divider
+    # divider_style: "dashed"            # Inner dividers: frame, light, heavy, double, dashed, dotted
+    width: 80                            # Fixed width: 80, "50%", max, -4, "min(80,100%)"
+    overflow: wrap                       # Wider than the terminal: wrap|truncate|shrink|error
 
     # === CONTENT ALIGNMENT ===
     title_align: "center"                # Title alignment (left|center|right)
@@ -538,8 +543,8 @@ text_styles:
 settings:
   default_theme: blueprint               # Theme used when none specified
   fallback_color: slate                  # Fallback border color
-  max_width: 120                         # Global maximum box width
-  min_width: 10                          # Global minimum box width
+  max_width: 120                         # Maximum box width for these themes
+  min_width: 10                          # Minimum box width for these themes
   cache_themes: true                     # Cache compiled themes in memory
   validate_colors: true                  # Validate color names at load time
 
@@ -12,6 +12,7 @@
 //! Version: boxy v0.16.0+ (RSB MODULE_SPEC reorganization)
 
 pub mod error;
+pub mod gallery;
 pub mod helpers;
 pub mod migration;
 pub mod utils;
@@ -134,7 +135,7 @@ mod tests {
             text_color: "white".to_string(),
             style: "rounded".to_string(),
             icon: Some("🎨".to_string()),
-            width: Some(80),
+            width: Some(80.into()),
             ..Default::default()
         };
 
@@ -159,7 +160,7 @@ mod tests {
             text_color: "white".to_string(),
             style: "heavy".to_string(),
             icon: Some("⚡".to_string()),
-            width: Some(60),
+            width: Some(60.into()),
             ..Default::default()
         };
 
@@ -13,6 +13,7 @@ use std::fs;
 use std::io::{self, Write};
 use std::path::PathBuf;
 
+use super::gallery::{handle_theme_gallery, theme_category};
 use super::helpers::*;
 
 /// Enhanced validation for theme files before import (ENGINE-014)
@@ -168,7 +169,7 @@ pub fn handle_theme_command(args: &[String], jynx: &JynxPlugin, opt_dev_level: O
             NAME
         );
         eprintln!(
-            "Available actions: list, browse, preview <theme>, show <theme>, hierarchy, dryrun <theme>, init, help"
+            "Available actions: list, browse, gallery, preview <theme>, show <theme>, hierarchy, dryrun <theme>, init, help"
         );
         std::process::exit(1);
     }
@@ -232,6 +233,9 @@ pub fn handle_theme_command(args: &[String], jynx: &JynxPlugin, opt_dev_level: O
         "browse" => {
             handle_theme_browse(opt_dev_level, jynx);
         }
+        "gallery" => {
+            handle_theme_gallery(&args[1..], opt_dev_level);
+        }
         "preview" => {
             if args.len() < 2 {
                 eprintln!(
@@ -258,7 +262,7 @@ pub fn handle_theme_command(args: &[String], jynx: &JynxPlugin, opt_dev_level: O
         action => {
             eprintln!("Unknown theme action: {}", action);
             eprintln!(
-                "Available actions: list, browse, preview, show, hierarchy, dryrun, init, create, import, export, edit, help"
+                "Available actions: list, browse, gallery, preview, show, hierarchy, dryrun, init, create, import, export, edit, help"
             );
             eprintln!("Use '{} theme help' for more information", NAME);
             std::process::exit(1);
@@ -541,7 +545,7 @@ pub fn create_theme_interactively(name: &str) -> BoxyTheme {
 
     // Width with validation loop
     let width = loop {
-        print!("Fixed width [10-200, or press Enter for auto]: ");
+        print!("Fixed width [e.g. 60, 80%, max, -4, or press Enter for auto]: ");
         io::stdout().flush().unwrap();
         let mut width_input = String::new();
         io::stdin().read_line(&mut width_input).unwrap();
@@ -552,7 +556,7 @@ pub fn create_theme_interactively(name: &str) -> BoxyTheme {
         }
 
         match validate_width(width) {
-            Ok(()) => break Some(width.parse().unwrap()),
+            Ok(()) => break SizeSpec::parse(width).ok(),
             Err(e) => {
                 println!("Error: {}", e);
                 continue;
@@ -629,6 +633,7 @@ pub fn edit_theme_interactively(name: &str, existing: &BoxyTheme) -> BoxyTheme {
     // Width
     let current_width = existing
         .width
+        .as_ref()
         .map(|w| w.to_string())
         .unwrap_or_else(|| "auto".to_string());
     print!("Fixed width [current: {}]: ", current_width);
@@ -637,15 +642,15 @@ pub fn edit_theme_interactively(name: &str, existing: &BoxyTheme) -> BoxyTheme {
     io::stdin().read_line(&mut width_input).unwrap();
     let width = width_input.trim();
     let width = if width.is_empty() {
-        existing.width
+        existing.width.clone()
     } else if width == "auto" {
         None
     } else {
-        match width.parse::<usize>() {
-            Ok(w) if w >= 10 => Some(w),
-            _ => {
+        match validate_width(width) {
+            Ok(()) => SizeSpec::parse(width).ok(),
+            Err(_) => {
                 println!("Warning: Invalid width, keeping current");
-                existing.width
+                existing.width.clone()
             }
         }
     };
@@ -765,7 +770,9 @@ pub fn convert_boxy_theme_to_legacy(boxy_theme: BoxyTheme) -> Theme {
     Theme {
         icon: Box::leak(icon.to_string().into_boxed_str()),
         color: Box::leak(boxy_theme.color.into_boxed_str()),
-        width: boxy_theme.width,
+        width: boxy_theme
+            .width
+            .and_then(|spec| resolve_width(&spec).ok().flatten()),
     }
 }
 
@@ -956,6 +963,9 @@ pub fn print_theme_help() {
     println!("COMMANDS:");
     println!("    list              Enhanced categorized theme list with visual previews");
     println!("    browse            Interactive theme browser with live previews");
+    println!(
+        "    gallery [PATTERN] All themes side by side in a grid (--category, --source, --html)"
+    );
     println!("    preview <name>    Comprehensive theme preview with multiple samples");
     println!("    show <name>       Show properties of a specific theme");
     println!("    dryrun <name>     Test theme with sample content");
@@ -995,6 +1005,14 @@ pub fn print_theme_help() {
         "    {} theme browse               # Interactive theme exploration",
         NAME
     );
+    println!(
+        "    {} theme gallery --category semantic  # Compare semantic themes",
+        NAME
+    );
+    println!(
+        "    {} theme gallery --html > themes.html # Shareable HTML gallery",
+        NAME
+    );
     println!(
         "    {} theme preview error        # Full preview of error theme",
         NAME
@@ -1098,28 +1116,13 @@ pub fn handle_enhanced_theme_list(opt_dev_level: Option<u8>, jynx: &JynxPlugin)
             // Categorize themes based on patterns and metadata
             for (name, description) in themes {
                 let theme_data = theme_engine.get_theme(&name);
-
-                // Check if it's a semantic theme (error, success, warning, info)
-                if matches!(
-                    name.as_str(),
-                    "error" | "success" | "warning" | "info" | "critical" | "fatal" | "debug"
-                ) {
-                    semantic_themes.push((name, description, theme_data));
-                }
-                // Check if it's a utility theme (base variants, blueprint, etc.)
-                else if name.starts_with("base")
-                    || matches!(name.as_str(), "blueprint" | "default" | "trace")
-                {
-                    utility_themes.push((name, description, theme_data));
-                }
-                // Check if it's a built-in theme by description
-                else if description.contains("Compiled default") {
-                    builtin_themes.push((name, description, theme_data));
-                }
-                // Everything else is custom
-                else {
-                    custom_themes.push((name, description, theme_data));
-                }
+                let group = match theme_category(&name, &description) {
+                    "semantic" => &mut semantic_themes,
+                    "utility" => &mut utility_themes,
+                    "builtin" => &mut builtin_themes,
+                    _ => &mut custom_themes,
+                };
+                group.push((name, description, theme_data));
             }
 
             // Display organized theme catalog
@@ -1326,10 +1329,16 @@ pub fn handle_theme_preview(theme_name: &str) {
                 if let Some(icon) = &theme.icon {
                     println!("├─ Icon: {}", icon);
                 }
-                if let Some(width) = theme.width {
+                if let Some(width) = &theme.width {
                     println!("├─ Width: {} characters", width);
                 }
-                println!("└─ Padding: {} spaces", theme.padding);
+                if let Some(margin) = &theme.margin {
+                    println!("├─ Margin: {}", margin);
+                }
+                if let Some(borders) = &theme.borders {
+                    println!("├─ Borders: {}", borders);
+                }
+                println!("└─ Padding: {}", theme.padding);
                 println!();
 
                 // Visual samples
@@ -7,6 +7,7 @@
 //!
 //! Environment variable BOXY_DEFAULTS_LEVEL controls which themes are loaded.
 
+use crate::core::SizeSpec;
 use crate::theme_engine::BoxyTheme;
 use std::collections::HashMap;
 use std::env;
@@ -154,7 +155,7 @@ fn get_standard_themes() -> (HashMap<String, BoxyTheme>, Vec<String>) {
             text_style: "bold".to_string(),
             title: Some("❌ Error".to_string()),
             icon: Some("❌".to_string()),
-            width: Some(60),
+            width: Some(SizeSpec::Cells(60)),
             ..Default::default()
         },
     );
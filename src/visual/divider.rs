@@ -0,0 +1,220 @@
+//! Divider styles - rules inside the box drawn independently of the frame
+//!
+//! By default a divider reuses the frame's own tee and horizontal glyphs. A
+//! divider style picks a different weight or pattern, and the junction where
+//! the rule meets the frame is chosen to join both:
+//!
+//! - `light` in a heavy frame: `┠────┨`
+//! - `heavy` in a light frame: `┝━━━━┥`
+//! - `double` in a light frame: `╞════╡`
+//! - `dashed` / `dotted`: light junctions with `┄` / `┈` rules
+//!
+//! Frames without box-drawing glyphs (ascii, colon, ...) keep their own tees
+//! and draw the rule with `-` (light patterns) or `=` (heavy and double).
+
+use super::utils::BoxStyle;
+
+/// Weight or pattern of a divider rule
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DividerStyle {
+    /// Same glyphs as the frame
+    #[default]
+    Frame,
+    Light,
+    Heavy,
+    Double,
+    Dashed,
+    Dotted,
+}
+
+/// Line weight of a box-drawing stroke
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Weight {
+    Light,
+    Heavy,
+    Double,
+}
+
+impl Weight {
+    /// Weight of the frame's vertical edge; `None` for non box-drawing frames
+    fn of_frame(frame: &BoxStyle) -> Option<Self> {
+        match frame.vertical {
+            "│" | "┆" | "┊" => Some(Self::Light),
+            "┃" | "┇" | "┋" => Some(Self::Heavy),
+            "║" => Some(Self::Double),
+            _ => None,
+        }
+    }
+}
+
+impl DividerStyle {
+    pub const ALL: [Self; 6] = [
+        Self::Frame,
+        Self::Light,
+        Self::Heavy,
+        Self::Double,
+        Self::Dashed,
+        Self::Dotted,
+    ];
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name.trim().to_lowercase().as_str() {
+            "frame" => Ok(Self::Frame),
+            "light" => Ok(Self::Light),
+            "heavy" => Ok(Self::Heavy),
+            "double" => Ok(Self::Double),
+            "dashed" => Ok(Self::Dashed),
+            "dotted" => Ok(Self::Dotted),
+            _ => Err(format!(
+                "Invalid divider style '{}'. Valid: frame, light, heavy, double, dashed, dotted",
+                name
+            )),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Frame => "frame",
+            Self::Light => "light",
+            Self::Heavy => "heavy",
+            Self::Double => "double",
+            Self::Dashed => "dashed",
+            Self::Dotted => "dotted",
+        }
+    }
+
+    fn weight(&self) -> Option<Weight> {
+        match self {
+            Self::Frame => None,
+            Self::Light | Self::Dashed | Self::Dotted => Some(Weight::Light),
+            Self::Heavy => Some(Weight::Heavy),
+            Self::Double => Some(Weight::Double),
+        }
+    }
+
+    /// Frame glyphs with the tees and horizontal swapped for this divider
+    ///
+    /// The returned style draws the rule (`horizontal`) and the junctions
+    /// with the frame (`tee_left`/`tee_right`); everything else is the frame's.
+    pub fn glyphs(&self, frame: &BoxStyle) -> BoxStyle {
+        let Some(rule) = self.weight() else {
+            return *frame;
+        };
+        let Some(edge) = Weight::of_frame(frame) else {
+            let horizontal = match rule {
+                Weight::Light => "-",
+                Weight::Heavy | Weight::Double => "=",
+            };
+            return BoxStyle {
+                horizontal,
+                ..*frame
+            };
+        };
+
+        let horizontal = match self {
+            Self::Dashed => "┄",
+            Self::Dotted => "┈",
+            _ => match rule {
+                Weight::Light => "─",
+                Weight::Heavy => "━",
+                Weight::Double => "═",
+            },
+        };
+        let (tee_left, tee_right) = match (edge, rule) {
+            (Weight::Light, Weight::Light) => ("├", "┤"),
+            (Weight::Light, Weight::Heavy) => ("┝", "┥"),
+            (Weight::Light, Weight::Double) => ("╞", "╡"),
+            (Weight::Heavy, Weight::Light) => ("┠", "┨"),
+            (Weight::Heavy, Weight::Heavy) => ("┣", "┫"),
+            (Weight::Double, Weight::Light) => ("╟", "╢"),
+            (Weight::Double, Weight::Double) => ("╠", "╣"),
+            // No glyph joins heavy and double strokes; keep the frame's tee
+            (Weight::Heavy, Weight::Double) | (Weight::Double, Weight::Heavy) => {
+                (frame.tee_left, frame.tee_right)
+            }
+        };
+        BoxStyle {
+            horizontal,
+            tee_left,
+            tee_right,
+            ..*frame
+        }
+    }
+
+    /// Tees that bracket a label sitting on this divider: `━┫ Totals ┣━`
+    pub fn label_brackets(&self, frame: &BoxStyle) -> (&'static str, &'static str) {
+        match self.weight() {
+            Some(weight) if Weight::of_frame(frame).is_some() => match weight {
+                Weight::Light => ("┤", "├"),
+                Weight::Heavy => ("┫", "┣"),
+                Weight::Double => ("╣", "╠"),
+            },
+            _ => (frame.tee_right, frame.tee_left),
+        }
+    }
+
+    /// Plain divider across `inner_width`, junctions included
+    pub fn line(&self, frame: &BoxStyle, inner_width: usize) -> String {
+        let glyphs = self.glyphs(frame);
+        format!(
+            "{}{}{}",
+            glyphs.tee_left,
+            glyphs.horizontal.repeat(inner_width),
+            glyphs.tee_right
+        )
+    }
+}
+
+/// Junctions where a vertical rule inside the box meets the top and bottom
+/// edges: `┬`/`┴` in a light frame; frames without box-drawing glyphs use their cross
+pub fn column_tees(frame: &BoxStyle) -> (&'static str, &'static str) {
+    match Weight::of_frame(frame) {
+        Some(Weight::Light) => ("┬", "┴"),
+        Some(Weight::Heavy) => ("┳", "┻"),
+        Some(Weight::Double) => ("╦", "╩"),
+        None => (frame.cross, frame.cross),
+    }
+}
+
+impl std::str::FromStr for DividerStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::visual::{ASCII, DOUBLE, HEAVY, NORMAL, ROUNDED};
+
+    #[test]
+    fn test_names_round_trip() {
+        for name in ["frame", "light", "heavy", "double", "dashed", "dotted"] {
+            assert_eq!(DividerStyle::from_name(name).unwrap().name(), name);
+        }
+        assert!(DividerStyle::from_name("wavy").is_err());
+    }
+
+    #[test]
+    fn test_mixed_junctions() {
+        assert_eq!(DividerStyle::Frame.line(&HEAVY, 3), "┣━━━┫");
+        assert_eq!(DividerStyle::Light.line(&HEAVY, 3), "┠───┨");
+        assert_eq!(DividerStyle::Heavy.line(&NORMAL, 3), "┝━━━┥");
+        assert_eq!(DividerStyle::Double.line(&ROUNDED, 3), "╞═══╡");
+        assert_eq!(DividerStyle::Light.line(&DOUBLE, 3), "╟───╢");
+        assert_eq!(DividerStyle::Dashed.line(&HEAVY, 3), "┠┄┄┄┨");
+        // Heavy and double strokes have no shared junction
+        assert_eq!(DividerStyle::Double.line(&HEAVY, 3), "┣═══┫");
+        assert_eq!(DividerStyle::Double.line(&ASCII, 3), "+===+");
+    }
+
+    #[test]
+    fn test_column_tees_follow_frame_weight() {
+        assert_eq!(column_tees(&ROUNDED), ("┬", "┴"));
+        assert_eq!(column_tees(&HEAVY), ("┳", "┻"));
+        assert_eq!(column_tees(&DOUBLE), ("╦", "╩"));
+        assert_eq!(column_tees(&ASCII), ("+", "+"));
+    }
+}
@@ -14,6 +14,7 @@
 //! Version: boxy v0.16.0+ (RSB MODULE_SPEC reorganization)
 
 pub mod calc_macros;
+pub mod divider;
 pub mod helpers;
 pub mod render_target;
 pub mod utils;
@@ -23,6 +24,8 @@ pub mod utils;
 #[allow(unused_imports)]
 pub use render_target::RenderTarget;
 
+pub use divider::DividerStyle;
+
 // Allow unused import lint here because library users depend on the broader API
 // while the CLI binary only touches a subset. This will be revisited when the
 // streaming-to-Write work lands.
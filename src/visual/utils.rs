@@ -10,7 +10,10 @@
 //!
 //! Version: boxy v0.16.0+ (RSB MODULE_SPEC reorganization)
 
-use crate::core::BoxyConfig;
+use crate::core::{
+    AlignedLine, BodyAlignment, BoxyConfig, align_line_parts, leading_icon, split_after_icon,
+    split_aligned_lines,
+};
 use crate::visual::render_target::RenderTarget;
 use crate::{
     RESET, expand_variables, get_color_code, get_display_width, get_terminal_width,
@@ -26,7 +29,7 @@ use std::io;
 // ============================================================================
 
 //WARN: this must contain all box characters!
-pub const BOX_CHARS: &str = "┌┐└┘─│├┤┼╭╮╰╯═║╠╣╬╔╗╚╝━┃┣┫╋┏┓┗┛+-|";
+pub const BOX_CHARS: &str = "┌┐└┘─│├┤┼╭╮╰╯═║╠╣╬╔╗╚╝━┃┣┫╋┏┓┗┛┄┈┆┝┥┠┨╞╡╟╢┬┴┳┻╦╩+-|";
 
 /// Validate style input
 pub fn validate_box_style(style: &str) -> Result<(), String> {
@@ -701,7 +704,7 @@ impl<'a> Body<'a> {
         // This macro preserves the exact working logic for content width calculation
         let _content_max_width = composed_lines
             .iter()
-            .map(|line| get_display_width(line))
+            .map(|line| get_display_width(&line.text))
             .max()
             .unwrap_or(0);
 
@@ -718,39 +721,51 @@ impl<'a> Body<'a> {
             line_count += 1;
         }
 
-        for (i, line) in composed_lines.iter().enumerate() {
+        // Emoji padding: indent follow-up lines to the column after the icon
+        let emoji_indent = self
+            .pad_icon()
+            .map_or(0, |icon| get_display_width(&icon) + 1);
+
+        for (i, aligned) in composed_lines.iter().enumerate() {
+            let line = &aligned.text;
+            let indent = if i > 0 { emoji_indent } else { 0 };
+            let line_target_width = available_content_width.saturating_sub(indent);
             let line_width = get_display_width(&line);
             let should_truncate = self.config.width.fixed_width.is_some()
                 && !self.config.width.enable_wrapping
-                && line_width > available_content_width;
-            let display_line = if should_truncate {
-                truncate_with_ellipsis(&line, available_content_width)
+                && line_width > line_target_width;
+            let fitted_line = if should_truncate {
+                truncate_with_ellipsis(&line, line_target_width)
             } else {
                 line.to_string()
             };
 
-            let width = get_display_width(&display_line);
-            let target_width = inner_content_target_width;
-            let spaces = " ".repeat(target_width.saturating_sub(width));
-            let debug_prefix = "";
+            let target_width = inner_content_target_width.saturating_sub(indent);
+            let (leading, display_line, spaces) = align_line_parts(
+                &fitted_line,
+                target_width,
+                &aligned.align,
+                aligned.paragraph_end,
+            );
+            let prefix = format!("{}{}", " ".repeat(indent), leading);
 
             if i == 0 && self.config.icon.is_some() {
                 let first_line = self.render_first_line_with_icon(
                     line,
-                    &display_line,
+                    &fitted_line,
                     available_content_width,
                     color_code,
-                    &pad,
+                    &aligned.align,
                     text_color_code,
                     title_color_code,
                 );
                 target.push_line(&first_line);
                 line_count += 1;
             } else {
-                let debug_display = format!("{}{}", debug_prefix, display_line);
+                let indented_line = format!("{}{}", prefix, display_line);
                 let regular_line = self.render_regular_line(
                     i,
-                    &debug_display,
+                    &indented_line,
                     &spaces,
                     color_code,
                     &pad,
@@ -799,21 +814,64 @@ impl<'a> Body<'a> {
         target.into_lines()
     }
 
+    /// Icon that emoji padding lines up with: the configured one, or one leading the title/text
+    fn pad_icon(&self) -> Option<String> {
+        use crate::expand_variables;
+
+        if !self.config.body_pad_emoji {
+            return None;
+        }
+        if let Some(icon) = &self.config.icon {
+            return Some(expand_variables(icon));
+        }
+        let first = match &self.config.title {
+            Some(title) => expand_variables(title),
+            None => self.config.text.lines().next()?.to_string(),
+        };
+        leading_icon(&first).map(str::to_string)
+    }
+
+    /// Split the text, wrapped to `width` minus the emoji-padding indent
+    ///
+    /// An icon leading the text (no title above it) stays on the first line;
+    /// the words after it wrap like the indented lines below.
+    fn split_text(&self, text: &str, width: Option<usize>) -> Vec<AlignedLine> {
+        let default_align = &self.config.body_align;
+        let pad_icon = self.pad_icon();
+        let width = match &pad_icon {
+            Some(icon) => width.map(|w| w.saturating_sub(get_display_width(icon) + 1)),
+            None => width,
+        };
+        match pad_icon {
+            Some(icon) if self.config.title.is_none() && self.config.icon.is_none() => {
+                split_after_icon(text, &icon, default_align, width)
+            }
+            _ => split_aligned_lines(text, default_align, width),
+        }
+    }
+
     /// Compose content lines with optional title as first line (preserves existing logic)
-    fn compose_content_lines(&self) -> Vec<String> {
+    ///
+    /// Each line carries its resolved alignment: the box-wide `body_align` unless an
+    /// inline `#L#`/`#C#`/`#R#`/`#J#` marker overrides it.
+    fn compose_content_lines(&self) -> Vec<AlignedLine> {
         use crate::expand_variables;
 
+        let default_align = &self.config.body_align;
+        let mut composed_lines: Vec<AlignedLine> = Vec::new();
+
+        if let Some(title_text) = &self.config.title {
+            composed_lines.extend(split_aligned_lines(
+                &expand_variables(title_text),
+                default_align,
+                None,
+            ));
+        }
+
         if self.config.width.fixed_width.is_none() {
             // AUTO WIDTH: Default wrapping at terminal boundaries, remove hints
-            use crate::core::wrap_text_at_word_boundaries;
             use crate::width_plugin::get_terminal_width;
 
-            let mut composed_lines: Vec<String> = Vec::new();
-
-            if let Some(title_text) = &self.config.title {
-                composed_lines.push(expand_variables(title_text));
-            }
-
             // For auto width, wrap at terminal width minus padding and borders
             let terminal_width = get_terminal_width();
             let available_width =
@@ -828,22 +886,11 @@ impl<'a> Body<'a> {
                 .iter()
                 .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
                 .collect();
-            let cleaned_text = normalized_lines.join("\n").replace("#NL#", "\n");
-
-            let wrapped_lines = wrap_text_at_word_boundaries(&cleaned_text, available_width);
-            composed_lines.extend(wrapped_lines);
+            let cleaned_text = normalized_lines.join("\n");
 
-            composed_lines
+            composed_lines.extend(self.split_text(&cleaned_text, Some(available_width)));
         } else if self.config.width.enable_wrapping {
             // FIXED WIDTH + WRAPPING: Use hint-aware wrapping within fixed width
-            use crate::core::wrap_text_at_word_boundaries;
-
-            let mut composed_lines: Vec<String> = Vec::new();
-
-            if let Some(title_text) = &self.config.title {
-                composed_lines.push(expand_variables(title_text));
-            }
-
             // Calculate max content width available for wrapping
             // Use the same width calculation as the main box
             let final_width = calculate_box_width(
@@ -854,22 +901,13 @@ impl<'a> Body<'a> {
             );
             let available_width = final_width.saturating_sub(2); // Account for borders
 
-            let wrapped_lines = wrap_text_at_word_boundaries(&self.config.text, available_width);
-            composed_lines.extend(wrapped_lines);
-
-            composed_lines
+            composed_lines.extend(self.split_text(&self.config.text, Some(available_width)));
         } else {
             // FIXED WIDTH WITHOUT WRAPPING: Original truncation mode
-            let lines: Vec<&str> = self.config.text.lines().collect();
-            let mut composed_lines: Vec<String> = Vec::new();
-
-            if let Some(title_text) = &self.config.title {
-                composed_lines.push(expand_variables(title_text));
-            }
-            composed_lines.extend(lines.iter().map(|l| (*l).to_string()));
-
-            composed_lines
+            composed_lines.extend(self.split_text(&self.config.text, None));
         }
+
+        composed_lines
     }
 
     fn render_padding_line(&self, inner_width: usize, color_code: &str, pad: &str) -> String {
@@ -904,12 +942,14 @@ impl<'a> Body<'a> {
         display_line: &str,
         available_content_width: usize,
         color_code: &str,
-        pad: &str,
+        align: &BodyAlignment,
         text_color_code: &str,
         title_color_code: &str,
     ) -> String {
         use crate::{expand_variables, get_display_width, truncate_with_ellipsis};
 
+        let pad = " ".repeat(self.config.width.h_padding);
+
         // Avoid duplicate icon if the title line already starts with an emoji/non-ASCII
         let starts_with_emoji = line.chars().next().map(|c| !c.is_ascii()).unwrap_or(false);
         if starts_with_emoji {
@@ -919,19 +959,20 @@ impl<'a> Body<'a> {
             } else {
                 text_color_code
             };
+            let (leading, aligned_line, spaces) =
+                align_line_parts(display_line, available_content_width, align, true);
             let colored_display_line = if line_code.is_empty() {
-                display_line.to_string()
+                aligned_line
             } else {
-                format!("{}{}{}", line_code, display_line, RESET)
+                format!("{}{}{}", line_code, aligned_line, RESET)
             };
-            let width = get_display_width(&display_line);
-            let spaces = " ".repeat(available_content_width.saturating_sub(width));
             return format!(
-                "{}{}{}{}{}{}{}{}",
+                "{}{}{}{}{}{}{}{}{}",
                 color_code,
                 self.config.style.vertical,
                 RESET,
                 pad,
+                leading,
                 colored_display_line,
                 spaces,
                 pad,
@@ -966,15 +1007,22 @@ impl<'a> Body<'a> {
             format!("{}{}{}", line_code, final_line, RESET)
         };
 
+        // Icon and text align as one unit; justify keeps the icon line ragged
         let final_width = get_display_width(&final_line);
-        let final_spaces =
-            " ".repeat(available_content_width.saturating_sub(final_width + icon_width));
+        let slack = available_content_width.saturating_sub(final_width + icon_width);
+        let leading = match align {
+            BodyAlignment::Center => slack / 2,
+            BodyAlignment::Right => slack,
+            BodyAlignment::Left | BodyAlignment::Justify => 0,
+        };
+        let final_spaces = " ".repeat(slack - leading);
 
         format!(
-            "{}{} {}{}{}{}{}{}{}",
+            "{}{} {}{}{}{}{}{}{}{}",
             color_code,
             self.config.style.vertical,
             RESET,
+            " ".repeat(leading),
             icon_expanded,
             " ",
             colored_final_line,
@@ -1049,6 +1097,37 @@ macro_rules! inner_target_width {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_emoji_padding_wraps_beside_the_indent() {
+        crate::width_calibration::install_uncalibrated();
+        let mut config = BoxyConfig {
+            text: "one two three four five six seven eight nine ten".to_string(),
+            title: Some("✅ Done".to_string()),
+            body_pad_emoji: true,
+            ..BoxyConfig::default()
+        };
+        config.width.fixed_width = Some(22);
+        config.width.enable_wrapping = true;
+
+        let mut target = RenderTarget::new();
+        Body::new(&config).render_into(&mut target, 20, "", "", "");
+        let lines: Vec<String> = target
+            .into_lines()
+            .iter()
+            .map(|l| crate::strip_ansi_codes(l))
+            .collect();
+
+        assert!(lines.len() > 2);
+        assert!(
+            lines.iter().all(|l| get_display_width(l) == 22),
+            "{:?}",
+            lines
+        );
+        for line in &lines[1..] {
+            assert!(line.starts_with("│    "), "not indented: {}", line);
+        }
+    }
+
     #[test]
     fn test_strip_box_content_preservation() {
         // Test the exact scenario from ceremony_14 step 6
@@ -0,0 +1,451 @@
+//! Terminal width calibration - learn real glyph widths from the terminal
+//!
+//! Width tables disagree between terminals, especially for emoji with variation
+//! selectors. `boxy width calibrate` prints probe glyphs, asks the terminal where
+//! the cursor ended up (DSR `ESC[6n`) and stores every glyph whose measured advance
+//! differs from the computed width. `get_display_width` consults that table.
+//!
+//! Tables live in `~/.local/etc/odx/boxy/widths/<terminal>.tsv`, one per
+//! TERM_PROGRAM/TERM combination. The CLI uses the table saved for its terminal;
+//! library callers opt in with [`install_table`] or `BOXY_WIDTH_TABLE`
+//! (`terminal` for the saved table, a file path, or `off`).
+
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// Glyphs whose width commonly differs between terminals
+pub const PROBE_GLYPHS: &[&str] = &[
+    "ℹ️",
+    "⚠️",
+    "✅",
+    "❌",
+    "✔",
+    "✓",
+    "✗",
+    "⭐",
+    "⚡",
+    "☀️",
+    "❤️",
+    "♻️",
+    "⚙️",
+    "✏️",
+    "‼️",
+    "©️",
+    "⏱",
+    "⏱️",
+    "🔥",
+    "📦",
+    "🟢",
+    "🚀",
+    "👍🏽",
+    "🏳️‍🌈",
+    "中",
+    "한",
+    "→",
+    "•",
+    "…",
+    "░",
+    "▓",
+    "█",
+];
+
+/// Glyph width overrides for one terminal
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WidthTable {
+    /// Overrides, longest glyph first so sequences win over their parts
+    entries: Vec<Override>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Override {
+    glyph: String,
+    width: usize,
+    /// Measured minus computed width, added per occurrence
+    delta: isize,
+}
+
+impl WidthTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, glyph: &str, width: usize) {
+        let computed = unicode_width::UnicodeWidthStr::width(glyph);
+        self.entries.retain(|o| o.glyph != glyph);
+        self.entries.push(Override {
+            glyph: glyph.to_string(),
+            width,
+            delta: width as isize - computed as isize,
+        });
+        self.entries.sort_by(|a, b| {
+            b.glyph
+                .len()
+                .cmp(&a.glyph.len())
+                .then(a.glyph.cmp(&b.glyph))
+        });
+    }
+
+    pub fn get(&self, glyph: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .find(|o| o.glyph == glyph)
+            .map(|o| o.width)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Parse the `glyph<TAB>width` file format; `#` lines are comments
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut table = Self::new();
+        for (number, line) in content.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (glyph, width) = line
+                .split_once('\t')
+                .ok_or_else(|| format!("Line {}: expected '<glyph>\\t<width>'", number + 1))?;
+            let width = width
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| format!("Line {}: invalid width '{}'", number + 1, width.trim()))?;
+            table.insert(glyph, width);
+        }
+        Ok(table)
+    }
+
+    /// Serialize to the file format read by `parse`
+    pub fn to_file_string(&self, terminal: &str) -> String {
+        let mut out = format!("# boxy width calibration for {}\n", terminal);
+        for o in &self.entries {
+            out.push_str(&format!("{}\t{}\n", o.glyph, o.width));
+        }
+        out
+    }
+
+    /// Correct a computed width for every overridden glyph in `text`
+    ///
+    /// `text` must already be free of ANSI codes and `computed` its unicode width.
+    pub fn adjust(&self, text: &str, computed: usize) -> usize {
+        if self.entries.is_empty() {
+            return computed;
+        }
+        let mut width = computed as isize;
+        let mut rest = text;
+
+        // One scan, longest match first, so shorter glyphs inside a sequence
+        // aren't counted twice
+        while let Some(ch) = rest.chars().next() {
+            match self
+                .entries
+                .iter()
+                .find(|o| rest.starts_with(o.glyph.as_str()))
+            {
+                Some(o) => {
+                    width += o.delta;
+                    rest = &rest[o.glyph.len()..];
+                }
+                None => rest = &rest[ch.len_utf8()..],
+            }
+        }
+
+        width.max(0) as usize
+    }
+}
+
+/// Identify the current terminal from TERM_PROGRAM and TERM
+pub fn terminal_key() -> String {
+    let program = std::env::var("TERM_PROGRAM").unwrap_or_default();
+    let term = std::env::var("TERM").unwrap_or_default();
+    let key = match (program.is_empty(), term.is_empty()) {
+        (false, false) => format!("{}.{}", program, term),
+        (false, true) => program,
+        (true, false) => term,
+        (true, true) => "unknown".to_string(),
+    };
+    key.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Path of the override table for a terminal key
+pub fn table_path(terminal: &str) -> PathBuf {
+    let home = std::env::var("HOME").unwrap_or_else(|_| "/".to_string());
+    PathBuf::from(home)
+        .join(".local/etc/odx/boxy/widths")
+        .join(format!("{}.tsv", terminal))
+}
+
+/// Load the table for a terminal, empty when none has been saved
+pub fn load_table(terminal: &str) -> Result<WidthTable, String> {
+    load_table_from(&table_path(terminal))
+}
+
+fn load_table_from(path: &std::path::Path) -> Result<WidthTable, String> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => WidthTable::parse(&content),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(WidthTable::new()),
+        Err(e) => Err(format!("Failed to read {}: {}", path.display(), e)),
+    }
+}
+
+/// Save the table for a terminal, returning the file written
+pub fn save_table(terminal: &str, table: &WidthTable) -> Result<PathBuf, String> {
+    let path = table_path(terminal);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    std::fs::write(&path, table.to_file_string(terminal))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+static ACTIVE: OnceLock<WidthTable> = OnceLock::new();
+
+/// Use `table` for every width calculation in this process
+///
+/// Only the first table takes effect; returns `false` once one is in use.
+pub fn install_table(table: WidthTable) -> bool {
+    ACTIVE.set(table).is_ok()
+}
+
+/// Use the table saved for the current terminal, unless `BOXY_WIDTH_TABLE` picks one
+pub fn install_terminal_table() -> bool {
+    match std::env::var_os("BOXY_WIDTH_TABLE") {
+        Some(_) => false,
+        None => install_table(load_table(&terminal_key()).unwrap_or_default()),
+    }
+}
+
+/// Table chosen by `BOXY_WIDTH_TABLE`: `terminal`, a file path or `off`
+fn env_table() -> WidthTable {
+    match std::env::var("BOXY_WIDTH_TABLE") {
+        Ok(value) if value == "terminal" => load_table(&terminal_key()).unwrap_or_default(),
+        Ok(value) if value == "off" || value.is_empty() => WidthTable::new(),
+        Ok(path) => load_table_from(path.as_ref()).unwrap_or_default(),
+        Err(_) => WidthTable::new(),
+    }
+}
+
+/// Table in effect for this process: the installed one, else `BOXY_WIDTH_TABLE`
+///
+/// Without either, widths are computed as-is. An unreadable table is ignored
+/// rather than failing every width calculation.
+pub fn active_table() -> &'static WidthTable {
+    ACTIVE.get_or_init(env_table)
+}
+
+/// Pin unit tests that measure wide glyphs to computed widths
+#[cfg(test)]
+pub(crate) fn install_uncalibrated() {
+    install_table(WidthTable::new());
+}
+
+/// Per-probe calibration result: (glyph, computed width, measured width)
+pub type CalibrationReport = Vec<(String, usize, usize)>;
+
+/// Measures glyph advances by asking a terminal for its cursor position
+///
+/// Works over any `Read + Write` handle so tests can stand in for a real tty.
+pub struct Calibrator<T: Read + Write> {
+    term: T,
+}
+
+impl<T: Read + Write> Calibrator<T> {
+    pub fn new(term: T) -> Self {
+        Self { term }
+    }
+
+    /// Print `glyph` at column 1 and report how far the cursor moved
+    pub fn measure(&mut self, glyph: &str) -> Result<usize, String> {
+        write!(self.term, "\r{}\x1b[6n", glyph).map_err(|e| e.to_string())?;
+        self.term.flush().map_err(|e| e.to_string())?;
+        let column = self.read_cursor_column()?;
+        // Wipe the probe so the terminal is left clean
+        write!(self.term, "\r\x1b[2K").map_err(|e| e.to_string())?;
+        Ok(column.saturating_sub(1))
+    }
+
+    /// Measure every probe and keep the ones that disagree with the computed width
+    ///
+    /// Returns the overrides plus a row per probe for reporting.
+    pub fn calibrate(
+        &mut self,
+        probes: &[&str],
+    ) -> Result<(WidthTable, CalibrationReport), String> {
+        let mut table = WidthTable::new();
+        let mut report = Vec::new();
+
+        for glyph in probes {
+            let computed = unicode_width::UnicodeWidthStr::width(*glyph);
+            let measured = self.measure(glyph)?;
+            if measured != computed {
+                table.insert(glyph, measured);
+            }
+            report.push((glyph.to_string(), computed, measured));
+        }
+        Ok((table, report))
+    }
+
+    /// Read a `ESC [ row ; col R` cursor position report
+    fn read_cursor_column(&mut self) -> Result<usize, String> {
+        let mut response = Vec::new();
+        let mut byte = [0u8; 1];
+
+        loop {
+            match self.term.read(&mut byte) {
+                Ok(0) => {
+                    return Err("Terminal did not answer the cursor position query".to_string());
+                }
+                Ok(_) => {
+                    response.push(byte[0]);
+                    if byte[0] == b'R' {
+                        break;
+                    }
+                    if response.len() > 32 {
+                        return Err("Unexpected reply to cursor position query".to_string());
+                    }
+                }
+                Err(e) => return Err(e.to_string()),
+            }
+        }
+
+        let response = String::from_utf8_lossy(&response);
+        let report = response
+            .rsplit_once("\x1b[")
+            .map(|(_, r)| r.trim_end_matches('R'))
+            .ok_or_else(|| "Unexpected reply to cursor position query".to_string())?;
+        report
+            .split_once(';')
+            .and_then(|(_, col)| col.parse::<usize>().ok())
+            .ok_or_else(|| format!("Malformed cursor position report '{}'", report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// Pseudo-terminal stand-in: tracks the cursor column using its own width table
+    struct FakeTerminal {
+        widths: WidthTable,
+        column: usize,
+        written: String,
+        replies: VecDeque<u8>,
+    }
+
+    impl FakeTerminal {
+        fn new(widths: WidthTable) -> Self {
+            Self {
+                widths,
+                column: 1,
+                written: String::new(),
+                replies: VecDeque::new(),
+            }
+        }
+    }
+
+    impl Write for FakeTerminal {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.written.push_str(&String::from_utf8_lossy(buf));
+            while let Some(pos) = self.written.find("\x1b[6n") {
+                let printed: String = self.written[..pos].replace('\r', "");
+                let printed = printed.replace("\x1b[2K", "");
+                let computed = unicode_width::UnicodeWidthStr::width(printed.as_str());
+                self.column = 1 + self.widths.adjust(&printed, computed);
+                self.replies
+                    .extend(format!("\x1b[7;{}R", self.column).into_bytes());
+                self.written = self.written[pos + 4..].to_string();
+            }
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Read for FakeTerminal {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            match self.replies.pop_front() {
+                Some(b) => {
+                    buf[0] = b;
+                    Ok(1)
+                }
+                None => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_measure_reads_cursor_report() {
+        let mut calibrator = Calibrator::new(FakeTerminal::new(WidthTable::new()));
+        assert_eq!(calibrator.measure("abc").unwrap(), 3);
+        assert_eq!(calibrator.measure("中").unwrap(), 2);
+    }
+
+    #[test]
+    fn test_calibrate_keeps_only_disagreements() {
+        let mut terminal_widths = WidthTable::new();
+        terminal_widths.insert("ℹ️", 1);
+        let mut calibrator = Calibrator::new(FakeTerminal::new(terminal_widths));
+
+        let (table, report) = calibrator.calibrate(&["ℹ️", "✅", "a"]).unwrap();
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.get("ℹ️"), Some(1));
+        assert_eq!(report.len(), 3);
+    }
+
+    #[test]
+    fn test_silent_terminal_is_an_error() {
+        struct Silent;
+        impl Read for Silent {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Ok(0)
+            }
+        }
+        impl Write for Silent {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        assert!(Calibrator::new(Silent).measure("x").is_err());
+    }
+
+    #[test]
+    fn test_table_round_trip_and_adjust() {
+        let mut table = WidthTable::new();
+        table.insert("ℹ️", 1);
+        table.insert("ℹ", 2);
+
+        let parsed = WidthTable::parse(&table.to_file_string("test")).unwrap();
+        assert_eq!(parsed, table);
+
+        // The sequence wins over the bare codepoint it contains
+        let text = "ℹ️ info";
+        let computed = unicode_width::UnicodeWidthStr::width(text);
+        assert_eq!(
+            parsed.adjust(text, computed),
+            computed - unicode_width::UnicodeWidthStr::width("ℹ️") + 1
+        );
+        assert!(WidthTable::parse("x\tnot-a-number").is_err());
+    }
+}
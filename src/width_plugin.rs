@@ -1,12 +1,10 @@
 use crate::{Command, File, Stdio};
 
-/// Validate width input
+/// Validate width input: a number or spec (`50%`, `max`, `-4`, `min(80,100%)`)
+/// that resolves to at least `MIN_WIDTH` columns on this terminal
 pub fn validate_width(width_str: &str) -> Result<(), String> {
-    match width_str.parse::<usize>() {
-        Ok(w) if w >= 10 && w <= 200 => Ok(()),
-        Ok(w) => Err(format!("Width {} out of range (10-200)", w)),
-        Err(_) => Err("Width must be a number".to_string()),
-    }
+    let spec = crate::core::SizeSpec::parse(width_str)?;
+    crate::core::resolve_width(&spec).map(|_| ())
 }
 
 /// Width diagnostics subcommand
@@ -58,6 +56,82 @@ pub fn handle_width_command() {
             .map(|v| v.to_string())
             .unwrap_or_else(|| "N/A".to_string())
     );
+    println!(
+        "  glyph overrides ({}): {} (run 'boxy width calibrate' to measure)",
+        crate::width_calibration::terminal_key(),
+        crate::width_calibration::active_table().len()
+    );
+}
+
+/// Width calibration subcommand: `boxy width calibrate [--dry-run|--reset]`
+pub fn handle_width_calibrate(args: &[String]) -> Result<(), String> {
+    use crate::width_calibration::{
+        Calibrator, PROBE_GLYPHS, save_table, table_path, terminal_key,
+    };
+
+    let terminal = terminal_key();
+
+    if args.iter().any(|a| a == "--reset") {
+        let path = table_path(&terminal);
+        if path.exists() {
+            std::fs::remove_file(&path)
+                .map_err(|e| format!("Failed to remove {}: {}", path.display(), e))?;
+            println!("Removed width overrides for {}", terminal);
+        } else {
+            println!("No width overrides saved for {}", terminal);
+        }
+        return Ok(());
+    }
+
+    let tty = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .map_err(|e| format!("Calibration needs an interactive terminal: {}", e))?;
+
+    // Raw-ish mode so the cursor report arrives without Enter; reads give up after 1s
+    let saved = stty_with_tty(&["-g"]).ok_or("Failed to read terminal settings")?;
+    stty_with_tty(&["-icanon", "-echo", "min", "0", "time", "10"])
+        .ok_or("Failed to switch terminal to raw mode")?;
+    let result = Calibrator::new(tty).calibrate(PROBE_GLYPHS);
+    stty_with_tty(&[saved.trim()]);
+    let (table, report) = result?;
+
+    println!("Width calibration for {}:", terminal);
+    for (glyph, computed, measured) in &report {
+        let marker = if computed == measured {
+            ""
+        } else {
+            "  <- override"
+        };
+        println!(
+            "  {}\tcomputed {}  measured {}{}",
+            glyph, computed, measured, marker
+        );
+    }
+
+    if args.iter().any(|a| a == "--dry-run") {
+        println!("{} override(s) found (dry run, nothing saved)", table.len());
+    } else {
+        let path = save_table(&terminal, &table)?;
+        println!("{} override(s) saved to {}", table.len(), path.display());
+    }
+    Ok(())
+}
+
+/// Run stty against the controlling terminal
+fn stty_with_tty(args: &[&str]) -> Option<String> {
+    let tty = File::open("/dev/tty").ok()?;
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::from(tty))
+        .output()
+        .ok()?;
+    if output.status.success() {
+        String::from_utf8(output.stdout).ok()
+    } else {
+        None
+    }
 }
 
 /// Get terminal width with fallback to 80 columns
@@ -211,9 +285,33 @@ pub fn get_display_width_unicode_crate(text: &str) -> usize {
 }
 
 /// Main width function - strips ANSI codes then calculates width
+/// Glyphs measured by `boxy width calibrate` use the terminal's real width
 pub fn get_display_width(text: &str) -> usize {
     // Strip ANSI escape codes first, then use unicode-width
     let clean = strip_ansi_escapes::strip(text);
     let clean_str = String::from_utf8_lossy(&clean);
-    unicode_width::UnicodeWidthStr::width(&*clean_str)
+    let computed = unicode_width::UnicodeWidthStr::width(&*clean_str);
+
+    let overrides = crate::width_calibration::active_table();
+    if overrides.is_empty() {
+        computed
+    } else {
+        overrides.adjust(&clean_str, computed)
+    }
+}
+
+/// Split text into display clusters: each base character with the combining
+/// marks, variation selectors and ZWJ-joined characters that follow it
+pub fn split_clusters(text: &str) -> Vec<String> {
+    let mut clusters: Vec<String> = Vec::new();
+    let mut joined = false;
+    for ch in text.chars() {
+        let zero_width = unicode_width::UnicodeWidthChar::width(ch).unwrap_or(0) == 0;
+        match clusters.last_mut() {
+            Some(cluster) if zero_width || joined => cluster.push(ch),
+            _ => clusters.push(ch.to_string()),
+        }
+        joined = ch == '\u{200D}';
+    }
+    clusters
 }
@@ -0,0 +1,247 @@
+//! CLI integration tests - run the boxy binary and check what it prints
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// Run boxy with `args`, feeding `input` on stdin
+fn boxy(args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_boxy"))
+        .args(args)
+        .env_remove("BOXY_THEME")
+        .env_remove("BOXY_OVERFLOW")
+        .env("BOXY_WIDTH_TABLE", "off")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to start boxy");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().expect("boxy did not finish")
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "boxy failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn test_emoji_padding_lines_up_with_title_icon() {
+    let text = "one two three four five six seven eight nine ten";
+    for icon_args in [["--title", "✅ Done"], ["--icon", "✅"]] {
+        let mut args = vec!["--layout", "bp", "--width", "22", "--wrap", "--no-color"];
+        args.extend(icon_args);
+        let output = stdout(&boxy(&args, text));
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert!(lines[1].starts_with("│ ✅ "), "{}", output);
+        for line in &lines[2..lines.len() - 1] {
+            assert!(line.starts_with("│    "), "not indented:\n{}", output);
+            assert!(line.ends_with('│') && !line.contains('…'), "{}", output);
+        }
+    }
+}
+
+#[test]
+fn test_icon_skips_nested_body() {
+    let inner = stdout(&boxy(&["--no-color"], "inner"));
+    for icon_args in [["--icon", "✅"], ["--theme", "success"]] {
+        let mut args = vec!["--nested", "--no-color"];
+        args.extend(icon_args);
+        let result = boxy(&args, &inner);
+        let output = stdout(&result);
+
+        assert!(!output.contains('✅'), "{}", output);
+        assert!(output.contains("│ │ inner │ │"), "{}", output);
+        let warned = String::from_utf8_lossy(&result.stderr).contains("icon dropped");
+        assert_eq!(warned, icon_args[0] == "--icon");
+    }
+}
+
+#[test]
+fn test_icon_goes_on_title_above_nested_body() {
+    let inner = stdout(&boxy(&["--no-color"], "inner"));
+    let result = boxy(
+        &["--nested", "--icon", "✅", "--title", "Done", "--no-color"],
+        &inner,
+    );
+    let output = stdout(&result);
+
+    assert!(output.contains("✅ Done"), "{}", output);
+    assert!(result.stderr.is_empty());
+}
+
+#[test]
+fn test_theme_icon_keeps_markdown_heading() {
+    let output = stdout(&boxy(
+        &["--markdown", "--theme", "success", "--no-color"],
+        "# Heading\n\nSome text",
+    ));
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert!(lines[1].starts_with("│ Heading"), "{}", output);
+    assert!(
+        !output.contains('#') && !output.contains('✅'),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn test_piped_output_is_fitted_only_on_request() {
+    let text = ["word"; 80].join(" ");
+    let widest = |output: &str| output.lines().map(|l| l.chars().count()).max().unwrap();
+
+    let unfitted = stdout(&boxy(&["--no-color"], &text));
+    assert_eq!(widest(&unfitted), text.len() + 4);
+
+    let fitted = stdout(&boxy(&["--no-color", "--overflow", "truncate"], &text));
+    assert!(widest(&fitted) < widest(&unfitted), "{}", fitted);
+}
+
+#[test]
+fn test_absolute_width_is_fitted_only_on_request() {
+    let output = stdout(&boxy(&["--no-color", "--width", "400"], "short"));
+    assert!(
+        output.lines().all(|l| l.chars().count() == 400),
+        "{}",
+        output
+    );
+
+    // Wider than the (at most 80 column) test terminal
+    let output = boxy(
+        &["--no-color", "--width", "300", "--overflow", "error"],
+        "short",
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Error:"), "{}", stderr);
+}
+
+#[test]
+fn test_code_language_is_never_a_flag() {
+    let output = stdout(&boxy(
+        &["--code", "--header", "H", "--no-color"],
+        "fn main() {}",
+    ));
+    assert!(output.lines().next().unwrap().contains('H'), "{}", output);
+    assert!(output.contains("fn main() {}"), "{}", output);
+
+    let output = stdout(&boxy(&["--code=rust", "--no-color"], "x"));
+    assert!(
+        output.lines().next().unwrap().contains("rust"),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn test_icon_skips_json_tree() {
+    for icon_args in [["--icon", "✅"], ["--theme", "success"]] {
+        let mut args = vec!["--tree", "json", "--no-color"];
+        args.extend(icon_args);
+        let output = stdout(&boxy(&args, r#"{"src": {"main.rs": null}}"#));
+
+        assert!(!output.contains('✅'), "{}", output);
+        assert!(output.contains("main.rs"), "{}", output);
+    }
+}
+
+#[test]
+fn test_diff_files_may_contain_the_separator() {
+    let dir = std::env::temp_dir().join(format!("boxy-diff-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let (old, new) = (dir.join("old.txt"), dir.join("new.txt"));
+    std::fs::write(&old, "keep\n#DIFF#\nold\n").unwrap();
+    std::fs::write(&new, "keep\n#DIFF#\nnew\n").unwrap();
+
+    let output = boxy(
+        &[
+            "diff",
+            old.to_str().unwrap(),
+            new.to_str().unwrap(),
+            "--no-color",
+        ],
+        "",
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+    let output = stdout(&output);
+
+    assert!(output.contains("│   #DIFF# "), "{}", output);
+    assert!(
+        output.contains("~ old") && output.contains("~ new"),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn test_icon_skips_diff_panes() {
+    for icon_args in [["--icon", "✅"], ["--theme", "success"]] {
+        let mut args = vec!["--diff", "--no-color"];
+        args.extend(icon_args);
+        let output = stdout(&boxy(&args, "a\nb\n#DIFF#\na\nc"));
+
+        assert!(!output.contains('✅'), "{}", output);
+        assert!(
+            output.contains("~ b") && output.contains("~ c"),
+            "{}",
+            output
+        );
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_boxes_output_literally() {
+    let script = "echo '#DIV:x# [a](b) #NL# b'; echo err >&2; exit 3";
+    let output = boxy(&["run", "--no-color", "--", "sh", "-c", script], "");
+    let text = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = text.lines().collect();
+
+    assert_eq!(output.status.code(), Some(3));
+    assert!(lines[1].contains(" #DIV:x# [a](b) #NL# b "), "{}", text);
+    assert!(lines[2].contains(" stderr "), "{}", text);
+    assert!(lines[3].contains(" err "), "{}", text);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_checks_options_before_the_command() {
+    let marker = std::env::temp_dir().join(format!("boxy-run-{}", std::process::id()));
+    let script = format!("touch '{}'", marker.display());
+    let output = boxy(&["run", "--width", "2", "--", "sh", "-c", &script], "");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(!marker.exists(), "command ran despite an invalid option");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_keeps_exit_code_when_boxing_fails() {
+    let script = "printf '%0300d\\n' 0; exit 4";
+    let output = boxy(
+        &["run", "--overflow", "error", "--", "sh", "-c", script],
+        "",
+    );
+    assert_eq!(output.status.code(), Some(4));
+}
+
+#[test]
+fn test_sizes_past_the_maximum_are_rejected() {
+    for args in [["--width", "5000"], ["--height", "5000"]] {
+        let output = boxy(&args, "x");
+        assert!(!output.status.success(), "{:?} accepted", args);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("above the maximum of 1000"), "{}", stderr);
+    }
+}